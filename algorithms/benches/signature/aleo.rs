@@ -74,6 +74,42 @@ fn aleo_signature_verify(c: &mut Criterion) {
     });
 }
 
+fn aleo_signature_batch_verify(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+    let parameters = SignatureScheme::setup("aleo_signature_batch_verify");
+    let message = [100u8; 128];
+
+    for num_signatures in [10, 100] {
+        let entries = (0..num_signatures)
+            .map(|_| {
+                let private_key = SignatureScheme::generate_private_key(&parameters, rng);
+                let public_key = SignatureScheme::generate_public_key(&parameters, &private_key);
+                let signature = SignatureScheme::sign(&parameters, &private_key, &message, rng).unwrap();
+                (public_key, &message[..], signature)
+            })
+            .collect::<Vec<_>>();
+
+        c.bench_function(&format!("Aleo Signature Verify ({} signatures)", num_signatures), |b| {
+            b.iter(|| {
+                for (public_key, message, signature) in &entries {
+                    assert!(SignatureScheme::verify(&parameters, public_key, message, signature).unwrap());
+                }
+            })
+        });
+
+        c.bench_function(
+            &format!("Aleo Signature Batch Verify ({} signatures)", num_signatures),
+            |b| {
+                b.iter(|| {
+                    assert!(SignatureScheme::batch_verify(&parameters, &entries, rng)
+                        .unwrap()
+                        .is_empty())
+                })
+            },
+        );
+    }
+}
+
 criterion_group! {
     name = aleo_signature;
     config = Criterion::default().sample_size(20);
@@ -82,5 +118,6 @@ criterion_group! {
                 aleo_signature_generate_public_key,
                 aleo_signature_sign,
                 aleo_signature_verify,
+                aleo_signature_batch_verify,
 }
 criterion_main!(aleo_signature);
@@ -17,6 +17,7 @@
 use crate::{
     crypto_hash::{PoseidonCryptoHash, PoseidonDefaultParametersField},
     hash_to_curve::hash_to_curve,
+    msm::{FixedBaseMSM, VariableBaseMSM},
     CryptoHash,
     SignatureError,
     SignatureScheme,
@@ -31,6 +32,8 @@ use snarkvm_curves::{
 };
 use snarkvm_fields::{ConstraintFieldError, Field, FieldParameters, PrimeField, ToConstraintField};
 use snarkvm_utilities::{
+    cfg_into_iter,
+    cfg_iter,
     io::{Read, Result as IoResult, Write},
     ops::Mul,
     rand::UniformRand,
@@ -45,6 +48,9 @@ use anyhow::Result;
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[derive(Derivative)]
 #[derivative(
     Copy(bound = "TE: TwistedEdwardsParameters"),
@@ -57,8 +63,8 @@ use rand::{CryptoRng, Rng};
 pub struct AleoSignature<TE: TwistedEdwardsParameters> {
     pub prover_response: TE::ScalarField,
     pub verifier_challenge: TE::ScalarField,
-    root_public_key: TE::BaseField,
-    root_randomizer: TE::BaseField,
+    pub(crate) root_public_key: TE::BaseField,
+    pub(crate) root_randomizer: TE::BaseField,
 }
 
 impl<TE: TwistedEdwardsParameters> AleoSignature<TE> {
@@ -246,17 +252,7 @@ where
         let public_key = g_sk_sig + g_r_sig + g_sk_prf;
 
         // Compute the verifier challenge.
-        let verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.x.to_field_elements()?);
-            preimage.extend_from_slice(&g_r.x.to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let verifier_challenge = self.compute_verifier_challenge(&public_key, &g_r, message)?;
 
         // Compute the prover response.
         let prover_response = r - (verifier_challenge * sk_sig);
@@ -292,17 +288,7 @@ where
         let g_r = self.g_scalar_multiply(prover_response) + g_sk_sig_c;
 
         // Compute the candidate verifier challenge.
-        let candidate_verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.x.to_field_elements()?);
-            preimage.extend_from_slice(&g_r.x.to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let candidate_verifier_challenge = self.compute_verifier_challenge(public_key, &g_r, message)?;
 
         // Recover G^r_sig.
         let g_r_sig = Self::recover_from_x_coordinate(root_randomizer)?;
@@ -321,6 +307,139 @@ where
 
         Ok(*verifier_challenge == candidate_verifier_challenge && *public_key == candidate_public_key)
     }
+
+    ///
+    /// Returns the indices of the entries that fail verification, which is empty if every signature is valid.
+    ///
+    /// The verifier challenge of each entry is checked as in `verify`, where the multiplications G^s
+    /// are batched into one fixed-base multi-scalar multiplication. The public keys are then checked
+    /// together with a randomized linear combination, for 128-bit coefficients rho_i sampled from the given RNG:
+    ///     sum_i rho_i (public_key_i - G^sk_sig_i - G^r_sig_i) == G^(sum_i rho_i sk_prf_i)
+    /// If the combination does not hold, each public key is checked individually to find the failing entries.
+    ///
+    fn batch_verify<R: Rng + CryptoRng>(
+        &self,
+        entries: &[(Self::PublicKey, &[u8], Self::Signature)],
+        rng: &mut R,
+    ) -> Result<Vec<usize>> {
+        // Recover (G^sk_sig, G^r_sig), and compute (G^sk_sig^c, sk_prf) for each entry.
+        let recovered = cfg_iter!(entries)
+            .map(|(_, _, signature)| {
+                let g_sk_sig = Self::recover_from_x_coordinate(&signature.root_public_key).ok()?;
+                let g_r_sig = Self::recover_from_x_coordinate(&signature.root_randomizer).ok()?;
+                let g_sk_sig_c = self.scalar_multiply(g_sk_sig.into_projective(), &signature.verifier_challenge);
+                let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.x, g_r_sig.x]);
+                Some((g_sk_sig, g_r_sig, g_sk_sig_c, sk_prf))
+            })
+            .collect::<Vec<_>>();
+
+        // Compute G^s for each recovered entry, in one fixed-base multi-scalar multiplication.
+        let scalars = entries
+            .iter()
+            .zip(&recovered)
+            .filter_map(|((_, _, signature), recovered)| recovered.map(|_| signature.prover_response))
+            .collect::<Vec<_>>();
+        let g_s = match scalars.is_empty() {
+            true => vec![],
+            false => {
+                let scalar_size = <TE::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize;
+                let window = FixedBaseMSM::get_mul_window_size(scalars.len());
+                let table = FixedBaseMSM::get_window_table(scalar_size, window, self.g_bases[0]);
+                FixedBaseMSM::multi_scalar_mul(scalar_size, window, &table, &scalars)
+            }
+        };
+
+        let mut g_s = g_s.into_iter();
+        let checks = entries
+            .iter()
+            .zip(recovered)
+            .map(|(entry, recovered)| recovered.map(|recovered| (entry, recovered, g_s.next().unwrap())))
+            .collect::<Vec<_>>();
+
+        // Check the verifier challenge of each entry, and compute (public_key - G^sk_sig - G^r_sig, sk_prf)
+        // for the public key check of each entry that passes.
+        let checks = cfg_into_iter!(checks)
+            .map(|check| {
+                let ((public_key, message, signature), (g_sk_sig, g_r_sig, g_sk_sig_c, sk_prf), g_s) = match check {
+                    Some(check) => check,
+                    None => return Ok(None),
+                };
+
+                // Compute G^r := G^s G^sk_sig^c.
+                let g_r = g_s.into_affine() + g_sk_sig_c;
+                let candidate_verifier_challenge = self.compute_verifier_challenge(public_key, &g_r, message)?;
+                if signature.verifier_challenge != candidate_verifier_challenge {
+                    return Ok(None);
+                }
+
+                // Ensure the public key is in the prime-order subgroup, as are G^sk_sig, G^r_sig, and G^sk_prf,
+                // so that a torsion component of a public key cannot cancel out in the linear combination.
+                if !public_key.is_on_curve() || !public_key.is_in_correct_subgroup_assuming_on_curve() {
+                    return Ok(None);
+                }
+
+                let mut g_sk_prf = public_key.into_projective();
+                g_sk_prf.add_assign_mixed(&-g_sk_sig);
+                g_sk_prf.add_assign_mixed(&-g_r_sig);
+                Ok(Some((g_sk_prf, sk_prf)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut failures = vec![];
+        let mut indices = vec![];
+        let mut candidates = vec![];
+        for (index, check) in checks.into_iter().enumerate() {
+            match check {
+                Some(check) => {
+                    indices.push(index);
+                    candidates.push(check);
+                }
+                None => failures.push(index),
+            }
+        }
+        if candidates.is_empty() {
+            return Ok(failures);
+        }
+
+        // Check the public keys together, as sum_i rho_i G^sk_prf_i == G^(sum_i rho_i sk_prf_i).
+        let coefficients = (0..candidates.len())
+            .map(|_| TE::ScalarField::from(rng.gen::<u128>()))
+            .collect::<Vec<_>>();
+        let (g_sk_prfs, sk_prfs): (Vec<_>, Vec<_>) = candidates.into_iter().unzip();
+        let g_sk_prfs = TEProjective::<TE>::batch_normalization_into_affine(g_sk_prfs);
+
+        let combination = VariableBaseMSM::multi_scalar_mul(
+            &g_sk_prfs,
+            &coefficients
+                .iter()
+                .map(|coefficient| coefficient.to_repr())
+                .collect::<Vec<_>>(),
+            -1,
+        );
+        let combined_sk_prf = coefficients
+            .iter()
+            .zip(&sk_prfs)
+            .map(|(coefficient, sk_prf)| *coefficient * sk_prf)
+            .sum::<TE::ScalarField>();
+
+        if combination.into_affine() != self.g_scalar_multiply(&combined_sk_prf) {
+            // Fall back to checking each public key, to find the failing entries.
+            let invalid = cfg_into_iter!(indices)
+                .zip(g_sk_prfs)
+                .zip(sk_prfs)
+                .filter_map(
+                    |((index, g_sk_prf), sk_prf)| match g_sk_prf == self.g_scalar_multiply(&sk_prf) {
+                        true => None,
+                        false => Some(index),
+                    },
+                )
+                .collect::<Vec<_>>();
+            failures.extend(invalid);
+            failures.sort_unstable();
+        }
+
+        Ok(failures)
+    }
 }
 
 impl<TE: TwistedEdwardsParameters> SignatureSchemeOperations for AleoSignatureScheme<TE>
@@ -378,6 +497,26 @@ where
         base.mul(*scalar).into_affine()
     }
 
+    ///
+    /// Returns the verifier challenge as Hash(G^sk_sig G^r_sig G^sk_prf, G^r, message).
+    ///
+    pub(crate) fn compute_verifier_challenge(
+        &self,
+        public_key: &TEAffine<TE>,
+        g_r: &TEAffine<TE>,
+        message: &[u8],
+    ) -> Result<TE::ScalarField> {
+        // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
+        let mut preimage = vec![];
+        preimage.extend_from_slice(&public_key.x.to_field_elements()?);
+        preimage.extend_from_slice(&g_r.x.to_field_elements()?);
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+
+        // Hash to derive the verifier challenge.
+        Ok(self.hash_to_scalar_field(&preimage))
+    }
+
     fn recover_from_x_coordinate(x_coordinate: &TE::BaseField) -> Result<TEAffine<TE>> {
         if let Some(element) = TEAffine::<TE>::from_x_coordinate(*x_coordinate, true) {
             if element.is_in_correct_subgroup_assuming_on_curve() {
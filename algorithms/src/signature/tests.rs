@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::SignatureScheme;
-use snarkvm_utilities::FromBytes;
+use snarkvm_utilities::{FromBytes, ToBytes};
 
 use rand::thread_rng;
 
@@ -39,6 +39,35 @@ fn failed_verification<S: SignatureScheme>(message: &[u8], bad_message: &[u8]) {
     assert!(!signature_scheme.verify(&public_key, bad_message, &signature).unwrap());
 }
 
+fn batch_verify<S: SignatureScheme>() {
+    let rng = &mut thread_rng();
+    let signature_scheme = S::setup("batch_verify");
+
+    let messages = (0..10u8).map(|i| vec![i; 32 * i as usize]).collect::<Vec<_>>();
+    let mut entries = messages
+        .iter()
+        .map(|message| {
+            let private_key = signature_scheme.generate_private_key(rng);
+            let public_key = signature_scheme.generate_public_key(&private_key);
+            let signature = signature_scheme.sign(&private_key, message, rng).unwrap();
+            (public_key, &message[..], signature)
+        })
+        .collect::<Vec<_>>();
+    assert!(signature_scheme.batch_verify(&entries, rng).unwrap().is_empty());
+    assert!(signature_scheme.batch_verify(&[], rng).unwrap().is_empty());
+
+    // Ensure the failing entries are reported.
+    entries[3].1 = b"Bad message";
+    entries[7].0 = entries[8].0.clone();
+    assert_eq!(vec![3, 7], signature_scheme.batch_verify(&entries, rng).unwrap());
+
+    // Ensure the batch agrees with individual verification.
+    for (index, (public_key, message, signature)) in entries.iter().enumerate() {
+        let is_valid = signature_scheme.verify(public_key, message, signature).unwrap();
+        assert_eq!(index != 3 && index != 7, is_valid);
+    }
+}
+
 fn signature_scheme_serialization<S: SignatureScheme>() {
     let signature_scheme = S::setup("signature_scheme_serialization");
     let recovered_signature_scheme: S = FromBytes::read_le(&signature_scheme.to_bytes_le().unwrap()[..]).unwrap();
@@ -47,11 +76,61 @@ fn signature_scheme_serialization<S: SignatureScheme>() {
 
 mod aleo {
     use super::*;
-    use crate::signature::AleoSignatureScheme;
+    use crate::{
+        crypto_hash::PoseidonDefaultParametersField,
+        signature::AleoSignatureScheme,
+        SignatureSchemeOperations,
+    };
     use snarkvm_curves::{
         edwards_bls12::EdwardsParameters as EdwardsBls12,
         edwards_bw6::EdwardsParameters as EdwardsBW6,
+        templates::twisted_edwards_extended::Affine,
+        AffineCurve,
+        ProjectiveCurve,
+        TwistedEdwardsParameters,
     };
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::{ops::Mul, UniformRand};
+
+    fn batch_verify_invalid_public_key<TE: TwistedEdwardsParameters>()
+    where
+        TE::BaseField: PoseidonDefaultParametersField,
+    {
+        let rng = &mut thread_rng();
+        let signature_scheme = AleoSignatureScheme::<TE>::setup("batch_verify_invalid_public_key");
+        let message = b"Hi, I am an Aleo signature!";
+
+        let mut entries = (0..4)
+            .map(|_| {
+                let private_key = signature_scheme.generate_private_key(rng);
+                let public_key = signature_scheme.generate_public_key(&private_key);
+                let signature = signature_scheme.sign(&private_key, message, rng).unwrap();
+                (public_key, &message[..], signature)
+            })
+            .collect::<Vec<_>>();
+
+        // Replace G^r_sig, which the verifier challenge does not commit to, so that only the public key check fails.
+        let g_r_sig: Affine<TE> = signature_scheme.g_scalar_multiply(&TE::ScalarField::rand(rng));
+        entries[2].2.root_randomizer = g_r_sig.x;
+
+        // Ensure the verifier challenge still holds, as G^r = G^s G^sk_sig^c.
+        let (public_key, message, signature) = &entries[2];
+        let g_sk_sig = signature.root_public_key().unwrap();
+        let g_r = signature_scheme.g_scalar_multiply(&signature.prover_response)
+            + g_sk_sig
+                .into_projective()
+                .mul(signature.verifier_challenge)
+                .into_affine();
+        let candidate_verifier_challenge = signature_scheme
+            .compute_verifier_challenge(public_key, &g_r, message)
+            .unwrap();
+        assert_eq!(signature.verifier_challenge, candidate_verifier_challenge);
+        assert!(!g_r.is_zero());
+
+        // Ensure the invalid public key is rejected, individually and in the batch.
+        assert!(!signature_scheme.verify(public_key, message, signature).unwrap());
+        assert_eq!(vec![2], signature_scheme.batch_verify(&entries, rng).unwrap());
+    }
 
     #[test]
     fn test_aleo_signature_on_edwards_bls12_377() {
@@ -60,6 +139,8 @@ mod aleo {
         let message = "Hi, I am an Aleo signature!";
         sign_and_verify::<TestSignature>(message.as_bytes());
         failed_verification::<TestSignature>(message.as_bytes(), b"Bad message");
+        batch_verify::<TestSignature>();
+        batch_verify_invalid_public_key::<EdwardsBls12>();
     }
 
     #[test]
@@ -69,6 +150,8 @@ mod aleo {
         let message = "Hi, I am an Aleo signature!";
         sign_and_verify::<TestSignature>(message.as_bytes());
         failed_verification::<TestSignature>(message.as_bytes(), b"Bad message");
+        batch_verify::<TestSignature>();
+        batch_verify_invalid_public_key::<EdwardsBW6>();
     }
 
     #[test]
@@ -76,4 +159,187 @@ mod aleo {
         signature_scheme_serialization::<AleoSignatureScheme<EdwardsBls12>>();
         signature_scheme_serialization::<AleoSignatureScheme<EdwardsBW6>>();
     }
+
+    mod threshold {
+        use super::*;
+        use crate::signature::{
+            KeyGenCommitment,
+            KeyGenShare,
+            SignatureShare,
+            SigningCommitment,
+            ThresholdKeyGen,
+            ThresholdKeyShare,
+            ThresholdParameters,
+            ThresholdPublicKey,
+        };
+
+        type TestSignature = AleoSignatureScheme<EdwardsBls12>;
+
+        /// Returns the key shares of all participants, after running the key generation protocol.
+        fn key_gen(
+            signature_scheme: &TestSignature,
+            threshold: u16,
+            num_participants: u16,
+        ) -> Vec<ThresholdKeyShare<EdwardsBls12>> {
+            let rng = &mut thread_rng();
+            let parameters = ThresholdParameters::new(threshold, num_participants).unwrap();
+
+            // Round 1: each participant broadcasts its commitment.
+            let (participants, commitments): (Vec<_>, Vec<_>) = (1..=num_participants)
+                .map(|index| ThresholdKeyGen::new(signature_scheme, parameters, index, rng).unwrap())
+                .unzip();
+
+            // Ensure the commitments round-trip through bytes.
+            let commitments = commitments
+                .iter()
+                .map(|commitment| KeyGenCommitment::read_le(&commitment.to_bytes_le().unwrap()[..]).unwrap())
+                .collect::<Vec<_>>();
+
+            // Round 2: each participant sends a share to every other participant.
+            let shares = participants
+                .iter()
+                .map(|participant| {
+                    (1..=num_participants)
+                        .filter(|recipient| *recipient != participant.index())
+                        .map(|recipient| participant.share(recipient).unwrap())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            participants
+                .into_iter()
+                .map(|participant| {
+                    let received = shares
+                        .iter()
+                        .flatten()
+                        .filter(|share| share.recipient() == participant.index())
+                        .map(|share| KeyGenShare::read_le(&share.to_bytes_le().unwrap()[..]).unwrap())
+                        .collect::<Vec<_>>();
+                    participant.finalize(signature_scheme, &commitments, &received).unwrap()
+                })
+                .collect()
+        }
+
+        /// Returns the signature commitments and shares of the given signers on the given message.
+        fn sign(
+            signature_scheme: &TestSignature,
+            signers: &[&ThresholdKeyShare<EdwardsBls12>],
+            message: &[u8],
+        ) -> (Vec<SigningCommitment<EdwardsBls12>>, Vec<SignatureShare<EdwardsBls12>>) {
+            let rng = &mut thread_rng();
+
+            // Round 1: each signer broadcasts its signing commitment.
+            let (nonces, commitments): (Vec<_>, Vec<_>) = signers
+                .iter()
+                .map(|signer| signer.commit(signature_scheme, rng))
+                .unzip();
+            let commitments = commitments
+                .iter()
+                .map(|commitment| SigningCommitment::read_le(&commitment.to_bytes_le().unwrap()[..]).unwrap())
+                .collect::<Vec<_>>();
+
+            // Round 2: each signer sends its signature share to the aggregator.
+            let shares = signers
+                .iter()
+                .zip(nonces)
+                .map(|(signer, nonces)| signer.sign(signature_scheme, nonces, message, &commitments).unwrap())
+                .map(|share| SignatureShare::read_le(&share.to_bytes_le().unwrap()[..]).unwrap())
+                .collect::<Vec<_>>();
+
+            (commitments, shares)
+        }
+
+        #[test]
+        fn test_threshold_sign_and_verify() {
+            let signature_scheme = TestSignature::setup("test_threshold_sign_and_verify");
+            let message = b"Hi, I am an Aleo threshold signature!";
+
+            let key_shares = key_gen(&signature_scheme, 2, 3);
+            let threshold_public_key = key_shares[0].public_key().clone();
+            for key_share in &key_shares {
+                assert_eq!(&threshold_public_key, key_share.public_key());
+            }
+            let public_key = threshold_public_key.to_public_key(&signature_scheme);
+
+            // Ensure the public key round-trips through bytes.
+            let recovered_public_key =
+                ThresholdPublicKey::read_le(&threshold_public_key.to_bytes_le().unwrap()[..]).unwrap();
+            assert_eq!(threshold_public_key, recovered_public_key);
+
+            // Ensure any 2 (or more) of the 3 participants can sign.
+            for signers in [vec![0, 1], vec![0, 2], vec![1, 2], vec![0, 1, 2]] {
+                let signers = signers.iter().map(|i| &key_shares[*i]).collect::<Vec<_>>();
+                let (commitments, shares) = sign(&signature_scheme, &signers, message);
+
+                let signature = threshold_public_key
+                    .aggregate(&signature_scheme, message, &commitments, &shares)
+                    .unwrap();
+                assert!(signature_scheme.verify(&public_key, message, &signature).unwrap());
+                assert!(!signature_scheme
+                    .verify(&public_key, b"Bad message", &signature)
+                    .unwrap());
+            }
+        }
+
+        #[test]
+        fn test_threshold_insufficient_signers() {
+            let signature_scheme = TestSignature::setup("test_threshold_insufficient_signers");
+            let rng = &mut thread_rng();
+
+            let key_shares = key_gen(&signature_scheme, 2, 3);
+            let (nonces, commitment) = key_shares[0].commit(&signature_scheme, rng);
+            assert!(key_shares[0]
+                .sign(&signature_scheme, nonces, b"message", &[commitment])
+                .is_err());
+        }
+
+        #[test]
+        fn test_threshold_invalid_signature_share() {
+            let signature_scheme = TestSignature::setup("test_threshold_invalid_signature_share");
+            let message = b"Hi, I am an Aleo threshold signature!";
+
+            let key_shares = key_gen(&signature_scheme, 2, 3);
+            let (commitments, mut shares) = sign(&signature_scheme, &[&key_shares[0], &key_shares[2]], message);
+
+            // Replace the share of participant 3 with its share on a different message.
+            shares[1] = sign(&signature_scheme, &[&key_shares[0], &key_shares[2]], b"Bad message").1[1].clone();
+
+            let result = key_shares[0]
+                .public_key()
+                .aggregate(&signature_scheme, message, &commitments, &shares);
+            assert!(result.unwrap_err().to_string().contains("Participant 3"));
+        }
+
+        #[test]
+        fn test_threshold_invalid_key_gen_share() {
+            let signature_scheme = TestSignature::setup("test_threshold_invalid_key_gen_share");
+            let rng = &mut thread_rng();
+            let parameters = ThresholdParameters::new(2, 2).unwrap();
+
+            let (participant_1, commitment_1) = ThresholdKeyGen::new(&signature_scheme, parameters, 1, rng).unwrap();
+            let (participant_2, commitment_2) = ThresholdKeyGen::new(&signature_scheme, parameters, 2, rng).unwrap();
+            let (other, _) = ThresholdKeyGen::<EdwardsBls12>::new(&signature_scheme, parameters, 2, rng).unwrap();
+
+            // Participant 1 receives a share that is inconsistent with the commitment of participant 2.
+            let commitments = vec![commitment_1, commitment_2];
+            let result = participant_1.finalize(&signature_scheme, &commitments, &[other.share(1).unwrap()]);
+            assert!(result.unwrap_err().to_string().contains("Participant 2"));
+
+            // Participant 2 receives a valid share from participant 1, but a forged commitment.
+            let mut forged = commitments.clone();
+            forged[0] = ThresholdKeyGen::new(&signature_scheme, parameters, 1, rng).unwrap().1;
+            let (participant_1, _) = ThresholdKeyGen::new(&signature_scheme, parameters, 1, rng).unwrap();
+            assert!(participant_2
+                .finalize(&signature_scheme, &forged, &[participant_1.share(2).unwrap()])
+                .is_err());
+        }
+
+        #[test]
+        fn test_threshold_parameters() {
+            assert!(ThresholdParameters::new(0, 3).is_err());
+            assert!(ThresholdParameters::new(4, 3).is_err());
+            assert!(ThresholdParameters::new(1, 1).is_ok());
+            assert!(ThresholdParameters::new(3, 3).is_ok());
+        }
+    }
 }
@@ -0,0 +1,1017 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Threshold (m-of-n) key generation and signing for the Aleo signature scheme.
+//!
+//! The protocol follows FROST: the signature root public key `G^sk_sig` is generated with a
+//! Pedersen distributed key generation, so that any `threshold` of the `num_participants`
+//! participants can jointly produce a signature, while no smaller coalition learns `sk_sig`.
+//! The signature root randomizer `G^r_sig` is the sum of a random contribution from each
+//! participant, and its discrete logarithm is never known to anyone.
+//!
+//! The resulting signatures are ordinary [`AleoSignature`]s, and verify with the unchanged
+//! [`SignatureScheme::verify`] against the public key from [`ThresholdPublicKey::to_public_key`].
+//!
+//! Key generation takes two rounds:
+//!   1. Each participant calls [`ThresholdKeyGen::new`] and broadcasts its [`KeyGenCommitment`].
+//!   2. Each participant calls [`ThresholdKeyGen::share`] for every other participant, and sends each
+//!      [`KeyGenShare`] to its recipient over a private channel. Each participant then calls
+//!      [`ThresholdKeyGen::finalize`] with all of the commitments and its received shares.
+//!
+//! Signing takes two rounds among at least `threshold` participants:
+//!   1. Each signer calls [`ThresholdKeyShare::commit`] and broadcasts its [`SigningCommitment`].
+//!   2. Each signer calls [`ThresholdKeyShare::sign`] with the message and all of the signing commitments,
+//!      and sends its [`SignatureShare`] to an aggregator, who calls [`ThresholdPublicKey::aggregate`].
+
+use crate::{
+    crypto_hash::PoseidonDefaultParametersField,
+    signature::{AleoSignature, AleoSignatureScheme},
+    SignatureError,
+    SignatureScheme,
+    SignatureSchemeOperations,
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    AffineCurve,
+    ProjectiveCurve,
+    TwistedEdwardsParameters,
+};
+use snarkvm_fields::{Field, One, ToConstraintField, Zero};
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    ops::Mul,
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+/// The domain separator for the proofs of knowledge in key generation.
+const KEY_GEN_DOMAIN: &str = "AleoThresholdKeyGen0";
+/// The domain separator for the binding factors in signing.
+const BINDING_FACTOR_DOMAIN: &str = "AleoThresholdBindingFactor0";
+
+/// The index of a participant in a threshold protocol, starting from 1.
+pub type ParticipantIndex = u16;
+
+/// The threshold and the number of participants of a threshold key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdParameters {
+    threshold: u16,
+    num_participants: u16,
+}
+
+impl ThresholdParameters {
+    /// Returns new threshold parameters, requiring `threshold` of `num_participants` participants to sign.
+    pub fn new(threshold: u16, num_participants: u16) -> Result<Self> {
+        if threshold == 0 || threshold > num_participants {
+            return Err(SignatureError::Message(format!(
+                "Invalid threshold of {} for {} participants",
+                threshold, num_participants
+            ))
+            .into());
+        }
+
+        Ok(Self {
+            threshold,
+            num_participants,
+        })
+    }
+
+    /// Returns the number of participants required to sign.
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the total number of participants.
+    pub fn num_participants(&self) -> u16 {
+        self.num_participants
+    }
+
+    /// Returns an error if the given index does not belong to a participant.
+    fn check_index(&self, index: ParticipantIndex) -> Result<()> {
+        match index >= 1 && index <= self.num_participants {
+            true => Ok(()),
+            false => Err(SignatureError::Message(format!("Invalid participant index {}", index)).into()),
+        }
+    }
+}
+
+impl FromBytes for ThresholdParameters {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let threshold = FromBytes::read_le(&mut reader)?;
+        let num_participants = FromBytes::read_le(&mut reader)?;
+        Self::new(threshold, num_participants).map_err(|_| error("Invalid threshold parameters"))
+    }
+}
+
+impl ToBytes for ThresholdParameters {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.threshold.write_le(&mut writer)?;
+        self.num_participants.write_le(&mut writer)
+    }
+}
+
+/// The secret state of a participant during key generation.
+#[derive(Derivative)]
+#[derivative(Debug(bound = "TE: TwistedEdwardsParameters"))]
+pub struct ThresholdKeyGen<TE: TwistedEdwardsParameters> {
+    parameters: ThresholdParameters,
+    index: ParticipantIndex,
+    /// The coefficients of the secret polynomial, starting with the constant term.
+    coefficients: Vec<TE::ScalarField>,
+}
+
+impl<TE: TwistedEdwardsParameters> ThresholdKeyGen<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    ///
+    /// Returns the key generation state of the participant with the given index,
+    /// along with its commitment to broadcast to every other participant.
+    ///
+    pub fn new<R: Rng + CryptoRng>(
+        signature_scheme: &AleoSignatureScheme<TE>,
+        parameters: ThresholdParameters,
+        index: ParticipantIndex,
+        rng: &mut R,
+    ) -> Result<(Self, KeyGenCommitment<TE>)> {
+        parameters.check_index(index)?;
+
+        // Sample the secret polynomial and the contribution to the root randomizer.
+        let coefficients = (0..parameters.threshold)
+            .map(|_| TE::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let randomizer = TE::ScalarField::rand(rng);
+
+        let coefficient_commitments = coefficients
+            .iter()
+            .map(|coefficient| signature_scheme.g_scalar_multiply(coefficient))
+            .collect::<Vec<_>>();
+        let root_randomizer = signature_scheme.g_scalar_multiply(&randomizer);
+
+        // Prove knowledge of the constant term and of the root randomizer contribution.
+        let (nonce_0, nonce_1) = (TE::ScalarField::rand(rng), TE::ScalarField::rand(rng));
+        let proof_challenge = key_gen_challenge(
+            signature_scheme,
+            index,
+            &coefficient_commitments[0],
+            &root_randomizer,
+            &signature_scheme.g_scalar_multiply(&nonce_0),
+            &signature_scheme.g_scalar_multiply(&nonce_1),
+        )?;
+        let proof_responses = (
+            nonce_0 + proof_challenge * coefficients[0],
+            nonce_1 + proof_challenge * randomizer,
+        );
+
+        let commitment = KeyGenCommitment {
+            index,
+            coefficient_commitments,
+            root_randomizer,
+            proof_challenge,
+            proof_responses,
+        };
+
+        Ok((
+            Self {
+                parameters,
+                index,
+                coefficients,
+            },
+            commitment,
+        ))
+    }
+
+    /// Returns the index of the participant.
+    pub fn index(&self) -> ParticipantIndex {
+        self.index
+    }
+
+    ///
+    /// Returns the secret share for the given recipient.
+    /// The share must be sent to the recipient over a private, authenticated channel.
+    ///
+    pub fn share(&self, recipient: ParticipantIndex) -> Result<KeyGenShare<TE>> {
+        self.parameters.check_index(recipient)?;
+
+        Ok(KeyGenShare {
+            sender: self.index,
+            recipient,
+            share: evaluate_polynomial(&self.coefficients, recipient),
+        })
+    }
+
+    ///
+    /// Returns the key share of the participant, given the commitments of all participants
+    /// (including its own), and the secret shares sent to it by every other participant.
+    ///
+    pub fn finalize(
+        self,
+        signature_scheme: &AleoSignatureScheme<TE>,
+        commitments: &[KeyGenCommitment<TE>],
+        shares: &[KeyGenShare<TE>],
+    ) -> Result<ThresholdKeyShare<TE>> {
+        let num_participants = self.parameters.num_participants as usize;
+
+        // Ensure there is exactly one valid commitment from each participant.
+        if commitments.len() != num_participants {
+            return Err(SignatureError::Message(format!(
+                "Expected {} key generation commitments, found {}",
+                num_participants,
+                commitments.len()
+            ))
+            .into());
+        }
+        let mut commitments = commitments.iter().collect::<Vec<_>>();
+        commitments.sort_by_key(|commitment| commitment.index);
+        for (expected_index, commitment) in (1..).zip(&commitments) {
+            if commitment.index != expected_index {
+                return Err(SignatureError::Message(format!(
+                    "Missing the key generation commitment of participant {}",
+                    expected_index
+                ))
+                .into());
+            }
+            if !commitment.is_valid(signature_scheme, self.parameters)? {
+                return Err(SignatureError::Message(format!(
+                    "Participant {} sent an invalid key generation commitment",
+                    commitment.index
+                ))
+                .into());
+            }
+        }
+
+        // Ensure this participant's own commitment was not replaced.
+        let own_commitments = self.coefficients.iter().map(|c| signature_scheme.g_scalar_multiply(c));
+        if !own_commitments.eq(commitments[self.index as usize - 1]
+            .coefficient_commitments
+            .iter()
+            .copied())
+        {
+            return Err(
+                SignatureError::Message("Mismatching key generation commitment for this participant".into()).into(),
+            );
+        }
+
+        // Ensure there is exactly one share from each other participant, and that each share is consistent.
+        if shares.len() != num_participants - 1 {
+            return Err(SignatureError::Message(format!(
+                "Expected {} key generation shares, found {}",
+                num_participants - 1,
+                shares.len()
+            ))
+            .into());
+        }
+        let mut secret_share = evaluate_polynomial(&self.coefficients, self.index);
+        let mut senders = Vec::with_capacity(shares.len());
+        for share in shares {
+            if share.recipient != self.index || share.sender == self.index || senders.contains(&share.sender) {
+                return Err(SignatureError::Message(format!(
+                    "Unexpected key generation share from participant {} to participant {}",
+                    share.sender, share.recipient
+                ))
+                .into());
+            }
+            self.parameters.check_index(share.sender)?;
+
+            let expected = evaluate_commitments(
+                &commitments[share.sender as usize - 1].coefficient_commitments,
+                self.index,
+            );
+            if signature_scheme.g_scalar_multiply(&share.share).into_projective() != expected {
+                return Err(SignatureError::Message(format!(
+                    "Participant {} sent an invalid key generation share",
+                    share.sender
+                ))
+                .into());
+            }
+
+            secret_share += share.share;
+            senders.push(share.sender);
+        }
+
+        // Compute the root public key, root randomizer, and the verification share of each participant.
+        let root_public_key = commitments
+            .iter()
+            .map(|commitment| commitment.coefficient_commitments[0].into_projective())
+            .sum::<TEProjective<TE>>()
+            .into_affine();
+        let root_randomizer = commitments
+            .iter()
+            .map(|commitment| commitment.root_randomizer.into_projective())
+            .sum::<TEProjective<TE>>()
+            .into_affine();
+        let verification_shares = (1..=self.parameters.num_participants)
+            .map(|index| {
+                commitments
+                    .iter()
+                    .map(|commitment| evaluate_commitments(&commitment.coefficient_commitments, index))
+                    .sum::<TEProjective<TE>>()
+                    .into_affine()
+            })
+            .collect::<Vec<_>>();
+
+        Ok(ThresholdKeyShare {
+            index: self.index,
+            secret_share,
+            public_key: ThresholdPublicKey {
+                parameters: self.parameters,
+                root_public_key,
+                root_randomizer,
+                verification_shares,
+            },
+        })
+    }
+}
+
+/// The round 1 key generation message, broadcast by each participant.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct KeyGenCommitment<TE: TwistedEdwardsParameters> {
+    index: ParticipantIndex,
+    /// The commitments to the coefficients of the secret polynomial.
+    coefficient_commitments: Vec<TEAffine<TE>>,
+    /// The contribution to the signature root randomizer.
+    root_randomizer: TEAffine<TE>,
+    /// The proof of knowledge of the constant term and the root randomizer contribution.
+    proof_challenge: TE::ScalarField,
+    proof_responses: (TE::ScalarField, TE::ScalarField),
+}
+
+impl<TE: TwistedEdwardsParameters> KeyGenCommitment<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    /// Returns the index of the sender.
+    pub fn index(&self) -> ParticipantIndex {
+        self.index
+    }
+
+    /// Returns `true` if the commitment is well-formed and its proof of knowledge is valid.
+    fn is_valid(&self, signature_scheme: &AleoSignatureScheme<TE>, parameters: ThresholdParameters) -> Result<bool> {
+        if self.coefficient_commitments.len() != parameters.threshold as usize
+            || !self.coefficient_commitments.iter().all(is_valid_point)
+            || !is_valid_point(&self.root_randomizer)
+        {
+            return Ok(false);
+        }
+
+        // Recompute the proof nonce commitments, and check them against the proof challenge.
+        let nonce_commitment = |response: &TE::ScalarField, point: &TEAffine<TE>| {
+            (signature_scheme.g_scalar_multiply(response).into_projective()
+                - point.into_projective().mul(self.proof_challenge))
+            .into_affine()
+        };
+        let candidate_challenge = key_gen_challenge(
+            signature_scheme,
+            self.index,
+            &self.coefficient_commitments[0],
+            &self.root_randomizer,
+            &nonce_commitment(&self.proof_responses.0, &self.coefficient_commitments[0]),
+            &nonce_commitment(&self.proof_responses.1, &self.root_randomizer),
+        )?;
+
+        Ok(candidate_challenge == self.proof_challenge)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for KeyGenCommitment<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = FromBytes::read_le(&mut reader)?;
+        let num_coefficients: u16 = FromBytes::read_le(&mut reader)?;
+        let mut coefficient_commitments = Vec::with_capacity(num_coefficients as usize);
+        for _ in 0..num_coefficients {
+            coefficient_commitments.push(FromBytes::read_le(&mut reader)?);
+        }
+        let root_randomizer = FromBytes::read_le(&mut reader)?;
+        let proof_challenge = FromBytes::read_le(&mut reader)?;
+        let proof_responses = (FromBytes::read_le(&mut reader)?, FromBytes::read_le(&mut reader)?);
+
+        Ok(Self {
+            index,
+            coefficient_commitments,
+            root_randomizer,
+            proof_challenge,
+            proof_responses,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for KeyGenCommitment<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        (self.coefficient_commitments.len() as u16).write_le(&mut writer)?;
+        for commitment in &self.coefficient_commitments {
+            commitment.write_le(&mut writer)?;
+        }
+        self.root_randomizer.write_le(&mut writer)?;
+        self.proof_challenge.write_le(&mut writer)?;
+        self.proof_responses.0.write_le(&mut writer)?;
+        self.proof_responses.1.write_le(&mut writer)
+    }
+}
+
+/// The round 2 key generation message, sent privately from one participant to another.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct KeyGenShare<TE: TwistedEdwardsParameters> {
+    sender: ParticipantIndex,
+    recipient: ParticipantIndex,
+    share: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> KeyGenShare<TE> {
+    /// Returns the index of the sender.
+    pub fn sender(&self) -> ParticipantIndex {
+        self.sender
+    }
+
+    /// Returns the index of the recipient.
+    pub fn recipient(&self) -> ParticipantIndex {
+        self.recipient
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for KeyGenShare<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let sender = FromBytes::read_le(&mut reader)?;
+        let recipient = FromBytes::read_le(&mut reader)?;
+        let share = FromBytes::read_le(&mut reader)?;
+        Ok(Self {
+            sender,
+            recipient,
+            share,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for KeyGenShare<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.sender.write_le(&mut writer)?;
+        self.recipient.write_le(&mut writer)?;
+        self.share.write_le(&mut writer)
+    }
+}
+
+/// The public key of a threshold account, along with the verification share of each participant.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct ThresholdPublicKey<TE: TwistedEdwardsParameters> {
+    parameters: ThresholdParameters,
+    /// The signature root public key, G^sk_sig.
+    root_public_key: TEAffine<TE>,
+    /// The signature root randomizer, G^r_sig.
+    root_randomizer: TEAffine<TE>,
+    /// The public key share G^sk_i of each participant.
+    verification_shares: Vec<TEAffine<TE>>,
+}
+
+impl<TE: TwistedEdwardsParameters> ThresholdPublicKey<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    /// Returns the threshold parameters.
+    pub fn parameters(&self) -> ThresholdParameters {
+        self.parameters
+    }
+
+    ///
+    /// Returns the public key as (G^sk_sig G^r_sig G^sk_prf), which verifies the aggregated signatures.
+    ///
+    pub fn to_public_key(&self, signature_scheme: &AleoSignatureScheme<TE>) -> TEAffine<TE> {
+        // Compute sk_prf := RO(G^sk_sig || G^r_sig).
+        let sk_prf = signature_scheme.hash_to_scalar_field(&[self.root_public_key.x, self.root_randomizer.x]);
+
+        // Compute G^sk_sig G^r_sig G^sk_prf.
+        self.root_public_key + self.root_randomizer + signature_scheme.g_scalar_multiply(&sk_prf)
+    }
+
+    ///
+    /// Returns the signature on the given message, aggregated from the signature shares.
+    /// Returns an error identifying the participant, if a signature share is invalid.
+    ///
+    pub fn aggregate(
+        &self,
+        signature_scheme: &AleoSignatureScheme<TE>,
+        message: &[u8],
+        commitments: &[SigningCommitment<TE>],
+        shares: &[SignatureShare<TE>],
+    ) -> Result<AleoSignature<TE>> {
+        let context = SigningContext::new(signature_scheme, self, message, commitments)?;
+
+        if shares.len() != context.commitments.len() {
+            return Err(SignatureError::Message(format!(
+                "Expected {} signature shares, found {}",
+                context.commitments.len(),
+                shares.len()
+            ))
+            .into());
+        }
+
+        let mut prover_response = TE::ScalarField::zero();
+        for (position, commitment) in context.commitments.iter().enumerate() {
+            let share = match shares.iter().find(|share| share.index == commitment.index) {
+                Some(share) => share,
+                None => {
+                    return Err(SignatureError::Message(format!(
+                        "Missing the signature share of participant {}",
+                        commitment.index
+                    ))
+                    .into());
+                }
+            };
+
+            // Check G^z_i G^sk_i^(c * lambda_i) == D_i E_i^rho_i.
+            let verification_share = self.verification_shares[commitment.index as usize - 1].into_projective();
+            let candidate = signature_scheme.g_scalar_multiply(&share.response).into_projective()
+                + verification_share.mul(context.verifier_challenge * context.lagrange_coefficients[position]);
+            if candidate != context.signer_commitments[position] {
+                return Err(SignatureError::Message(format!(
+                    "Participant {} sent an invalid signature share",
+                    share.index
+                ))
+                .into());
+            }
+
+            prover_response += share.response;
+        }
+
+        let signature = AleoSignature {
+            prover_response,
+            verifier_challenge: context.verifier_challenge,
+            root_public_key: self.root_public_key.x,
+            root_randomizer: self.root_randomizer.x,
+        };
+
+        match signature_scheme.verify(&self.to_public_key(signature_scheme), message, &signature)? {
+            true => Ok(signature),
+            false => Err(SignatureError::Message("Failed to aggregate a valid signature".into()).into()),
+        }
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for ThresholdPublicKey<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let parameters: ThresholdParameters = FromBytes::read_le(&mut reader)?;
+        let root_public_key = FromBytes::read_le(&mut reader)?;
+        let root_randomizer = FromBytes::read_le(&mut reader)?;
+        let mut verification_shares = Vec::with_capacity(parameters.num_participants as usize);
+        for _ in 0..parameters.num_participants {
+            verification_shares.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self {
+            parameters,
+            root_public_key,
+            root_randomizer,
+            verification_shares,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for ThresholdPublicKey<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.parameters.write_le(&mut writer)?;
+        self.root_public_key.write_le(&mut writer)?;
+        self.root_randomizer.write_le(&mut writer)?;
+        for verification_share in &self.verification_shares {
+            verification_share.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// The secret key share of a participant in a threshold account.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct ThresholdKeyShare<TE: TwistedEdwardsParameters> {
+    index: ParticipantIndex,
+    secret_share: TE::ScalarField,
+    public_key: ThresholdPublicKey<TE>,
+}
+
+impl<TE: TwistedEdwardsParameters> ThresholdKeyShare<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    /// Returns the index of the participant.
+    pub fn index(&self) -> ParticipantIndex {
+        self.index
+    }
+
+    /// Returns the threshold public key.
+    pub fn public_key(&self) -> &ThresholdPublicKey<TE> {
+        &self.public_key
+    }
+
+    ///
+    /// Returns fresh signing nonces, along with the signing commitment to broadcast to the other signers.
+    /// The nonces must be used for at most one signature.
+    ///
+    pub fn commit<R: Rng + CryptoRng>(
+        &self,
+        signature_scheme: &AleoSignatureScheme<TE>,
+        rng: &mut R,
+    ) -> (SigningNonces<TE>, SigningCommitment<TE>) {
+        let nonces = SigningNonces {
+            index: self.index,
+            hiding: TE::ScalarField::rand(rng),
+            binding: TE::ScalarField::rand(rng),
+        };
+        let commitment = nonces.to_commitment(signature_scheme);
+        (nonces, commitment)
+    }
+
+    ///
+    /// Returns the signature share on the given message, given the signing commitments of all signers.
+    ///
+    pub fn sign(
+        &self,
+        signature_scheme: &AleoSignatureScheme<TE>,
+        nonces: SigningNonces<TE>,
+        message: &[u8],
+        commitments: &[SigningCommitment<TE>],
+    ) -> Result<SignatureShare<TE>> {
+        let context = SigningContext::new(signature_scheme, &self.public_key, message, commitments)?;
+
+        // Ensure the signing commitment of this participant is present and matches its nonces.
+        let position = match context
+            .commitments
+            .iter()
+            .position(|commitment| commitment.index == self.index)
+        {
+            Some(position) => position,
+            None => {
+                return Err(
+                    SignatureError::Message("Missing the signing commitment for this participant".into()).into(),
+                )
+            }
+        };
+        if nonces.index != self.index || *context.commitments[position] != nonces.to_commitment(signature_scheme) {
+            return Err(SignatureError::Message("Mismatching signing commitment for this participant".into()).into());
+        }
+
+        // Compute z_i := d_i + e_i * rho_i - c * lambda_i * sk_i.
+        let response = nonces.hiding + nonces.binding * context.binding_factors[position]
+            - context.verifier_challenge * context.lagrange_coefficients[position] * self.secret_share;
+
+        Ok(SignatureShare {
+            index: self.index,
+            response,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for ThresholdKeyShare<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = FromBytes::read_le(&mut reader)?;
+        let secret_share = FromBytes::read_le(&mut reader)?;
+        let public_key: ThresholdPublicKey<TE> = FromBytes::read_le(&mut reader)?;
+        if public_key.parameters.check_index(index).is_err() {
+            return Err(error("Invalid participant index in threshold key share"));
+        }
+
+        Ok(Self {
+            index,
+            secret_share,
+            public_key,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for ThresholdKeyShare<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.secret_share.write_le(&mut writer)?;
+        self.public_key.write_le(&mut writer)
+    }
+}
+
+/// The secret signing nonces of a signer, for a single signature.
+#[derive(Derivative)]
+#[derivative(Debug(bound = "TE: TwistedEdwardsParameters"))]
+pub struct SigningNonces<TE: TwistedEdwardsParameters> {
+    index: ParticipantIndex,
+    hiding: TE::ScalarField,
+    binding: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> SigningNonces<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    fn to_commitment(&self, signature_scheme: &AleoSignatureScheme<TE>) -> SigningCommitment<TE> {
+        SigningCommitment {
+            index: self.index,
+            hiding: signature_scheme.g_scalar_multiply(&self.hiding),
+            binding: signature_scheme.g_scalar_multiply(&self.binding),
+        }
+    }
+}
+
+/// The round 1 signing message, broadcast by each signer.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct SigningCommitment<TE: TwistedEdwardsParameters> {
+    index: ParticipantIndex,
+    hiding: TEAffine<TE>,
+    binding: TEAffine<TE>,
+}
+
+impl<TE: TwistedEdwardsParameters> SigningCommitment<TE> {
+    /// Returns the index of the signer.
+    pub fn index(&self) -> ParticipantIndex {
+        self.index
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for SigningCommitment<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = FromBytes::read_le(&mut reader)?;
+        let hiding = FromBytes::read_le(&mut reader)?;
+        let binding = FromBytes::read_le(&mut reader)?;
+        Ok(Self { index, hiding, binding })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for SigningCommitment<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.hiding.write_le(&mut writer)?;
+        self.binding.write_le(&mut writer)
+    }
+}
+
+/// The round 2 signing message, sent by each signer to the aggregator.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct SignatureShare<TE: TwistedEdwardsParameters> {
+    index: ParticipantIndex,
+    response: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> SignatureShare<TE> {
+    /// Returns the index of the signer.
+    pub fn index(&self) -> ParticipantIndex {
+        self.index
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for SignatureShare<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = FromBytes::read_le(&mut reader)?;
+        let response = FromBytes::read_le(&mut reader)?;
+        Ok(Self { index, response })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for SignatureShare<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+/// The values shared by all signers of a message, derived from the signing commitments.
+struct SigningContext<'a, TE: TwistedEdwardsParameters> {
+    /// The signing commitments, sorted by index.
+    commitments: Vec<&'a SigningCommitment<TE>>,
+    /// The binding factor rho_i of each signer.
+    binding_factors: Vec<TE::ScalarField>,
+    /// The Lagrange coefficient lambda_i of each signer.
+    lagrange_coefficients: Vec<TE::ScalarField>,
+    /// The commitment D_i E_i^rho_i of each signer.
+    signer_commitments: Vec<TEProjective<TE>>,
+    /// The verifier challenge c.
+    verifier_challenge: TE::ScalarField,
+}
+
+impl<'a, TE: TwistedEdwardsParameters> SigningContext<'a, TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    fn new(
+        signature_scheme: &AleoSignatureScheme<TE>,
+        public_key: &ThresholdPublicKey<TE>,
+        message: &[u8],
+        commitments: &'a [SigningCommitment<TE>],
+    ) -> Result<Self> {
+        // Ensure there are enough signers, each with a unique index and well-formed commitments.
+        if commitments.len() < public_key.parameters.threshold as usize {
+            return Err(SignatureError::Message(format!(
+                "Expected at least {} signing commitments, found {}",
+                public_key.parameters.threshold,
+                commitments.len()
+            ))
+            .into());
+        }
+        let mut commitments = commitments.iter().collect::<Vec<_>>();
+        commitments.sort_by_key(|commitment| commitment.index);
+        for (position, commitment) in commitments.iter().enumerate() {
+            public_key.parameters.check_index(commitment.index)?;
+            if position > 0 && commitments[position - 1].index == commitment.index {
+                return Err(SignatureError::Message(format!(
+                    "Duplicate signing commitments from participant {}",
+                    commitment.index
+                ))
+                .into());
+            }
+            if !is_valid_point(&commitment.hiding) || !is_valid_point(&commitment.binding) {
+                return Err(SignatureError::Message(format!(
+                    "Participant {} sent an invalid signing commitment",
+                    commitment.index
+                ))
+                .into());
+            }
+        }
+
+        let group_public_key = public_key.to_public_key(signature_scheme);
+
+        // Compute the binding factor of each signer as RO(domain, G^sk_sig G^r_sig G^sk_prf, message, commitments, index).
+        let mut preimage = vec![];
+        preimage.extend_from_slice(&BINDING_FACTOR_DOMAIN.as_bytes().to_field_elements()?);
+        preimage.push(group_public_key.x);
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+        for commitment in &commitments {
+            preimage.push(TE::BaseField::from(commitment.index as u128));
+            preimage.push(commitment.hiding.x);
+            preimage.push(commitment.binding.x);
+        }
+        let binding_factors = commitments
+            .iter()
+            .map(|commitment| {
+                let mut preimage = preimage.clone();
+                preimage.push(TE::BaseField::from(commitment.index as u128));
+                signature_scheme.hash_to_scalar_field(&preimage)
+            })
+            .collect::<Vec<_>>();
+
+        // Compute the commitment of each signer as D_i E_i^rho_i, and the group commitment G^r as their sum.
+        let signer_commitments = commitments
+            .iter()
+            .zip(&binding_factors)
+            .map(|(commitment, binding_factor)| {
+                commitment.hiding.into_projective() + commitment.binding.into_projective().mul(*binding_factor)
+            })
+            .collect::<Vec<_>>();
+        let g_r = signer_commitments
+            .iter()
+            .copied()
+            .sum::<TEProjective<TE>>()
+            .into_affine();
+
+        // Compute the verifier challenge, as in the single-party signature scheme.
+        let verifier_challenge = signature_scheme.compute_verifier_challenge(&group_public_key, &g_r, message)?;
+
+        // Compute the Lagrange coefficient of each signer, at zero.
+        let indices = commitments
+            .iter()
+            .map(|commitment| commitment.index)
+            .collect::<Vec<_>>();
+        let lagrange_coefficients = indices
+            .iter()
+            .map(|index| lagrange_coefficient::<TE>(*index, &indices))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            commitments,
+            binding_factors,
+            lagrange_coefficients,
+            signer_commitments,
+            verifier_challenge,
+        })
+    }
+}
+
+/// Returns the challenge for the key generation proof of knowledge.
+fn key_gen_challenge<TE: TwistedEdwardsParameters>(
+    signature_scheme: &AleoSignatureScheme<TE>,
+    index: ParticipantIndex,
+    constant_commitment: &TEAffine<TE>,
+    root_randomizer: &TEAffine<TE>,
+    nonce_commitment_0: &TEAffine<TE>,
+    nonce_commitment_1: &TEAffine<TE>,
+) -> Result<TE::ScalarField>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    let mut preimage = vec![];
+    preimage.extend_from_slice(&KEY_GEN_DOMAIN.as_bytes().to_field_elements()?);
+    preimage.push(TE::BaseField::from(index as u128));
+    preimage.push(constant_commitment.x);
+    preimage.push(root_randomizer.x);
+    preimage.push(nonce_commitment_0.x);
+    preimage.push(nonce_commitment_1.x);
+    Ok(signature_scheme.hash_to_scalar_field(&preimage))
+}
+
+/// Returns the evaluation of the polynomial with the given coefficients at the given index.
+fn evaluate_polynomial<F: Field>(coefficients: &[F], index: ParticipantIndex) -> F {
+    let x = F::from(index as u128);
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+/// Returns the evaluation "in the exponent" of the committed polynomial at the given index.
+fn evaluate_commitments<TE: TwistedEdwardsParameters>(
+    commitments: &[TEAffine<TE>],
+    index: ParticipantIndex,
+) -> TEProjective<TE> {
+    let x = TE::ScalarField::from(index as u128);
+    commitments
+        .iter()
+        .rev()
+        .fold(TEProjective::<TE>::zero(), |acc, commitment| {
+            acc.mul(x) + commitment.into_projective()
+        })
+}
+
+/// Returns the Lagrange coefficient at zero of the given index, over the given set of indices.
+fn lagrange_coefficient<TE: TwistedEdwardsParameters>(
+    index: ParticipantIndex,
+    indices: &[ParticipantIndex],
+) -> Result<TE::ScalarField> {
+    let x_i = TE::ScalarField::from(index as u128);
+
+    let mut numerator = TE::ScalarField::one();
+    let mut denominator = TE::ScalarField::one();
+    for j in indices.iter().filter(|j| **j != index) {
+        let x_j = TE::ScalarField::from(*j as u128);
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+
+    match denominator.inverse() {
+        Some(inverse) => Ok(numerator * inverse),
+        None => Err(SignatureError::Message("Failed to compute a Lagrange coefficient".into()).into()),
+    }
+}
+
+/// Returns `true` if the given point is a non-zero element of the prime-order subgroup.
+fn is_valid_point<TE: TwistedEdwardsParameters>(point: &TEAffine<TE>) -> bool {
+    !point.is_zero() && point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()
+}
@@ -44,6 +44,22 @@ pub trait SignatureScheme:
     ) -> Result<Self::Signature>;
 
     fn verify(&self, public_key: &Self::PublicKey, message: &[u8], signature: &Self::Signature) -> Result<bool>;
+
+    /// Returns the indices of the entries that fail verification, which is empty if every signature is valid.
+    /// The given RNG is used by schemes that check the entries together with a randomized linear combination.
+    fn batch_verify<R: Rng + CryptoRng>(
+        &self,
+        entries: &[(Self::PublicKey, &[u8], Self::Signature)],
+        _rng: &mut R,
+    ) -> Result<Vec<usize>> {
+        let mut failures = vec![];
+        for (index, (public_key, message, signature)) in entries.iter().enumerate() {
+            if !matches!(self.verify(public_key, message, signature), Ok(true)) {
+                failures.push(index);
+            }
+        }
+        Ok(failures)
+    }
 }
 
 pub trait SignatureSchemeOperations {
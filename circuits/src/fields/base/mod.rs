@@ -82,6 +82,12 @@ impl<E: Environment> fmt::Debug for BaseField<E> {
 
 impl<E: Environment> BaseFieldTrait for BaseField<E> {}
 
+impl<E: Environment> From<LinearCombination<E::BaseField>> for BaseField<E> {
+    fn from(linear_combination: LinearCombination<E::BaseField>) -> Self {
+        Self(linear_combination)
+    }
+}
+
 impl<E: Environment> From<BaseField<E>> for LinearCombination<E::BaseField> {
     fn from(field: BaseField<E>) -> Self {
         field.0
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Double for ScalarField<E> {
+    type Output = ScalarField<E>;
+
+    fn double(self) -> Self::Output {
+        (&self).double()
+    }
+}
+
+impl<E: Environment> Double for &ScalarField<E> {
+    type Output = ScalarField<E>;
+
+    fn double(self) -> Self::Output {
+        self + self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 25;
+
+    fn check_double(
+        name: &str,
+        given: <Circuit as Environment>::ScalarField,
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let a = ScalarField::<Circuit>::new(mode, given);
+
+        Circuit::scoped(name, |scope| {
+            let expected = given.double();
+            let candidate = a.double();
+            assert_eq!(
+                expected,
+                candidate.eject_value(),
+                "{} != {} := ({} + {})",
+                expected,
+                candidate.eject_value(),
+                given,
+                given
+            );
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    fn run_test(mode: Mode, num_constants: usize, num_public: usize, num_private: usize, num_constraints: usize) {
+        let check = |name: &str, given| {
+            check_double(
+                &format!("{} ({:?})", name, mode),
+                given,
+                mode,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            )
+        };
+
+        for i in 0..ITERATIONS {
+            check(&format!("Double: a + a {}", i), UniformRand::rand(&mut thread_rng()));
+        }
+
+        // Check the boundaries of the field.
+        check("Double: 0 + 0", <Circuit as Environment>::ScalarField::zero());
+        check("Double: 1 + 1", <Circuit as Environment>::ScalarField::one());
+        check("Double: -1 + -1", -<Circuit as Environment>::ScalarField::one());
+    }
+
+    #[test]
+    fn test_double_constant() {
+        run_test(Mode::Constant, 251, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_double_public() {
+        run_test(Mode::Public, 1, 0, 502, 754);
+    }
+
+    #[test]
+    fn test_double_private() {
+        run_test(Mode::Private, 1, 0, 502, 754);
+    }
+}
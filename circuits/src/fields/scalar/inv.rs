@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Inv for ScalarField<E> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output {
+        (&self).inv()
+    }
+}
+
+impl<E: Environment> Inv for &ScalarField<E> {
+    type Output = ScalarField<E>;
+
+    fn inv(self) -> Self::Output {
+        let mode = match self.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        let inverse = match self.eject_value().inverse() {
+            Some(inverse) => inverse,
+            None => E::halt("Failed to compute the inverse for a scalar field element"),
+        };
+
+        let inverse = ScalarField::new(mode, inverse);
+
+        // Ensure self * self^(-1) == 1.
+        if !inverse.is_constant() {
+            inverse.enforce_canonical();
+            ScalarField::enforce_product(self, &inverse, &ScalarField::one());
+        }
+
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 25;
+
+    fn check_inv(
+        name: &str,
+        given: <Circuit as Environment>::ScalarField,
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let a = ScalarField::<Circuit>::new(mode, given);
+
+        Circuit::scoped(name, |scope| {
+            let expected = given.inverse().unwrap();
+            let candidate = a.inv();
+            assert_eq!(
+                expected,
+                candidate.eject_value(),
+                "{} != {} := ({}^(-1))",
+                expected,
+                candidate.eject_value(),
+                given
+            );
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    fn run_test(mode: Mode, num_constants: usize, num_public: usize, num_private: usize, num_constraints: usize) {
+        let check = |name: &str, given| {
+            check_inv(
+                &format!("{} ({:?})", name, mode),
+                given,
+                mode,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            )
+        };
+
+        for i in 0..ITERATIONS {
+            let given: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut thread_rng());
+            if !given.is_zero() {
+                check(&format!("Inv: a^(-1) {}", i), given);
+            }
+        }
+
+        // Check the boundaries of the field.
+        check("Inv: 1^(-1)", <Circuit as Environment>::ScalarField::one());
+        check("Inv: -1^(-1)", -<Circuit as Environment>::ScalarField::one());
+    }
+
+    #[test]
+    fn test_inv_zero_fails() {
+        let zero = <Circuit as Environment>::ScalarField::zero();
+
+        let result = std::panic::catch_unwind(|| ScalarField::<Circuit>::new(Mode::Constant, zero).inv());
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| ScalarField::<Circuit>::new(Mode::Private, zero).inv());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_inv_constant() {
+        run_test(Mode::Constant, 251, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_inv_public() {
+        run_test(Mode::Public, 252, 0, 1176, 1434);
+    }
+
+    #[test]
+    fn test_inv_private() {
+        run_test(Mode::Private, 252, 0, 1176, 1434);
+    }
+}
@@ -14,31 +14,39 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-// pub mod add;
-// pub mod div;
-// pub mod double;
-// pub mod inv;
-// pub mod mul;
-// pub mod neg;
-// pub mod square;
-// pub mod sub;
-
+pub mod add;
+pub mod div;
+pub mod double;
 pub mod equal;
+pub mod inv;
+pub mod mul;
+pub mod neg;
 pub mod one;
+pub mod square;
+pub mod sub;
 pub mod ternary;
 pub mod to_bits;
 pub mod zero;
 
-use crate::{traits::*, Boolean, Environment, Mode};
-use snarkvm_fields::{One as O, PrimeField, Zero as Z};
-use snarkvm_utilities::{FromBits as FBits, ToBits as TBits};
+use crate::{traits::*, BaseField, Boolean, Environment, LinearCombination, Mode};
+use snarkvm_fields::{Field as F, FieldParameters, One as O, PrimeField, Zero as Z};
+use snarkvm_utilities::{BigInteger, FromBits as FBits, ToBits as TBits};
 
-use std::fmt;
+use itertools::Itertools;
+use num_bigint::{BigInt, BigUint};
+use num_traits::Inv;
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
 #[derive(Clone)]
 pub struct ScalarField<E: Environment>(Vec<Boolean<E>>);
 
 impl<E: Environment> ScalarField<E> {
+    /// The number of bits in each limb, when checking a product of scalar field elements in the base field.
+    const LIMB_BITS: usize = 64;
+
     ///
     /// Initializes a new instance of a scalar field from a constant scalar field value.
     ///
@@ -71,8 +79,195 @@ impl<E: Environment> ScalarField<E> {
             None => E::halt("Failed to eject scalar field value"),
         }
     }
+
+    ///
+    /// Returns the scalar field element `value mod MODULUS`, for a `value` in `[0, 2 * MODULUS)`.
+    ///
+    /// The caller provides `is_overflow`, which is `true` if `value` is at least the modulus,
+    /// and the expected `remainder`, which are witnessed and checked in the circuit.
+    ///
+    fn reduce_once(value: LinearCombination<E::BaseField>, is_overflow: bool, remainder: E::ScalarField) -> Self {
+        let quotient = Boolean::<E>::new(Mode::Private, is_overflow);
+        let remainder = Self::new(Mode::Private, remainder);
+        remainder.enforce_canonical();
+
+        // Ensure `value == quotient * MODULUS + remainder`.
+        E::assert_eq(
+            value,
+            LinearCombination::from(&quotient) * Self::modulus_in_base_field() + remainder.to_lc(),
+        );
+
+        remainder
+    }
+
+    ///
+    /// Enforces that `a * b == c (mod MODULUS)`.
+    ///
+    /// As the product of two scalar field elements may not fit in the base field, the quotient
+    /// of `a * b` by the modulus is witnessed, and `a * b == quotient * MODULUS + c` is checked
+    /// over the integers, by splitting each term into limbs and propagating the carries.
+    ///
+    fn enforce_product(a: &Self, b: &Self, c: &Self) {
+        let modulus = Self::modulus();
+        let num_bits = <E::ScalarField as PrimeField>::size_in_bits();
+
+        // Compute the quotient, and witness it in the circuit.
+        let quotient = (Self::to_biguint(a.eject_value()) * Self::to_biguint(b.eject_value())
+            - Self::to_biguint(c.eject_value()))
+            / &modulus;
+        let mode = match a.is_constant() && b.is_constant() && c.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let quotient = (0..num_bits)
+            .map(|i| Boolean::new(mode, quotient.bit(i as u64)))
+            .collect::<Vec<_>>();
+
+        // Split each term into limbs.
+        let a = Self::to_limbs(&a.0);
+        let b = Self::to_limbs(&b.0);
+        let c = Self::to_limbs(&c.0);
+        let quotient = Self::to_limbs(&quotient);
+        let modulus = Self::to_bits(&modulus, num_bits)
+            .chunks(Self::LIMB_BITS)
+            .map(|limb| (E::one() * Self::bits_to_base_field(limb)).into())
+            .collect::<Vec<BaseField<E>>>();
+
+        // Compute the limbs of `a * b` and `quotient * MODULUS + c`.
+        let num_limbs = a.len();
+        let mut lhs = vec![BaseField::<E>::zero(); 2 * num_limbs - 1];
+        let mut rhs = vec![BaseField::<E>::zero(); 2 * num_limbs - 1];
+        for i in 0..num_limbs {
+            for j in 0..num_limbs {
+                lhs[i + j] += &a[i] * &b[j];
+                rhs[i + j] += &quotient[i] * &modulus[j];
+            }
+            rhs[i] += &c[i];
+        }
+
+        // Each carry is less than `(num_limbs + 2) * 2^LIMB_BITS` in absolute value,
+        // and is witnessed with an offset of `2^(num_carry_bits - 1)`, to ensure it is non-negative.
+        let num_carry_bits = Self::LIMB_BITS + (num_limbs + 2).next_power_of_two().trailing_zeros() as usize + 1;
+        let offset = BigUint::from(1u64) << (num_carry_bits - 1);
+        let carry_offset = Self::bits_to_base_field(&Self::to_bits(&offset, num_carry_bits));
+        let limb_shift = Self::bits_to_base_field(&Self::to_bits(
+            &(BigUint::from(1u64) << Self::LIMB_BITS),
+            Self::LIMB_BITS + 1,
+        ));
+
+        // Ensure `lhs == rhs` over the integers, by checking each limb matches up to the carries.
+        let mut carry = E::zero();
+        let mut carry_value = BigInt::from(0u64);
+        for (i, (lhs, rhs)) in lhs.iter().zip_eq(rhs.iter()).enumerate() {
+            let difference = LinearCombination::from(lhs) - LinearCombination::from(rhs) + &carry;
+            let difference_value = BigInt::from(Self::to_biguint(lhs.eject_value()))
+                - BigInt::from(Self::to_biguint(rhs.eject_value()))
+                + &carry_value;
+
+            match i == 2 * num_limbs - 2 {
+                // Ensure the most significant limb has no carry.
+                true => E::assert_eq(difference, E::zero()),
+                // Ensure `difference == carry * 2^LIMB_BITS`, for the next carry.
+                false => {
+                    carry_value = difference_value >> Self::LIMB_BITS;
+                    let carry_bits = match (&carry_value + BigInt::from(offset.clone())).to_biguint() {
+                        Some(shifted) => Self::to_bits(&shifted, num_carry_bits),
+                        None => E::halt("Failed to witness a carry for the product of scalar field elements"),
+                    };
+                    let carry_bits = carry_bits
+                        .into_iter()
+                        .map(|bit| Boolean::new(mode, bit))
+                        .collect::<Vec<_>>();
+
+                    carry = Self::bits_to_lc(&carry_bits) - E::one() * carry_offset;
+                    E::assert_eq(difference, carry.clone() * limb_shift);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Enforces that the scalar field element is less than the modulus.
+    ///
+    fn enforce_canonical(&self) {
+        let modulus_minus_one = Self::to_bits(&(Self::modulus() - 1u64), self.0.len());
+
+        // Compare the bits from least to most significant, where `is_less_than_or_equal`
+        // holds if the bits seen so far are less than or equal to those of `MODULUS - 1`.
+        let mut is_less_than_or_equal = Boolean::new(Mode::Constant, true);
+        for (bit, modulus_bit) in self.0.iter().zip(modulus_minus_one) {
+            is_less_than_or_equal = match modulus_bit {
+                true => (!bit.clone()).or(&is_less_than_or_equal),
+                false => (!bit.clone()).and(&is_less_than_or_equal),
+            };
+        }
+
+        E::assert(&is_less_than_or_equal);
+    }
+
+    /// Returns the scalar field element as a linear combination in the base field.
+    fn to_lc(&self) -> LinearCombination<E::BaseField> {
+        Self::bits_to_lc(&self.0)
+    }
+
+    /// Returns the limbs of the given bits, as base field elements.
+    fn to_limbs(bits_le: &[Boolean<E>]) -> Vec<BaseField<E>> {
+        bits_le
+            .chunks(Self::LIMB_BITS)
+            .map(|limb| Self::bits_to_lc(limb).into())
+            .collect()
+    }
+
+    /// Returns the linear combination of the given bits, in the base field.
+    fn bits_to_lc(bits_le: &[Boolean<E>]) -> LinearCombination<E::BaseField> {
+        let mut accumulator = E::zero();
+        let mut coefficient = E::BaseField::one();
+
+        for bit in bits_le {
+            accumulator += LinearCombination::from(bit) * coefficient;
+            coefficient = coefficient.double();
+        }
+
+        accumulator
+    }
+
+    /// Returns the modulus of the scalar field.
+    fn modulus() -> BigUint {
+        <E::ScalarField as PrimeField>::Parameters::MODULUS.to_biguint()
+    }
+
+    /// Returns the modulus of the scalar field, as a base field element.
+    fn modulus_in_base_field() -> E::BaseField {
+        Self::bits_to_base_field(&Self::to_bits(
+            &Self::modulus(),
+            <E::ScalarField as PrimeField>::size_in_bits(),
+        ))
+    }
+
+    /// Returns the given field element as an integer.
+    fn to_biguint<F: PrimeField>(value: F) -> BigUint {
+        value.to_repr().to_biguint()
+    }
+
+    /// Returns the `num_bits` least significant bits of the given integer.
+    fn to_bits(value: &BigUint, num_bits: usize) -> Vec<bool> {
+        (0..num_bits).map(|i| value.bit(i as u64)).collect()
+    }
+
+    /// Returns the base field element of the given bits.
+    fn bits_to_base_field(bits_le: &[bool]) -> E::BaseField {
+        bits_le
+            .iter()
+            .rev()
+            .fold(E::BaseField::zero(), |accumulator, bit| match bit {
+                true => accumulator.double() + E::BaseField::one(),
+                false => accumulator.double(),
+            })
+    }
 }
 
+impl<E: Environment> ScalarFieldTrait for ScalarField<E> {}
+
 impl<E: Environment> fmt::Debug for ScalarField<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.eject_value())
@@ -82,11 +277,14 @@ impl<E: Environment> fmt::Debug for ScalarField<E> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Circuit;
+    use crate::{Circuit, Native};
     use snarkvm_utilities::UniformRand;
 
     use rand::thread_rng;
-    use std::str::FromStr;
+    use std::{
+        panic::{self, AssertUnwindSafe},
+        str::FromStr,
+    };
 
     const ITERATIONS: usize = 250;
 
@@ -140,6 +338,55 @@ mod tests {
         check_new("Private", expected, Mode::Private, 0, 0, 251, 251);
     }
 
+    #[test]
+    fn test_enforce_canonical() {
+        // Check that the largest scalar field element is canonical.
+        Circuit::scoped("Canonical: MODULUS - 1", |_| {
+            let candidate = ScalarField::<Circuit>::new(Mode::Private, -<Circuit as Environment>::ScalarField::one());
+            candidate.enforce_canonical();
+            assert!(Circuit::is_satisfied());
+        });
+
+        // Check that the modulus is not canonical.
+        Circuit::scoped("Canonical: MODULUS", |_| {
+            let bits = ScalarField::<Circuit>::to_bits(&ScalarField::<Circuit>::modulus(), 251);
+            let candidate =
+                ScalarField::<Circuit>(bits.into_iter().map(|bit| Boolean::new(Mode::Private, bit)).collect());
+            candidate.enforce_canonical();
+            assert!(!Circuit::is_satisfied());
+        });
+        Circuit::reset_circuit();
+
+        // Check that the modulus halts natively.
+        let bits = ScalarField::<Native>::to_bits(&ScalarField::<Native>::modulus(), 251);
+        let candidate = ScalarField::<Native>(bits.into_iter().map(|bit| Boolean::new(Mode::Private, bit)).collect());
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| candidate.enforce_canonical())).is_err());
+        assert!(!Native::is_satisfied());
+        Native::reset();
+    }
+
+    #[test]
+    fn test_enforce_product() {
+        let one = <Circuit as Environment>::ScalarField::one();
+
+        // Check that (-1) * (-1) == 1.
+        Circuit::scoped("Product: -1 * -1 == 1", |_| {
+            let a = ScalarField::<Circuit>::new(Mode::Private, -one);
+            let c = ScalarField::<Circuit>::new(Mode::Private, one);
+            ScalarField::enforce_product(&a, &a, &c);
+            assert!(Circuit::is_satisfied());
+        });
+
+        // Check that (-1) * (-1) != 2.
+        Circuit::scoped("Product: -1 * -1 != 2", |_| {
+            let a = ScalarField::<Circuit>::new(Mode::Private, -one);
+            let c = ScalarField::<Circuit>::new(Mode::Private, one + one);
+            ScalarField::enforce_product(&a, &a, &c);
+            assert!(!Circuit::is_satisfied());
+        });
+        Circuit::reset_circuit();
+    }
+
     #[test]
     fn test_debug() {
         for _ in 0..ITERATIONS {
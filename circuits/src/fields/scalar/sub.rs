@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Sub<ScalarField<E>> for ScalarField<E> {
+    type Output = ScalarField<E>;
+
+    fn sub(self, other: ScalarField<E>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<ScalarField<E>> for &ScalarField<E> {
+    type Output = ScalarField<E>;
+
+    fn sub(self, other: ScalarField<E>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<&ScalarField<E>> for ScalarField<E> {
+    type Output = ScalarField<E>;
+
+    fn sub(self, other: &ScalarField<E>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment> Sub<&ScalarField<E>> for &ScalarField<E> {
+    type Output = ScalarField<E>;
+
+    fn sub(self, other: &ScalarField<E>) -> Self::Output {
+        let (first, second) = (self.eject_value(), other.eject_value());
+        let difference = first - second;
+
+        match self.is_constant() && other.is_constant() {
+            true => ScalarField::new(Mode::Constant, difference),
+            false => {
+                // Compute `self - other + MODULUS`, which overflows the modulus iff `self >= other`.
+                let value = self.to_lc() - other.to_lc() + E::one() * ScalarField::<E>::modulus_in_base_field();
+                ScalarField::reduce_once(value, first.to_repr() >= second.to_repr(), difference)
+            }
+        }
+    }
+}
+
+impl<E: Environment> SubAssign<ScalarField<E>> for ScalarField<E> {
+    fn sub_assign(&mut self, other: ScalarField<E>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment> SubAssign<&ScalarField<E>> for ScalarField<E> {
+    fn sub_assign(&mut self, other: &ScalarField<E>) {
+        *self = &*self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 25;
+
+    fn check_sub(
+        name: &str,
+        first: <Circuit as Environment>::ScalarField,
+        second: <Circuit as Environment>::ScalarField,
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let a = ScalarField::<Circuit>::new(mode_a, first);
+        let b = ScalarField::<Circuit>::new(mode_b, second);
+
+        Circuit::scoped(name, |scope| {
+            let expected = first - second;
+            let candidate = a - b;
+            assert_eq!(
+                expected,
+                candidate.eject_value(),
+                "{} != {} := ({} - {})",
+                expected,
+                candidate.eject_value(),
+                first,
+                second
+            );
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    fn run_test(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let check = |name: &str, first, second| {
+            check_sub(
+                &format!("{} ({:?}, {:?})", name, mode_a, mode_b),
+                first,
+                second,
+                mode_a,
+                mode_b,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            )
+        };
+
+        for i in 0..ITERATIONS {
+            let first = UniformRand::rand(&mut thread_rng());
+            let second = UniformRand::rand(&mut thread_rng());
+            check(&format!("Sub: a - b {}", i), first, second);
+        }
+
+        // Check the boundaries of the field.
+        let zero = <Circuit as Environment>::ScalarField::zero();
+        let one = <Circuit as Environment>::ScalarField::one();
+        check("Sub: 0 - 0", zero, zero);
+        check("Sub: 0 - 1", zero, one);
+        check("Sub: 1 - -1", one, -one);
+        check("Sub: -1 - -1", -one, -one);
+    }
+
+    #[test]
+    fn test_constant_minus_constant() {
+        run_test(Mode::Constant, Mode::Constant, 251, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_constant_minus_public() {
+        run_test(Mode::Constant, Mode::Public, 1, 0, 502, 754);
+    }
+
+    #[test]
+    fn test_public_minus_constant() {
+        run_test(Mode::Public, Mode::Constant, 1, 0, 502, 754);
+    }
+
+    #[test]
+    fn test_public_minus_public() {
+        run_test(Mode::Public, Mode::Public, 1, 0, 502, 754);
+    }
+
+    #[test]
+    fn test_private_minus_private() {
+        run_test(Mode::Private, Mode::Private, 1, 0, 502, 754);
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> And<Self> for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `(self AND other)`, for each bit.
+    fn and(&self, other: &Self) -> Self::Output {
+        let bits_le = self
+            .bits_le
+            .iter()
+            .zip(other.bits_le.iter())
+            .map(|(a, b)| a.and(b))
+            .collect();
+        Integer::from_bits_le(bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_and<I: IntegerType>(
+        name: &str,
+        expected: I,
+        a: Integer<Circuit, I>,
+        b: Integer<Circuit, I>,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        Circuit::scoped(name, |scope| {
+            let candidate = a.and(&b);
+            assert_eq!(
+                expected,
+                candidate.eject_value(),
+                "{} != {} := ({} AND {})",
+                expected,
+                candidate.eject_value(),
+                a.eject_value(),
+                b.eject_value()
+            );
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    fn run_test<I: IntegerType>(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) where
+        Standard: Distribution<I>,
+    {
+        for i in 0..ITERATIONS {
+            let first: I = thread_rng().gen();
+            let second: I = thread_rng().gen();
+
+            let expected = I::from_bits_le(
+                &first
+                    .to_bits_le()
+                    .iter()
+                    .zip(second.to_bits_le().iter())
+                    .map(|(a, b)| a & b)
+                    .collect::<Vec<_>>(),
+            );
+            let a = Integer::<Circuit, I>::new(mode_a, first);
+            let b = Integer::<Circuit, I>::new(mode_b, second);
+
+            let name = format!("And: {:?} {:?} {}", mode_a, mode_b, i);
+            check_and(
+                &name,
+                expected,
+                a,
+                b,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            );
+        }
+    }
+
+    #[test]
+    fn test_u8_and() {
+        run_test::<u8>(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
+        run_test::<u8>(Mode::Constant, Mode::Public, 0, 0, 0, 0);
+        run_test::<u8>(Mode::Public, Mode::Constant, 0, 0, 0, 0);
+        run_test::<u8>(Mode::Public, Mode::Public, 0, 0, 8, 16);
+        run_test::<u8>(Mode::Private, Mode::Private, 0, 0, 8, 16);
+    }
+
+    #[test]
+    fn test_i128_and() {
+        run_test::<i128>(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
+        run_test::<i128>(Mode::Public, Mode::Private, 0, 0, 128, 256);
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Compare<Self> for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` is less than `other`.
+    ///
+    /// This method costs `I::BITS + 2` constraints.
+    ///
+    fn is_lt(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            Boolean::new(Mode::Constant, self.eject_value() < other.eject_value())
+        } else if I::IS_SIGNED {
+            // Flipping the sign bit maps the signed integers in [MIN, MAX] onto the unsigned integers in [0, 2^n),
+            // while preserving their order, so the signed integers are compared as unsigned integers.
+            let flip_msb = |integer: &Self| {
+                let mut bits_le = integer.bits_le.clone();
+                bits_le[I::BITS - 1] = !integer.msb();
+                Integer::<E, I::Magnitude>::from_bits_le(bits_le)
+            };
+            flip_msb(self).is_lt(&flip_msb(other))
+        } else {
+            // Compute 2^n + self - other, which is in [1, 2^(n+1) - 1].
+            // Its most significant bit is set iff `self` is greater than or equal to `other`.
+            let modulus: BaseField<E> = (E::one() * Self::two_pow(I::BITS)).into();
+            let difference = modulus + self.to_field() - other.to_field();
+            let bits_le = Self::decompose(&difference, I::BITS + 1);
+
+            !&bits_le[I::BITS]
+        }
+    }
+
+    ///
+    /// Returns `true` if `self` is greater than `other`.
+    ///
+    /// This method costs `I::BITS + 2` constraints.
+    ///
+    fn is_gt(&self, other: &Self) -> Self::Output {
+        other.is_lt(self)
+    }
+
+    ///
+    /// Returns `true` if `self` is less than or equal to `other`.
+    ///
+    /// This method costs `I::BITS + 2` constraints.
+    ///
+    fn is_le(&self, other: &Self) -> Self::Output {
+        !other.is_lt(self)
+    }
+
+    ///
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    ///
+    /// This method costs `I::BITS + 2` constraints.
+    ///
+    fn is_ge(&self, other: &Self) -> Self::Output {
+        !self.is_lt(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_compare<I: IntegerType>(
+        name: &str,
+        first: I,
+        second: I,
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        let cases: [(
+            &str,
+            fn(&Integer<Circuit, I>, &Integer<Circuit, I>) -> Boolean<Circuit>,
+            bool,
+        ); 4] = [
+            ("<", |a, b| a.is_lt(b), first < second),
+            (">", |a, b| a.is_gt(b), first > second),
+            ("<=", |a, b| a.is_le(b), first <= second),
+            (">=", |a, b| a.is_ge(b), first >= second),
+        ];
+
+        for (operator, compare, expected) in cases {
+            Circuit::scoped(&format!("{} {}", name, operator), |scope| {
+                let candidate = compare(&a, &b);
+                assert_eq!(
+                    expected,
+                    candidate.eject_value(),
+                    "{} != {} := ({} {} {})",
+                    expected,
+                    candidate.eject_value(),
+                    first,
+                    operator,
+                    second
+                );
+                assert!(Circuit::is_satisfied());
+
+                assert_eq!(num_constants, scope.num_constants_in_scope());
+                assert_eq!(num_public, scope.num_public_in_scope());
+                assert_eq!(num_private, scope.num_private_in_scope());
+                assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            });
+        }
+    }
+
+    fn run_test<I: IntegerType>(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) where
+        Standard: Distribution<I>,
+    {
+        let check = |name: &str, first: I, second: I| {
+            check_compare(
+                &format!("{} ({:?}, {:?})", name, mode_a, mode_b),
+                first,
+                second,
+                mode_a,
+                mode_b,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            )
+        };
+
+        for i in 0..ITERATIONS {
+            let first: I = thread_rng().gen();
+            let second: I = thread_rng().gen();
+            check(&format!("Compare: {:?} {:?} {}", mode_a, mode_b, i), first, second);
+            check(&format!("Compare: {:?} {:?} equal {}", mode_a, mode_b, i), first, first);
+        }
+
+        // Check the boundaries of the type.
+        check("Compare: MIN MAX", I::MIN, I::MAX);
+        check("Compare: MAX MIN", I::MAX, I::MIN);
+        check("Compare: ZERO MIN", I::zero(), I::MIN);
+        check("Compare: ZERO MAX", I::zero(), I::MAX);
+    }
+
+    #[test]
+    fn test_u8_compare() {
+        run_test::<u8>(Mode::Constant, Mode::Constant, 1, 0, 0, 0);
+        run_test::<u8>(Mode::Constant, Mode::Public, 0, 0, 9, 10);
+        run_test::<u8>(Mode::Public, Mode::Constant, 0, 0, 9, 10);
+        run_test::<u8>(Mode::Public, Mode::Public, 0, 0, 9, 10);
+        run_test::<u8>(Mode::Private, Mode::Private, 0, 0, 9, 10);
+    }
+
+    #[test]
+    fn test_i8_compare() {
+        run_test::<i8>(Mode::Constant, Mode::Constant, 1, 0, 0, 0);
+        run_test::<i8>(Mode::Constant, Mode::Public, 0, 0, 9, 10);
+        run_test::<i8>(Mode::Public, Mode::Constant, 0, 0, 9, 10);
+        run_test::<i8>(Mode::Public, Mode::Public, 0, 0, 9, 10);
+        run_test::<i8>(Mode::Private, Mode::Private, 0, 0, 9, 10);
+    }
+
+    #[test]
+    fn test_u128_compare() {
+        run_test::<u128>(Mode::Constant, Mode::Constant, 1, 0, 0, 0);
+        run_test::<u128>(Mode::Public, Mode::Private, 0, 0, 129, 130);
+    }
+
+    #[test]
+    fn test_i128_compare() {
+        run_test::<i128>(Mode::Constant, Mode::Constant, 1, 0, 0, 0);
+        run_test::<i128>(Mode::Public, Mode::Private, 0, 0, 129, 130);
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Div<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn div(self, other: Integer<E, I>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Div<Integer<E, I>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn div(self, other: Integer<E, I>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Div<&Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn div(self, other: &Integer<E, I>) -> Self::Output {
+        &self / other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Div<&Integer<E, I>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn div(self, other: &Integer<E, I>) -> Self::Output {
+        self.div_checked(other)
+    }
+}
+
+impl<E: Environment, I: IntegerType> DivAssign<Integer<E, I>> for Integer<E, I> {
+    fn div_assign(&mut self, other: Integer<E, I>) {
+        *self /= &other;
+    }
+}
+
+impl<E: Environment, I: IntegerType> DivAssign<&Integer<E, I>> for Integer<E, I> {
+    fn div_assign(&mut self, other: &Integer<E, I>) {
+        *self = self.div_checked(other);
+    }
+}
+
+impl<E: Environment, I: IntegerType> DivChecked<Self> for Integer<E, I> {
+    type Output = Self;
+
+    ///
+    /// Returns `self / other`, rounding towards zero, and halting on division by zero or overflow.
+    ///
+    /// If either operand is not a constant, a division by zero or an overflow leaves the circuit unsatisfied.
+    ///
+    fn div_checked(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            match self.eject_value().checked_div(&other.eject_value()) {
+                Some(value) => Integer::new(Mode::Constant, value),
+                None => E::halt("Division by zero or integer overflow on division of two constants"),
+            }
+        } else if I::IS_SIGNED {
+            let (quotient, magnitude, is_negative) = self.div_signed(other);
+
+            // Ensure the quotient has the expected sign, which fails for `MIN / -1`.
+            E::assert_eq(quotient.is_signed_overflow(&magnitude, &is_negative), E::zero());
+
+            quotient
+        } else {
+            self.div_unsigned(other)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> DivWrapped<Self> for Integer<E, I> {
+    type Output = Self;
+
+    ///
+    /// Returns `self / other`, rounding towards zero, wrapping around at the boundary of the type,
+    /// and halting on division by zero.
+    ///
+    /// If either operand is not a constant, a division by zero leaves the circuit unsatisfied.
+    ///
+    fn div_wrapped(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            match other.eject_value() == I::zero() {
+                true => E::halt("Division by zero on division of two constants"),
+                false => Integer::new(Mode::Constant, self.eject_value().wrapping_div(&other.eject_value())),
+            }
+        } else if I::IS_SIGNED {
+            // The only overflow, `MIN / -1`, wraps around to `MIN`, which is the negated magnitude.
+            let (quotient, _, _) = self.div_signed(other);
+            quotient
+        } else {
+            self.div_unsigned(other)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    ///
+    /// Returns the quotient of `self` and `other`, interpreting their bits as unsigned.
+    ///
+    fn div_unsigned(&self, other: &Self) -> Self {
+        let (dividend, divisor) = (self.eject_value(), other.eject_value());
+
+        // Compute the quotient and remainder. If the divisor is zero, any witness fails the checks below.
+        let (quotient, remainder) = match divisor == I::zero() {
+            true => (I::zero(), dividend),
+            false => {
+                let quotient = dividend.wrapping_div(&divisor);
+                (quotient, dividend.wrapping_sub(&quotient.wrapping_mul(&divisor)))
+            }
+        };
+        let quotient = Integer::new(Mode::Private, quotient);
+        let remainder = Integer::new(Mode::Private, remainder);
+
+        // Ensure self == quotient * other + remainder, where the right-hand side does not overflow.
+        let product = quotient.mul_checked(other).add_checked(&remainder);
+        E::assert_eq(self.to_field(), product.to_field());
+
+        // Ensure remainder < other, which also ensures other is not zero.
+        E::assert(&remainder.is_lt(other));
+
+        quotient
+    }
+
+    ///
+    /// Returns the wrapped quotient of `self` and `other`, the magnitude of the quotient,
+    /// and `true` if the quotient is expected to be negative.
+    ///
+    fn div_signed(&self, other: &Self) -> (Self, Integer<E, I::Magnitude>, Boolean<E>) {
+        // Divide the magnitudes, which rounds towards zero.
+        let magnitude = self.to_magnitude().div_unsigned(&other.to_magnitude());
+
+        // Negate the quotient if the operands have different signs.
+        let is_negative = self.msb().is_neq(other.msb());
+        let quotient = Integer::from_bits_le(magnitude.bits_le.clone()).neg_wrapped_if(&is_negative);
+
+        (quotient, magnitude, is_negative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+    use std::panic::catch_unwind;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_div<I: IntegerType>(
+        name: &str,
+        first: I,
+        second: I,
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_wrapped_private: usize,
+        num_wrapped_constraints: usize,
+        num_checked_private: usize,
+        num_checked_constraints: usize,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let is_constant = mode_a.is_constant() && mode_b.is_constant();
+
+        // Check the wrapped division.
+        match second == I::zero() {
+            true => match is_constant {
+                true => assert!(catch_unwind(|| a.div_wrapped(&b)).is_err()),
+                false => {
+                    Circuit::scoped(&format!("{} wrapped", name), |_| {
+                        let _candidate = a.div_wrapped(&b);
+                        assert!(!Circuit::is_satisfied());
+                    });
+                    Circuit::reset_circuit();
+                }
+            },
+            false => Circuit::scoped(&format!("{} wrapped", name), |scope| {
+                let expected = first.wrapping_div(&second);
+                let candidate = a.div_wrapped(&b);
+                assert_eq!(
+                    expected,
+                    candidate.eject_value(),
+                    "{} != {} := ({} / {})",
+                    expected,
+                    candidate.eject_value(),
+                    first,
+                    second
+                );
+                assert!(Circuit::is_satisfied());
+
+                assert_eq!(num_constants, scope.num_constants_in_scope());
+                assert_eq!(0, scope.num_public_in_scope());
+                assert_eq!(num_wrapped_private, scope.num_private_in_scope());
+                assert_eq!(num_wrapped_constraints, scope.num_constraints_in_scope());
+            }),
+        }
+
+        // Check the checked division.
+        match first.checked_div(&second) {
+            Some(expected) => Circuit::scoped(&format!("{} checked", name), |scope| {
+                let candidate = a / &b;
+                assert_eq!(
+                    expected,
+                    candidate.eject_value(),
+                    "{} != {} := ({} / {})",
+                    expected,
+                    candidate.eject_value(),
+                    first,
+                    second
+                );
+                assert!(Circuit::is_satisfied());
+
+                assert_eq!(num_constants, scope.num_constants_in_scope());
+                assert_eq!(0, scope.num_public_in_scope());
+                assert_eq!(num_checked_private, scope.num_private_in_scope());
+                assert_eq!(num_checked_constraints, scope.num_constraints_in_scope());
+            }),
+            None => match is_constant {
+                true => assert!(catch_unwind(|| a.div_checked(&b)).is_err()),
+                false => {
+                    Circuit::scoped(&format!("{} overflow", name), |_| {
+                        let _candidate = a.div_checked(&b);
+                        assert!(!Circuit::is_satisfied());
+                    });
+                    Circuit::reset_circuit();
+                }
+            },
+        }
+    }
+
+    fn run_test<I: IntegerType>(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_wrapped_private: usize,
+        num_wrapped_constraints: usize,
+        num_checked_private: usize,
+        num_checked_constraints: usize,
+    ) where
+        Standard: Distribution<I>,
+    {
+        let check = |name: &str, first: I, second: I| {
+            check_div(
+                &format!("{} ({:?}, {:?})", name, mode_a, mode_b),
+                first,
+                second,
+                mode_a,
+                mode_b,
+                num_constants,
+                num_wrapped_private,
+                num_wrapped_constraints,
+                num_checked_private,
+                num_checked_constraints,
+            )
+        };
+
+        for i in 0..ITERATIONS {
+            let first: I = thread_rng().gen();
+            let second: I = thread_rng().gen();
+            check(&format!("Div: {:?} / {:?} {}", mode_a, mode_b, i), first, second);
+
+            // Check divisions by a small integer.
+            let second = I::from_bits_le(&second.to_bits_le()[..3]);
+            check(&format!("Div: {:?} / {:?} small {}", mode_a, mode_b, i), first, second);
+        }
+
+        // Check the boundaries of the type.
+        let negative_one = I::zero().wrapping_sub(&I::one());
+        check("Div: MAX / ONE", I::MAX, I::one());
+        check("Div: MIN / ONE", I::MIN, I::one());
+        check("Div: MIN / -ONE", I::MIN, negative_one);
+        check("Div: MIN / MAX", I::MIN, I::MAX);
+        check("Div: MAX / MIN", I::MAX, I::MIN);
+        check("Div: ONE / ZERO", I::one(), I::zero());
+        check("Div: ZERO / ZERO", I::zero(), I::zero());
+    }
+
+    #[test]
+    fn test_u8_div() {
+        run_test::<u8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0, 0);
+        run_test::<u8>(Mode::Constant, Mode::Public, 0, 42, 47, 42, 47);
+        run_test::<u8>(Mode::Public, Mode::Constant, 0, 41, 46, 41, 46);
+        run_test::<u8>(Mode::Public, Mode::Public, 0, 42, 47, 42, 47);
+        run_test::<u8>(Mode::Private, Mode::Private, 0, 42, 47, 42, 47);
+    }
+
+    #[test]
+    fn test_i8_div() {
+        run_test::<i8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0, 0);
+        run_test::<i8>(Mode::Constant, Mode::Public, 8, 76, 99, 80, 107);
+        run_test::<i8>(Mode::Public, Mode::Constant, 8, 75, 98, 79, 106);
+        run_test::<i8>(Mode::Public, Mode::Public, 0, 94, 127, 98, 135);
+        run_test::<i8>(Mode::Private, Mode::Private, 0, 94, 127, 98, 135);
+    }
+
+    #[test]
+    fn test_u128_div() {
+        run_test::<u128>(Mode::Constant, Mode::Constant, 128, 0, 0, 0, 0);
+        run_test::<u128>(Mode::Public, Mode::Private, 0, 645, 651, 645, 651);
+    }
+
+    #[test]
+    fn test_i128_div() {
+        run_test::<i128>(Mode::Constant, Mode::Constant, 128, 0, 0, 0, 0);
+        run_test::<i128>(Mode::Public, Mode::Private, 0, 1417, 1811, 1421, 1819);
+    }
+}
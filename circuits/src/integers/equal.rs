@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Equal<Self> for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` and `other` are equal.
+    ///
+    /// This method costs 3 constraints.
+    ///
+    fn is_eq(&self, other: &Self) -> Self::Output {
+        !self.is_neq(other)
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` are *not* equal.
+    ///
+    /// As the bits of an integer have a unique base field representation,
+    /// the integers are compared as base field elements.
+    ///
+    /// This method costs 3 constraints.
+    ///
+    fn is_neq(&self, other: &Self) -> Self::Output {
+        match self.is_constant() && other.is_constant() {
+            true => Boolean::new(Mode::Constant, self.eject_value() != other.eject_value()),
+            false => self.to_field().is_neq(&other.to_field()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_is_eq<I: IntegerType>(
+        name: &str,
+        first: I,
+        second: I,
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        Circuit::scoped(&format!("{} ==", name), |scope| {
+            let candidate = a.is_eq(&b);
+            assert_eq!(first == second, candidate.eject_value(), "({} == {})", first, second);
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+
+        Circuit::scoped(&format!("{} !=", name), |scope| {
+            let candidate = a.is_neq(&b);
+            assert_eq!(first != second, candidate.eject_value(), "({} != {})", first, second);
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    fn run_test<I: IntegerType>(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) where
+        Standard: Distribution<I>,
+    {
+        for i in 0..ITERATIONS {
+            let first: I = thread_rng().gen();
+            let second: I = thread_rng().gen();
+
+            let name = format!("Equal: {:?} {:?} {}", mode_a, mode_b, i);
+            check_is_eq(
+                &name,
+                first,
+                second,
+                mode_a,
+                mode_b,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            );
+
+            let name = format!("Equal: {:?} {:?} same {}", mode_a, mode_b, i);
+            check_is_eq(
+                &name,
+                first,
+                first,
+                mode_a,
+                mode_b,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            );
+        }
+    }
+
+    #[test]
+    fn test_u8_equal() {
+        run_test::<u8>(Mode::Constant, Mode::Constant, 1, 0, 0, 0);
+        run_test::<u8>(Mode::Constant, Mode::Public, 0, 0, 2, 3);
+        run_test::<u8>(Mode::Public, Mode::Constant, 0, 0, 2, 3);
+        run_test::<u8>(Mode::Public, Mode::Public, 0, 0, 2, 3);
+        run_test::<u8>(Mode::Private, Mode::Private, 0, 0, 2, 3);
+    }
+
+    #[test]
+    fn test_i128_equal() {
+        run_test::<i128>(Mode::Constant, Mode::Constant, 1, 0, 0, 0);
+        run_test::<i128>(Mode::Public, Mode::Private, 0, 0, 2, 3);
+    }
+}
@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    fmt::{Debug, Display},
+    panic::RefUnwindSafe,
+};
+
+/// A native integer type, which may be represented as a circuit `Integer`.
+pub trait IntegerType: Copy + Clone + Debug + Display + Eq + Ord + Send + Sync + RefUnwindSafe + 'static {
+    /// The unsigned integer type with the same number of bits.
+    type Magnitude: Magnitude;
+
+    /// The number of bits in the integer type.
+    const BITS: usize;
+    /// `true` if the integer type is signed (in two's complement).
+    const IS_SIGNED: bool;
+    /// The minimum value of the integer type.
+    const MIN: Self;
+    /// The maximum value of the integer type.
+    const MAX: Self;
+
+    /// Returns the `zero` value.
+    fn zero() -> Self;
+
+    /// Returns the `one` value.
+    fn one() -> Self;
+
+    /// Returns the little-endian bits of the integer, in two's complement.
+    fn to_bits_le(&self) -> Vec<bool>;
+
+    /// Returns the integer from the given little-endian bits, in two's complement.
+    fn from_bits_le(bits_le: &[bool]) -> Self;
+
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+    fn checked_shl(&self, rhs: u32) -> Option<Self>;
+    fn checked_shr(&self, rhs: u32) -> Option<Self>;
+
+    fn wrapping_add(&self, other: &Self) -> Self;
+    fn wrapping_sub(&self, other: &Self) -> Self;
+    fn wrapping_mul(&self, other: &Self) -> Self;
+    /// Returns the wrapped quotient. Panics if `other` is zero.
+    fn wrapping_div(&self, other: &Self) -> Self;
+    fn wrapping_shl(&self, rhs: u32) -> Self;
+    fn wrapping_shr(&self, rhs: u32) -> Self;
+}
+
+/// An unsigned native integer type.
+pub trait Magnitude: IntegerType {}
+
+macro_rules! integer_type_impl {
+    ($type:ty, $magnitude:ty, $is_signed:expr) => {
+        impl IntegerType for $type {
+            type Magnitude = $magnitude;
+
+            const BITS: usize = <$type>::BITS as usize;
+            const IS_SIGNED: bool = $is_signed;
+            const MAX: Self = <$type>::MAX;
+            const MIN: Self = <$type>::MIN;
+
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn to_bits_le(&self) -> Vec<bool> {
+                (0..<Self as IntegerType>::BITS)
+                    .map(|i| (*self >> i) & 1 == 1)
+                    .collect()
+            }
+
+            fn from_bits_le(bits_le: &[bool]) -> Self {
+                bits_le
+                    .iter()
+                    .take(<Self as IntegerType>::BITS)
+                    .enumerate()
+                    .fold(0, |value, (i, bit)| value | ((*bit as $type) << i))
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_add(*self, *other)
+            }
+
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_sub(*self, *other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_mul(*self, *other)
+            }
+
+            fn checked_div(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_div(*self, *other)
+            }
+
+            fn checked_shl(&self, rhs: u32) -> Option<Self> {
+                <$type>::checked_shl(*self, rhs)
+            }
+
+            fn checked_shr(&self, rhs: u32) -> Option<Self> {
+                <$type>::checked_shr(*self, rhs)
+            }
+
+            fn wrapping_add(&self, other: &Self) -> Self {
+                <$type>::wrapping_add(*self, *other)
+            }
+
+            fn wrapping_sub(&self, other: &Self) -> Self {
+                <$type>::wrapping_sub(*self, *other)
+            }
+
+            fn wrapping_mul(&self, other: &Self) -> Self {
+                <$type>::wrapping_mul(*self, *other)
+            }
+
+            fn wrapping_div(&self, other: &Self) -> Self {
+                <$type>::wrapping_div(*self, *other)
+            }
+
+            fn wrapping_shl(&self, rhs: u32) -> Self {
+                <$type>::wrapping_shl(*self, rhs)
+            }
+
+            fn wrapping_shr(&self, rhs: u32) -> Self {
+                <$type>::wrapping_shr(*self, rhs)
+            }
+        }
+    };
+}
+
+integer_type_impl!(i8, u8, true);
+integer_type_impl!(i16, u16, true);
+integer_type_impl!(i32, u32, true);
+integer_type_impl!(i64, u64, true);
+integer_type_impl!(i128, u128, true);
+
+integer_type_impl!(u8, u8, false);
+integer_type_impl!(u16, u16, false);
+integer_type_impl!(u32, u32, false);
+integer_type_impl!(u64, u64, false);
+integer_type_impl!(u128, u128, false);
+
+impl Magnitude for u8 {}
+impl Magnitude for u16 {}
+impl Magnitude for u32 {}
+impl Magnitude for u64 {}
+impl Magnitude for u128 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+
+    const ITERATIONS: usize = 1000;
+
+    fn check_bits<I: IntegerType>()
+    where
+        Standard: Distribution<I>,
+    {
+        for _ in 0..ITERATIONS {
+            let expected: I = thread_rng().gen();
+            let bits_le = expected.to_bits_le();
+            assert_eq!(I::BITS, bits_le.len());
+            assert_eq!(expected, I::from_bits_le(&bits_le));
+        }
+    }
+
+    #[test]
+    fn test_bits() {
+        check_bits::<i8>();
+        check_bits::<i16>();
+        check_bits::<i32>();
+        check_bits::<i64>();
+        check_bits::<i128>();
+        check_bits::<u8>();
+        check_bits::<u16>();
+        check_bits::<u32>();
+        check_bits::<u64>();
+        check_bits::<u128>();
+    }
+
+    #[test]
+    fn test_bits_signed() {
+        assert_eq!(vec![false; 8], 0i8.to_bits_le());
+        assert_eq!(vec![true; 8], (-1i8).to_bits_le());
+        assert_eq!(
+            i8::MIN,
+            i8::from_bits_le(&[false, false, false, false, false, false, false, true])
+        );
+        assert_eq!(
+            i8::MAX,
+            i8::from_bits_le(&[true, true, true, true, true, true, true, false])
+        );
+    }
+}
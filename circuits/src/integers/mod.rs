@@ -14,37 +14,337 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-// pub mod add;
-// pub mod double;
-// pub mod equal;
-// pub mod inv;
-// pub mod mul;
-// pub mod neg;
-// pub mod one;
-// pub mod sub;
-// pub mod zero;
-
-use crate::{boolean::Boolean, traits::*, Environment, Field, Mode};
-use snarkvm_curves::{AffineCurve, TwistedEdwardsParameters};
-use snarkvm_fields::{Field as F, One as O, Zero as Z};
-
-// use num_traits::Inv;
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-
-pub type I8<E> = Signed<E, i8, 8>;
-pub type I16<E> = Signed<E, i16, 16>;
-pub type I32<E> = Signed<E, i32, 32>;
-pub type I64<E> = Signed<E, i64, 64>;
-pub type I128<E> = Signed<E, i128, 128>;
+mod add;
+mod and;
+mod compare;
+mod div;
+mod equal;
+mod mul;
+mod not;
+mod one;
+mod or;
+mod shl;
+mod shr;
+mod sub;
+mod ternary;
+mod to_bits;
+mod xor;
+mod zero;
+
+pub mod integer_type;
+pub use integer_type::*;
+
+use crate::{traits::*, BaseField, Boolean, Environment, LinearCombination, Mode};
+use snarkvm_fields::{Field as F, One as O, PrimeField};
+use snarkvm_utilities::ToBits as TBits;
+
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Not, Shl, Shr, Sub, SubAssign},
+};
+
+pub type I8<E> = Integer<E, i8>;
+pub type I16<E> = Integer<E, i16>;
+pub type I32<E> = Integer<E, i32>;
+pub type I64<E> = Integer<E, i64>;
+pub type I128<E> = Integer<E, i128>;
+
+pub type U8<E> = Integer<E, u8>;
+pub type U16<E> = Integer<E, u16>;
+pub type U32<E> = Integer<E, u32>;
+pub type U64<E> = Integer<E, u64>;
+pub type U128<E> = Integer<E, u128>;
 
 #[derive(Clone)]
-pub struct Signed<E: Environment, I, const SIZE: usize> {
+pub struct Integer<E: Environment, I: IntegerType> {
     bits_le: Vec<Boolean<E>>,
+    phantom: PhantomData<I>,
 }
 
-impl<E: Environment, I, const SIZE: usize> Signed<E, I, SIZE> {
-    /// Initializes a new signed integer.
-    pub fn new(mode: Mode, value: I) -> Self {}
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    ///
+    /// Initializes a new instance of an integer from a constant integer value.
+    ///
+    pub fn new(mode: Mode, value: I) -> Self {
+        let bits_le = value
+            .to_bits_le()
+            .iter()
+            .map(|bit| Boolean::new(mode, *bit))
+            .collect::<Vec<_>>();
+
+        Self::from_bits_le(bits_le)
+    }
+
+    ///
+    /// Initializes a new instance of an integer from its little-endian bits, in two's complement.
+    ///
+    pub fn from_bits_le(bits_le: Vec<Boolean<E>>) -> Self {
+        if bits_le.len() != I::BITS {
+            E::halt(format!(
+                "Expected {} bits for an integer, found {} bits",
+                I::BITS,
+                bits_le.len()
+            ))
+        }
+
+        Self {
+            bits_le,
+            phantom: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns `true` if the integer is a constant.
+    ///
+    pub fn is_constant(&self) -> bool {
+        self.bits_le.iter().all(Boolean::is_constant)
+    }
+
+    ///
+    /// Ejects the integer as a constant integer value.
+    ///
+    pub fn eject_value(&self) -> I {
+        let bits_le = self.bits_le.iter().map(Boolean::eject_value).collect::<Vec<_>>();
+        I::from_bits_le(&bits_le)
+    }
+
+    ///
+    /// Returns the most significant bit, which is the sign bit of a signed integer.
+    ///
+    fn msb(&self) -> &Boolean<E> {
+        &self.bits_le[I::BITS - 1]
+    }
+
+    ///
+    /// Returns the bits of `self`, reinterpreted as its unsigned counterpart.
+    ///
+    fn cast_as_magnitude(&self) -> Integer<E, I::Magnitude> {
+        Integer::from_bits_le(self.bits_le.clone())
+    }
+
+    ///
+    /// Returns the absolute value of `self` as its unsigned counterpart.
+    ///
+    /// Note that the absolute value of the minimum signed integer fits in its unsigned counterpart.
+    ///
+    fn to_magnitude(&self) -> Integer<E, I::Magnitude> {
+        match I::IS_SIGNED {
+            true => Integer::ternary(self.msb(), &self.neg_wrapped(), self).cast_as_magnitude(),
+            false => self.cast_as_magnitude(),
+        }
+    }
+
+    ///
+    /// Returns `-self`, wrapping around at the boundary of the type.
+    ///
+    fn neg_wrapped(&self) -> Self {
+        if self.is_constant() {
+            Integer::new(Mode::Constant, I::zero().wrapping_sub(&self.eject_value()))
+        } else {
+            // Compute 2^n - self, which is in [1, 2^n], and drop the carry bit.
+            let modulus: BaseField<E> = (E::one() * Self::two_pow(I::BITS)).into();
+            let difference = modulus - self.to_field();
+            let mut bits_le = Self::decompose(&difference, I::BITS + 1);
+            bits_le.truncate(I::BITS);
+            Integer::from_bits_le(bits_le)
+        }
+    }
+
+    ///
+    /// Returns `self` if `condition` is `false`, and `-self` otherwise, wrapping around at the boundary of the type.
+    ///
+    fn neg_wrapped_if(&self, condition: &Boolean<E>) -> Self {
+        Integer::ternary(condition, &self.neg_wrapped(), self)
+    }
+
+    ///
+    /// Returns `true` if `self` has overflowed, given the magnitude of the result and its expected sign.
+    ///
+    /// A signed result overflows if its sign differs from the expected sign, unless its magnitude is zero.
+    ///
+    fn is_signed_overflow(&self, magnitude: &Integer<E, I::Magnitude>, expected_sign: &Boolean<E>) -> Boolean<E> {
+        self.msb().is_neq(expected_sign).and(&!magnitude.is_zero())
+    }
+
+    ///
+    /// Returns the integer as a base field element, interpreting its bits as unsigned.
+    ///
+    /// This method costs 0 constraints.
+    ///
+    fn to_field(&self) -> BaseField<E> {
+        Self::bits_to_field(&self.bits_le)
+    }
+
+    ///
+    /// Returns the little-endian bits as a base field element.
+    ///
+    /// This method costs 0 constraints.
+    ///
+    fn bits_to_field(bits_le: &[Boolean<E>]) -> BaseField<E> {
+        let mut accumulator = E::zero();
+        let mut coefficient = E::BaseField::one();
+
+        for bit in bits_le {
+            accumulator += LinearCombination::from(bit) * coefficient;
+            coefficient = coefficient.double();
+        }
+
+        accumulator.into()
+    }
+
+    ///
+    /// Returns the lower `num_bits` little-endian bits of the given base field element.
+    ///
+    /// If the base field element does not fit in `num_bits` bits, the circuit is not satisfied,
+    /// which is how the checked integer operations enforce the absence of overflow.
+    /// The caller must ensure `num_bits` is less than the capacity of the base field.
+    ///
+    /// This method costs `num_bits + 1` constraints.
+    ///
+    fn decompose(value: &BaseField<E>, num_bits: usize) -> Vec<Boolean<E>> {
+        let mode = match value.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        let bits_le = value
+            .eject_value()
+            .to_bits_le()
+            .iter()
+            .take(num_bits)
+            .map(|bit| Boolean::new(mode, *bit))
+            .collect::<Vec<_>>();
+
+        // Ensure value * 1 == (2^i * b_i + ... + 2^0 * b_0)
+        E::enforce(|| (value, E::one(), Self::bits_to_field(&bits_le)));
+
+        bits_le
+    }
+
+    ///
+    /// Returns the number of lower bits of a shift amount that are used to shift `self`, which is `log2(I::BITS)`.
+    ///
+    fn num_shift_bits() -> usize {
+        I::BITS.trailing_zeros() as usize
+    }
+
+    ///
+    /// Returns the given constant shift amount as a `u32`, or `None` if it does not fit.
+    ///
+    fn shift_amount<M: Magnitude>(rhs: &Integer<E, M>) -> Option<u32> {
+        let bits_le = rhs.eject_value().to_bits_le();
+        match bits_le.iter().skip(32).any(|bit| *bit) {
+            true => None,
+            false => Some(u32::from_bits_le(&bits_le[..bits_le.len().min(32)])),
+        }
+    }
+
+    ///
+    /// Ensures the given shift amount is less than `I::BITS`, by ensuring its upper bits are zero.
+    ///
+    /// This method costs 1 constraint.
+    ///
+    fn assert_shift_in_range<M: Magnitude>(rhs: &Integer<E, M>) {
+        let upper_bits = &rhs.bits_le[Self::num_shift_bits()..];
+
+        // As each bit is either 0 or 1, the upper bits are all zero iff their sum is zero.
+        if upper_bits.iter().all(Boolean::is_constant) {
+            if upper_bits.iter().any(Boolean::eject_value) {
+                E::halt("Shift amount is out of range")
+            }
+        } else {
+            let sum = upper_bits
+                .iter()
+                .fold(E::zero(), |sum, bit| sum + LinearCombination::from(bit));
+            E::assert_eq(sum, E::zero());
+        }
+    }
+
+    ///
+    /// Returns `2^exponent` as a base field element.
+    ///
+    fn two_pow(exponent: usize) -> E::BaseField {
+        (0..exponent).fold(E::BaseField::one(), |power, _| power.double())
+    }
+}
+
+impl<E: Environment, I: IntegerType> fmt::Debug for Integer<E, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.eject_value())
+    }
+}
+
+impl<E: Environment, I: IntegerType> IntegerTrait for Integer<E, I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_new<I: IntegerType>(
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) where
+        Standard: Distribution<I>,
+    {
+        for i in 0..ITERATIONS {
+            let expected: I = thread_rng().gen();
+
+            Circuit::scoped(
+                &format!("New {} {:?} {}", std::any::type_name::<I>(), mode, i),
+                |scope| {
+                    let candidate = Integer::<Circuit, I>::new(mode, expected);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(mode.is_constant(), candidate.is_constant());
+
+                    assert_eq!(num_constants, scope.num_constants_in_scope());
+                    assert_eq!(num_public, scope.num_public_in_scope());
+                    assert_eq!(num_private, scope.num_private_in_scope());
+                    assert_eq!(num_constraints, scope.num_constraints_in_scope());
+                    assert!(Circuit::is_satisfied());
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_constant() {
+        check_new::<u8>(Mode::Constant, 8, 0, 0, 0);
+        check_new::<i8>(Mode::Constant, 8, 0, 0, 0);
+        check_new::<u128>(Mode::Constant, 128, 0, 0, 0);
+        check_new::<i128>(Mode::Constant, 128, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_new_public() {
+        check_new::<u8>(Mode::Public, 0, 8, 0, 8);
+        check_new::<i8>(Mode::Public, 0, 8, 0, 8);
+        check_new::<u128>(Mode::Public, 0, 128, 0, 128);
+        check_new::<i128>(Mode::Public, 0, 128, 0, 128);
+    }
+
+    #[test]
+    fn test_new_private() {
+        check_new::<u8>(Mode::Private, 0, 0, 8, 8);
+        check_new::<i8>(Mode::Private, 0, 0, 8, 8);
+        check_new::<u128>(Mode::Private, 0, 0, 128, 128);
+        check_new::<i128>(Mode::Private, 0, 0, 128, 128);
+    }
+
+    #[test]
+    fn test_debug() {
+        let candidate = I8::<Circuit>::new(Mode::Private, -128);
+        assert_eq!("-128", format!("{:?}", candidate));
 
-    pub fn to_value(&self) -> I {}
+        let candidate = U128::<Circuit>::new(Mode::Public, u128::MAX);
+        assert_eq!(u128::MAX.to_string(), format!("{:?}", candidate));
+    }
 }
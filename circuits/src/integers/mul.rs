@@ -0,0 +1,333 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Mul<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn mul(self, other: Integer<E, I>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Mul<Integer<E, I>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn mul(self, other: Integer<E, I>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Mul<&Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn mul(self, other: &Integer<E, I>) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Mul<&Integer<E, I>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn mul(self, other: &Integer<E, I>) -> Self::Output {
+        self.mul_checked(other)
+    }
+}
+
+impl<E: Environment, I: IntegerType> MulAssign<Integer<E, I>> for Integer<E, I> {
+    fn mul_assign(&mut self, other: Integer<E, I>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment, I: IntegerType> MulAssign<&Integer<E, I>> for Integer<E, I> {
+    fn mul_assign(&mut self, other: &Integer<E, I>) {
+        *self = self.mul_checked(other);
+    }
+}
+
+impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
+    type Output = Self;
+
+    ///
+    /// Returns `self * other`, halting on overflow.
+    ///
+    /// If either operand is not a constant, an overflow leaves the circuit unsatisfied.
+    ///
+    fn mul_checked(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            match self.eject_value().checked_mul(&other.eject_value()) {
+                Some(value) => Integer::new(Mode::Constant, value),
+                None => E::halt("Integer overflow on multiplication of two constants"),
+            }
+        } else if I::IS_SIGNED {
+            // Multiply the magnitudes, which must not overflow the unsigned counterpart.
+            let magnitude = self.to_magnitude().mul_checked(&other.to_magnitude());
+
+            // Negate the product if the operands have different signs.
+            let is_negative = self.msb().is_neq(other.msb());
+            let product = Integer::from_bits_le(magnitude.bits_le.clone()).neg_wrapped_if(&is_negative);
+
+            // Ensure the product has the expected sign.
+            E::assert_eq(product.is_signed_overflow(&magnitude, &is_negative), E::zero());
+
+            product
+        } else {
+            Integer::from_bits_le(self.mul_bits(other, true))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> MulWrapped<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self * other`, wrapping around at the boundary of the type.
+    fn mul_wrapped(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            Integer::new(Mode::Constant, self.eject_value().wrapping_mul(&other.eject_value()))
+        } else {
+            // The lower `I::BITS` bits of the unsigned product of the bits in two's complement
+            // are the bits of the wrapped product, so signed integers are multiplied as unsigned.
+            Integer::from_bits_le(self.mul_bits(other, false))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    ///
+    /// Returns the lower `I::BITS` bits of the product of `self` and `other`, interpreting their bits as unsigned.
+    ///
+    /// If `checked` is `true`, the circuit is not satisfied if the product does not fit in `I::BITS` bits.
+    ///
+    fn mul_bits(&self, other: &Self, checked: bool) -> Vec<Boolean<E>> {
+        let num_bits = I::BITS;
+
+        // If the product fits in the base field, multiply the integers as base field elements.
+        if 2 * num_bits < E::BaseField::size_in_bits() - 1 {
+            let product = self.to_field() * other.to_field();
+            match checked {
+                true => Self::decompose(&product, num_bits),
+                false => {
+                    let mut bits_le = Self::decompose(&product, 2 * num_bits);
+                    bits_le.truncate(num_bits);
+                    bits_le
+                }
+            }
+        }
+        // Otherwise, split the integers into halves, and multiply the halves.
+        else {
+            //
+            // Let a = a_hi * 2^h + a_lo, and b = b_hi * 2^h + b_lo, for h = n / 2.
+            // Then a * b = z_2 * 2^n + z_1 * 2^h + z_0, where:
+            //   z_0 = a_lo * b_lo, which is less than 2^n,
+            //   z_1 = a_hi * b_lo + a_lo * b_hi, which is less than 2^(n + 1),
+            //   z_2 = a_hi * b_hi.
+            //
+            // The lower n bits of the product are the lower n bits of z_1 * 2^h + z_0,
+            // which is less than 2^(n + h + 2), and fits in the base field.
+            //
+            let half = num_bits / 2;
+            let (a_lo, a_hi) = (
+                Self::bits_to_field(&self.bits_le[..half]),
+                Self::bits_to_field(&self.bits_le[half..]),
+            );
+            let (b_lo, b_hi) = (
+                Self::bits_to_field(&other.bits_le[..half]),
+                Self::bits_to_field(&other.bits_le[half..]),
+            );
+
+            let z_0 = &a_lo * &b_lo;
+            let z_1 = (&a_hi * &b_lo) + (&a_lo * &b_hi);
+            let shift: BaseField<E> = (E::one() * Self::two_pow(half)).into();
+            let sum = z_0 + (z_1 * shift);
+
+            match checked {
+                true => {
+                    // Ensure z_2 is zero, and z_1 * 2^h + z_0 is less than 2^n.
+                    E::assert_eq(a_hi * b_hi, E::zero());
+                    Self::decompose(&sum, num_bits)
+                }
+                false => {
+                    let mut bits_le = Self::decompose(&sum, num_bits + half + 2);
+                    bits_le.truncate(num_bits);
+                    bits_le
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+    use std::panic::catch_unwind;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_mul<I: IntegerType>(
+        name: &str,
+        first: I,
+        second: I,
+        mode_a: Mode,
+        mode_b: Mode,
+        num_checked_constants: usize,
+        num_wrapped_private: usize,
+        num_wrapped_constraints: usize,
+        num_checked_private: usize,
+        num_checked_constraints: usize,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let is_constant = mode_a.is_constant() && mode_b.is_constant();
+
+        // Check the wrapped multiplication.
+        Circuit::scoped(&format!("{} wrapped", name), |scope| {
+            let expected = first.wrapping_mul(&second);
+            let candidate = a.mul_wrapped(&b);
+            assert_eq!(
+                expected,
+                candidate.eject_value(),
+                "{} != {} := ({} * {})",
+                expected,
+                candidate.eject_value(),
+                first,
+                second
+            );
+            assert!(Circuit::is_satisfied());
+
+            assert_eq!(if is_constant { I::BITS } else { 0 }, scope.num_constants_in_scope());
+            assert_eq!(0, scope.num_public_in_scope());
+            assert_eq!(num_wrapped_private, scope.num_private_in_scope());
+            assert_eq!(num_wrapped_constraints, scope.num_constraints_in_scope());
+        });
+
+        // Check the checked multiplication.
+        match first.checked_mul(&second) {
+            Some(expected) => Circuit::scoped(&format!("{} checked", name), |scope| {
+                let candidate = a * &b;
+                assert_eq!(
+                    expected,
+                    candidate.eject_value(),
+                    "{} != {} := ({} * {})",
+                    expected,
+                    candidate.eject_value(),
+                    first,
+                    second
+                );
+                assert!(Circuit::is_satisfied());
+
+                assert_eq!(num_checked_constants, scope.num_constants_in_scope());
+                assert_eq!(0, scope.num_public_in_scope());
+                assert_eq!(num_checked_private, scope.num_private_in_scope());
+                assert_eq!(num_checked_constraints, scope.num_constraints_in_scope());
+            }),
+            None => match is_constant {
+                true => assert!(catch_unwind(|| a.mul_checked(&b)).is_err()),
+                false => {
+                    Circuit::scoped(&format!("{} overflow", name), |_| {
+                        let _candidate = a.mul_checked(&b);
+                        assert!(!Circuit::is_satisfied());
+                    });
+                    Circuit::reset_circuit();
+                }
+            },
+        }
+    }
+
+    fn run_test<I: IntegerType>(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_checked_constants: usize,
+        num_wrapped_private: usize,
+        num_wrapped_constraints: usize,
+        num_checked_private: usize,
+        num_checked_constraints: usize,
+    ) where
+        Standard: Distribution<I>,
+    {
+        let check = |name: &str, first: I, second: I| {
+            check_mul(
+                &format!("{} ({:?}, {:?})", name, mode_a, mode_b),
+                first,
+                second,
+                mode_a,
+                mode_b,
+                num_checked_constants,
+                num_wrapped_private,
+                num_wrapped_constraints,
+                num_checked_private,
+                num_checked_constraints,
+            )
+        };
+
+        for i in 0..ITERATIONS {
+            let first: I = thread_rng().gen();
+            let second: I = thread_rng().gen();
+            check(&format!("Mul: {:?} * {:?} {}", mode_a, mode_b, i), first, second);
+
+            // Check products that do not overflow, by multiplying with a small integer.
+            let second = I::from_bits_le(&second.to_bits_le()[..2]);
+            check(&format!("Mul: {:?} * {:?} small {}", mode_a, mode_b, i), first, second);
+        }
+
+        // Check the boundaries of the type.
+        check("Mul: MAX * ONE", I::MAX, I::one());
+        check("Mul: MIN * ONE", I::MIN, I::one());
+        check("Mul: MIN * ZERO", I::MIN, I::zero());
+        check("Mul: MAX * MAX", I::MAX, I::MAX);
+        check("Mul: MIN * MAX", I::MIN, I::MAX);
+    }
+
+    #[test]
+    fn test_u8_mul() {
+        run_test::<u8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0, 0);
+        run_test::<u8>(Mode::Constant, Mode::Public, 0, 16, 17, 8, 9);
+        run_test::<u8>(Mode::Public, Mode::Constant, 0, 16, 17, 8, 9);
+        run_test::<u8>(Mode::Public, Mode::Public, 0, 17, 18, 9, 10);
+        run_test::<u8>(Mode::Private, Mode::Private, 0, 17, 18, 9, 10);
+    }
+
+    #[test]
+    fn test_i8_mul() {
+        run_test::<i8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0, 0);
+        run_test::<i8>(Mode::Constant, Mode::Public, 8, 16, 17, 46, 69);
+        run_test::<i8>(Mode::Public, Mode::Constant, 8, 16, 17, 46, 69);
+        run_test::<i8>(Mode::Public, Mode::Public, 0, 17, 18, 65, 98);
+        run_test::<i8>(Mode::Private, Mode::Private, 0, 17, 18, 65, 98);
+    }
+
+    #[test]
+    fn test_u64_mul() {
+        run_test::<u64>(Mode::Constant, Mode::Constant, 64, 0, 0, 0, 0);
+        run_test::<u64>(Mode::Public, Mode::Private, 0, 129, 130, 65, 66);
+    }
+
+    #[test]
+    fn test_u128_mul() {
+        run_test::<u128>(Mode::Constant, Mode::Constant, 128, 0, 0, 0, 0);
+        run_test::<u128>(Mode::Public, Mode::Private, 0, 197, 198, 132, 134);
+    }
+
+    #[test]
+    fn test_i128_mul() {
+        run_test::<i128>(Mode::Constant, Mode::Constant, 128, 0, 0, 0, 0);
+        run_test::<i128>(Mode::Public, Mode::Private, 0, 197, 198, 908, 1302);
+    }
+}
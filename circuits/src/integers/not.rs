@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Not for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns `(NOT a)`, for each bit.
+    fn not(self) -> Self::Output {
+        (&self).not()
+    }
+}
+
+impl<E: Environment, I: IntegerType> Not for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns `(NOT a)`, for each bit.
+    fn not(self) -> Self::Output {
+        Integer::from_bits_le(self.bits_le.iter().map(|bit| !bit).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_not<I: IntegerType>(mode: Mode)
+    where
+        Standard: Distribution<I>,
+    {
+        for i in 0..ITERATIONS {
+            let value: I = thread_rng().gen();
+            let expected = I::from_bits_le(&value.to_bits_le().iter().map(|bit| !bit).collect::<Vec<_>>());
+            let candidate = Integer::<Circuit, I>::new(mode, value);
+
+            Circuit::scoped(
+                &format!("Not: {} {:?} {}", std::any::type_name::<I>(), mode, i),
+                |scope| {
+                    let candidate = !candidate;
+                    assert_eq!(expected, candidate.eject_value(), "{} != !{}", expected, value);
+
+                    assert_eq!(0, scope.num_constants_in_scope());
+                    assert_eq!(0, scope.num_public_in_scope());
+                    assert_eq!(0, scope.num_private_in_scope());
+                    assert_eq!(0, scope.num_constraints_in_scope());
+                    assert!(Circuit::is_satisfied());
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_not() {
+        check_not::<u8>(Mode::Constant);
+        check_not::<i8>(Mode::Public);
+        check_not::<u64>(Mode::Private);
+        check_not::<i128>(Mode::Private);
+    }
+}
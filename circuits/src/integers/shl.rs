@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Shl<Integer<E, M>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn shl(self, rhs: Integer<E, M>) -> Self::Output {
+        self << &rhs
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Shl<Integer<E, M>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn shl(self, rhs: Integer<E, M>) -> Self::Output {
+        self << &rhs
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Shl<&Integer<E, M>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn shl(self, rhs: &Integer<E, M>) -> Self::Output {
+        &self << rhs
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Shl<&Integer<E, M>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn shl(self, rhs: &Integer<E, M>) -> Self::Output {
+        self.shl_checked(rhs)
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> ShlChecked<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    ///
+    /// Returns `self << rhs`, halting if `rhs` is not less than `I::BITS`.
+    ///
+    /// If either operand is not a constant, an out-of-range `rhs` leaves the circuit unsatisfied.
+    ///
+    fn shl_checked(&self, rhs: &Integer<E, M>) -> Self::Output {
+        if self.is_constant() && rhs.is_constant() {
+            match Self::shift_amount(rhs).and_then(|shift| self.eject_value().checked_shl(shift)) {
+                Some(value) => Integer::new(Mode::Constant, value),
+                None => E::halt("Shift amount is out of range on shift of two constants"),
+            }
+        } else {
+            Self::assert_shift_in_range(rhs);
+            self.shl_wrapped(rhs)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> ShlWrapped<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    ///
+    /// Returns `self << rhs`, using only the lower `log2(I::BITS)` bits of `rhs`.
+    ///
+    /// This method costs `2 * I::BITS` constraints for each bit of `rhs` that is used and not a constant.
+    ///
+    fn shl_wrapped(&self, rhs: &Integer<E, M>) -> Self::Output {
+        let num_shift_bits = Self::num_shift_bits();
+
+        if self.is_constant() && rhs.is_constant() {
+            let shift = u32::from_bits_le(&rhs.eject_value().to_bits_le()[..num_shift_bits]);
+            Integer::new(Mode::Constant, self.eject_value().wrapping_shl(shift))
+        } else {
+            // Shift by 2^k for each bit k of the shift amount, filling with zeros.
+            let fill = Boolean::new(Mode::Constant, false);
+
+            let mut bits_le = self.bits_le.clone();
+            for (k, bit) in rhs.bits_le.iter().take(num_shift_bits).enumerate() {
+                let shift = 1 << k;
+                bits_le = (0..I::BITS)
+                    .map(|i| match i < shift {
+                        true => Boolean::ternary(bit, &fill, &bits_le[i]),
+                        false => Boolean::ternary(bit, &bits_le[i - shift], &bits_le[i]),
+                    })
+                    .collect();
+            }
+
+            Integer::from_bits_le(bits_le)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+    use std::panic::catch_unwind;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_shl<I: IntegerType, M: Magnitude>(
+        name: &str,
+        first: I,
+        second: M,
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        let shift = u32::from_bits_le(&second.to_bits_le()[..Integer::<Circuit, I>::num_shift_bits()]);
+
+        // Check the wrapped shift.
+        Circuit::scoped(&format!("{} wrapped", name), |scope| {
+            let expected = first.wrapping_shl(shift);
+            let candidate = a.shl_wrapped(&b);
+            assert_eq!(
+                expected,
+                candidate.eject_value(),
+                "{} != {} := ({} << {})",
+                expected,
+                candidate.eject_value(),
+                first,
+                second
+            );
+            assert!(Circuit::is_satisfied());
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+        });
+
+        // Check the checked shift.
+        match Integer::<Circuit, I>::shift_amount(&b).and_then(|shift| first.checked_shl(shift)) {
+            Some(expected) => Circuit::scoped(&format!("{} checked", name), |_| {
+                let candidate = a << &b;
+                assert_eq!(
+                    expected,
+                    candidate.eject_value(),
+                    "{} != {} := ({} << {})",
+                    expected,
+                    candidate.eject_value(),
+                    first,
+                    second
+                );
+                assert!(Circuit::is_satisfied());
+            }),
+            None => match mode_b.is_constant() {
+                true => assert!(catch_unwind(|| a.shl_checked(&b)).is_err()),
+                false => {
+                    Circuit::scoped(&format!("{} out of range", name), |_| {
+                        let _candidate = a.shl_checked(&b);
+                        assert!(!Circuit::is_satisfied());
+                    });
+                    Circuit::reset_circuit();
+                }
+            },
+        }
+    }
+
+    fn run_test<I: IntegerType, M: Magnitude>(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) where
+        Standard: Distribution<I> + Distribution<M>,
+    {
+        let check = |name: &str, first: I, second: M| {
+            check_shl(
+                &format!("{} ({:?}, {:?})", name, mode_a, mode_b),
+                first,
+                second,
+                mode_a,
+                mode_b,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            )
+        };
+
+        for i in 0..ITERATIONS {
+            let first: I = thread_rng().gen();
+            let second: M = thread_rng().gen();
+            check(&format!("Shl: {:?} << {:?} {}", mode_a, mode_b, i), first, second);
+
+            // Check shift amounts that are in range.
+            let second = M::from_bits_le(&second.to_bits_le()[..Integer::<Circuit, I>::num_shift_bits()]);
+            check(
+                &format!("Shl: {:?} << {:?} in range {}", mode_a, mode_b, i),
+                first,
+                second,
+            );
+        }
+
+        // Check the boundaries of the type.
+        check("Shl: MIN << ZERO", I::MIN, M::zero());
+        check("Shl: MAX << ONE", I::MAX, M::one());
+        check("Shl: MIN << MAX", I::MIN, M::MAX);
+    }
+
+    #[test]
+    fn test_u8_shl() {
+        run_test::<u8, u8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0);
+        run_test::<u8, u8>(Mode::Public, Mode::Constant, 1, 0, 0, 0);
+        run_test::<u8, u8>(Mode::Public, Mode::Public, 1, 0, 24, 48);
+        run_test::<u8, u8>(Mode::Private, Mode::Private, 1, 0, 24, 48);
+    }
+
+    #[test]
+    fn test_i8_shl() {
+        run_test::<i8, u8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0);
+        run_test::<i8, u8>(Mode::Public, Mode::Constant, 1, 0, 0, 0);
+        run_test::<i8, u8>(Mode::Public, Mode::Public, 1, 0, 24, 48);
+        run_test::<i8, u8>(Mode::Private, Mode::Private, 1, 0, 24, 48);
+    }
+
+    #[test]
+    fn test_i128_shl() {
+        run_test::<i128, u32>(Mode::Constant, Mode::Constant, 128, 0, 0, 0);
+        run_test::<i128, u32>(Mode::Private, Mode::Public, 1, 0, 896, 1792);
+        run_test::<u128, u8>(Mode::Private, Mode::Public, 1, 0, 896, 1792);
+    }
+
+    #[test]
+    fn test_constant_shl_variable() {
+        for i in 0..ITERATIONS {
+            let first: i32 = thread_rng().gen();
+            let second: u8 = thread_rng().gen();
+
+            let a = I32::<Circuit>::new(Mode::Constant, first);
+            let b = U8::<Circuit>::new(Mode::Private, second % 32);
+
+            Circuit::scoped(&format!("Shl: Constant << Private {}", i), |_| {
+                let expected = first.wrapping_shl((second % 32) as u32);
+                let candidate = a << b;
+                assert_eq!(
+                    expected,
+                    candidate.eject_value(),
+                    "{} != {} := ({} << {})",
+                    expected,
+                    candidate.eject_value(),
+                    first,
+                    second
+                );
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+}
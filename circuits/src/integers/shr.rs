@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Shr<Integer<E, M>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn shr(self, rhs: Integer<E, M>) -> Self::Output {
+        self >> &rhs
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Shr<Integer<E, M>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn shr(self, rhs: Integer<E, M>) -> Self::Output {
+        self >> &rhs
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Shr<&Integer<E, M>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn shr(self, rhs: &Integer<E, M>) -> Self::Output {
+        &self >> rhs
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Shr<&Integer<E, M>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn shr(self, rhs: &Integer<E, M>) -> Self::Output {
+        self.shr_checked(rhs)
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> ShrChecked<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    ///
+    /// Returns `self >> rhs`, halting if `rhs` is not less than `I::BITS`.
+    ///
+    /// If either operand is not a constant, an out-of-range `rhs` leaves the circuit unsatisfied.
+    ///
+    fn shr_checked(&self, rhs: &Integer<E, M>) -> Self::Output {
+        if self.is_constant() && rhs.is_constant() {
+            match Self::shift_amount(rhs).and_then(|shift| self.eject_value().checked_shr(shift)) {
+                Some(value) => Integer::new(Mode::Constant, value),
+                None => E::halt("Shift amount is out of range on shift of two constants"),
+            }
+        } else {
+            Self::assert_shift_in_range(rhs);
+            self.shr_wrapped(rhs)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> ShrWrapped<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    ///
+    /// Returns `self >> rhs`, using only the lower `log2(I::BITS)` bits of `rhs`.
+    ///
+    /// This method costs `2 * I::BITS` constraints for each bit of `rhs` that is used and not a constant.
+    ///
+    fn shr_wrapped(&self, rhs: &Integer<E, M>) -> Self::Output {
+        let num_shift_bits = Self::num_shift_bits();
+
+        if self.is_constant() && rhs.is_constant() {
+            let shift = u32::from_bits_le(&rhs.eject_value().to_bits_le()[..num_shift_bits]);
+            Integer::new(Mode::Constant, self.eject_value().wrapping_shr(shift))
+        } else {
+            // Shift by 2^k for each bit k of the shift amount, filling with the sign bit for signed integers,
+            // and with zeros for unsigned integers.
+            let fill = match I::IS_SIGNED {
+                true => self.msb().clone(),
+                false => Boolean::new(Mode::Constant, false),
+            };
+
+            let mut bits_le = self.bits_le.clone();
+            for (k, bit) in rhs.bits_le.iter().take(num_shift_bits).enumerate() {
+                let shift = 1 << k;
+                bits_le = (0..I::BITS)
+                    .map(|i| match i + shift < I::BITS {
+                        true => Boolean::ternary(bit, &bits_le[i + shift], &bits_le[i]),
+                        false => Boolean::ternary(bit, &fill, &bits_le[i]),
+                    })
+                    .collect();
+            }
+
+            Integer::from_bits_le(bits_le)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+    use std::panic::catch_unwind;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_shr<I: IntegerType, M: Magnitude>(
+        name: &str,
+        first: I,
+        second: M,
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        let shift = u32::from_bits_le(&second.to_bits_le()[..Integer::<Circuit, I>::num_shift_bits()]);
+
+        // Check the wrapped shift.
+        Circuit::scoped(&format!("{} wrapped", name), |scope| {
+            let expected = first.wrapping_shr(shift);
+            let candidate = a.shr_wrapped(&b);
+            assert_eq!(
+                expected,
+                candidate.eject_value(),
+                "{} != {} := ({} >> {})",
+                expected,
+                candidate.eject_value(),
+                first,
+                second
+            );
+            assert!(Circuit::is_satisfied());
+
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(num_public, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+        });
+
+        // Check the checked shift.
+        match Integer::<Circuit, I>::shift_amount(&b).and_then(|shift| first.checked_shr(shift)) {
+            Some(expected) => Circuit::scoped(&format!("{} checked", name), |_| {
+                let candidate = a >> &b;
+                assert_eq!(
+                    expected,
+                    candidate.eject_value(),
+                    "{} != {} := ({} >> {})",
+                    expected,
+                    candidate.eject_value(),
+                    first,
+                    second
+                );
+                assert!(Circuit::is_satisfied());
+            }),
+            None => match mode_b.is_constant() {
+                true => assert!(catch_unwind(|| a.shr_checked(&b)).is_err()),
+                false => {
+                    Circuit::scoped(&format!("{} out of range", name), |_| {
+                        let _candidate = a.shr_checked(&b);
+                        assert!(!Circuit::is_satisfied());
+                    });
+                    Circuit::reset_circuit();
+                }
+            },
+        }
+    }
+
+    fn run_test<I: IntegerType, M: Magnitude>(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) where
+        Standard: Distribution<I> + Distribution<M>,
+    {
+        let check = |name: &str, first: I, second: M| {
+            check_shr(
+                &format!("{} ({:?}, {:?})", name, mode_a, mode_b),
+                first,
+                second,
+                mode_a,
+                mode_b,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            )
+        };
+
+        for i in 0..ITERATIONS {
+            let first: I = thread_rng().gen();
+            let second: M = thread_rng().gen();
+            check(&format!("Shr: {:?} >> {:?} {}", mode_a, mode_b, i), first, second);
+
+            // Check shift amounts that are in range.
+            let second = M::from_bits_le(&second.to_bits_le()[..Integer::<Circuit, I>::num_shift_bits()]);
+            check(
+                &format!("Shr: {:?} >> {:?} in range {}", mode_a, mode_b, i),
+                first,
+                second,
+            );
+        }
+
+        // Check the boundaries of the type.
+        check("Shr: MIN >> ZERO", I::MIN, M::zero());
+        check("Shr: MAX >> ONE", I::MAX, M::one());
+        check("Shr: MIN >> MAX", I::MIN, M::MAX);
+    }
+
+    #[test]
+    fn test_u8_shr() {
+        run_test::<u8, u8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0);
+        run_test::<u8, u8>(Mode::Public, Mode::Constant, 1, 0, 0, 0);
+        run_test::<u8, u8>(Mode::Public, Mode::Public, 1, 0, 24, 48);
+        run_test::<u8, u8>(Mode::Private, Mode::Private, 1, 0, 24, 48);
+    }
+
+    #[test]
+    fn test_i8_shr() {
+        run_test::<i8, u8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0);
+        run_test::<i8, u8>(Mode::Public, Mode::Constant, 0, 0, 0, 0);
+        run_test::<i8, u8>(Mode::Public, Mode::Public, 0, 0, 24, 48);
+        run_test::<i8, u8>(Mode::Private, Mode::Private, 0, 0, 24, 48);
+    }
+
+    #[test]
+    fn test_i128_shr() {
+        run_test::<i128, u32>(Mode::Constant, Mode::Constant, 128, 0, 0, 0);
+        run_test::<i128, u32>(Mode::Private, Mode::Public, 0, 0, 896, 1792);
+        run_test::<u128, u8>(Mode::Private, Mode::Public, 1, 0, 896, 1792);
+    }
+
+    #[test]
+    fn test_constant_shr_variable() {
+        for i in 0..ITERATIONS {
+            let first: i32 = thread_rng().gen();
+            let second: u8 = thread_rng().gen();
+
+            let a = I32::<Circuit>::new(Mode::Constant, first);
+            let b = U8::<Circuit>::new(Mode::Private, second % 32);
+
+            Circuit::scoped(&format!("Shr: Constant >> Private {}", i), |_| {
+                let expected = first.wrapping_shr((second % 32) as u32);
+                let candidate = a >> b;
+                assert_eq!(
+                    expected,
+                    candidate.eject_value(),
+                    "{} != {} := ({} >> {})",
+                    expected,
+                    candidate.eject_value(),
+                    first,
+                    second
+                );
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+}
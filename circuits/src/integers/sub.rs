@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Sub<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn sub(self, other: Integer<E, I>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Sub<Integer<E, I>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn sub(self, other: Integer<E, I>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Sub<&Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn sub(self, other: &Integer<E, I>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Sub<&Integer<E, I>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn sub(self, other: &Integer<E, I>) -> Self::Output {
+        self.sub_checked(other)
+    }
+}
+
+impl<E: Environment, I: IntegerType> SubAssign<Integer<E, I>> for Integer<E, I> {
+    fn sub_assign(&mut self, other: Integer<E, I>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment, I: IntegerType> SubAssign<&Integer<E, I>> for Integer<E, I> {
+    fn sub_assign(&mut self, other: &Integer<E, I>) {
+        *self = self.sub_checked(other);
+    }
+}
+
+impl<E: Environment, I: IntegerType> SubChecked<Self> for Integer<E, I> {
+    type Output = Self;
+
+    ///
+    /// Returns `self - other`, halting on underflow.
+    ///
+    /// If either operand is not a constant, an underflow leaves the circuit unsatisfied.
+    ///
+    fn sub_checked(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            match self.eject_value().checked_sub(&other.eject_value()) {
+                Some(value) => Integer::new(Mode::Constant, value),
+                None => E::halt("Integer underflow on subtraction of two constants"),
+            }
+        } else if I::IS_SIGNED {
+            let difference = self.sub_wrapped(other);
+
+            // Overflow occurs iff the operands have different signs, and the difference has a different sign from `self`.
+            let is_different_sign = self.msb().is_neq(other.msb());
+            let is_overflow = is_different_sign.and(&difference.msb().is_neq(self.msb()));
+            E::assert_eq(is_overflow, E::zero());
+
+            difference
+        } else {
+            // Decompose the difference into exactly `I::BITS` bits.
+            // If `other` is greater than `self`, the difference wraps around the base field, and does not fit.
+            let difference = self.to_field() - other.to_field();
+            Integer::from_bits_le(Self::decompose(&difference, I::BITS))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> SubWrapped<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self - other`, wrapping around at the boundary of the type.
+    fn sub_wrapped(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            Integer::new(Mode::Constant, self.eject_value().wrapping_sub(&other.eject_value()))
+        } else {
+            // Compute 2^n + self - other, which is in [1, 2^(n+1) - 1], and drop the carry bit.
+            let modulus: BaseField<E> = (E::one() * Self::two_pow(I::BITS)).into();
+            let difference = modulus + self.to_field() - other.to_field();
+            let mut bits_le = Self::decompose(&difference, I::BITS + 1);
+            bits_le.truncate(I::BITS);
+
+            Integer::from_bits_le(bits_le)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+    use std::panic::catch_unwind;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_sub<I: IntegerType>(
+        name: &str,
+        first: I,
+        second: I,
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        // Check the wrapped subtraction.
+        Circuit::scoped(&format!("{} wrapped", name), |scope| {
+            let expected = first.wrapping_sub(&second);
+            let candidate = a.sub_wrapped(&b);
+            assert_eq!(
+                expected,
+                candidate.eject_value(),
+                "{} != {} := ({} - {})",
+                expected,
+                candidate.eject_value(),
+                first,
+                second
+            );
+            assert!(Circuit::is_satisfied());
+
+            let (num_constants, num_private, num_constraints) = match (mode_a, mode_b) {
+                (Mode::Constant, Mode::Constant) => (I::BITS, 0, 0),
+                _ => (0, I::BITS + 1, I::BITS + 2),
+            };
+            assert_eq!(num_constants, scope.num_constants_in_scope());
+            assert_eq!(0, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+        });
+
+        // Check the checked subtraction.
+        match first.checked_sub(&second) {
+            Some(expected) => Circuit::scoped(&format!("{} checked", name), |scope| {
+                let candidate = a - &b;
+                assert_eq!(
+                    expected,
+                    candidate.eject_value(),
+                    "{} != {} := ({} - {})",
+                    expected,
+                    candidate.eject_value(),
+                    first,
+                    second
+                );
+                assert!(Circuit::is_satisfied());
+
+                assert_eq!(num_constants, scope.num_constants_in_scope());
+                assert_eq!(num_public, scope.num_public_in_scope());
+                assert_eq!(num_private, scope.num_private_in_scope());
+                assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            }),
+            None => match (mode_a, mode_b) {
+                (Mode::Constant, Mode::Constant) => assert!(catch_unwind(|| a.sub_checked(&b)).is_err()),
+                _ => {
+                    Circuit::scoped(&format!("{} underflow", name), |_| {
+                        let _candidate = a.sub_checked(&b);
+                        assert!(!Circuit::is_satisfied());
+                    });
+                    Circuit::reset_circuit();
+                }
+            },
+        }
+    }
+
+    fn run_test<I: IntegerType>(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) where
+        Standard: Distribution<I>,
+    {
+        let check = |name: &str, first: I, second: I| {
+            check_sub(
+                &format!("{} ({:?}, {:?})", name, mode_a, mode_b),
+                first,
+                second,
+                mode_a,
+                mode_b,
+                num_constants,
+                num_public,
+                num_private,
+                num_constraints,
+            )
+        };
+
+        for i in 0..ITERATIONS {
+            let first: I = thread_rng().gen();
+            let second: I = thread_rng().gen();
+            check(&format!("Sub: {:?} - {:?} {}", mode_a, mode_b, i), first, second);
+        }
+
+        // Check the boundaries of the type.
+        check("Sub: MIN - ONE", I::MIN, I::one());
+        check("Sub: MIN - MAX", I::MIN, I::MAX);
+        check("Sub: MAX - MIN", I::MAX, I::MIN);
+        check("Sub: MAX - MAX", I::MAX, I::MAX);
+    }
+
+    #[test]
+    fn test_u8_sub() {
+        run_test::<u8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0);
+        run_test::<u8>(Mode::Constant, Mode::Public, 0, 0, 8, 9);
+        run_test::<u8>(Mode::Public, Mode::Constant, 0, 0, 8, 9);
+        run_test::<u8>(Mode::Public, Mode::Public, 0, 0, 8, 9);
+        run_test::<u8>(Mode::Public, Mode::Private, 0, 0, 8, 9);
+        run_test::<u8>(Mode::Private, Mode::Private, 0, 0, 8, 9);
+    }
+
+    #[test]
+    fn test_i8_sub() {
+        run_test::<i8>(Mode::Constant, Mode::Constant, 8, 0, 0, 0);
+        run_test::<i8>(Mode::Constant, Mode::Public, 0, 0, 10, 13);
+        run_test::<i8>(Mode::Public, Mode::Constant, 0, 0, 11, 15);
+        run_test::<i8>(Mode::Public, Mode::Public, 0, 0, 12, 17);
+        run_test::<i8>(Mode::Public, Mode::Private, 0, 0, 12, 17);
+        run_test::<i8>(Mode::Private, Mode::Private, 0, 0, 12, 17);
+    }
+
+    #[test]
+    fn test_u64_sub() {
+        run_test::<u64>(Mode::Constant, Mode::Constant, 64, 0, 0, 0);
+        run_test::<u64>(Mode::Public, Mode::Private, 0, 0, 64, 65);
+    }
+
+    #[test]
+    fn test_i128_sub() {
+        run_test::<i128>(Mode::Constant, Mode::Constant, 128, 0, 0, 0);
+        run_test::<i128>(Mode::Public, Mode::Private, 0, 0, 132, 137);
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Ternary for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Constant `condition`
+        if condition.is_constant() {
+            match condition.eject_value() {
+                true => first.clone(),
+                false => second.clone(),
+            }
+        }
+        // Variables
+        else {
+            let bits_le = first
+                .bits_le
+                .iter()
+                .zip(second.bits_le.iter())
+                .map(|(a, b)| Boolean::ternary(condition, a, b))
+                .collect();
+
+            Integer::from_bits_le(bits_le)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_ternary<I: IntegerType>(
+        name: &str,
+        expected: I,
+        condition: Boolean<Circuit>,
+        a: Integer<Circuit, I>,
+        b: Integer<Circuit, I>,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        Circuit::scoped(name, |scope| {
+            let case = format!(
+                "({} ? {} : {})",
+                condition.eject_value(),
+                a.eject_value(),
+                b.eject_value()
+            );
+
+            let candidate = Integer::ternary(&condition, &a, &b);
+            assert_eq!(expected, candidate.eject_value(), "{}", case);
+
+            assert_eq!(
+                num_constants,
+                scope.num_constants_in_scope(),
+                "{} (num_constants)",
+                case
+            );
+            assert_eq!(num_public, scope.num_public_in_scope(), "{} (num_public)", case);
+            assert_eq!(num_private, scope.num_private_in_scope(), "{} (num_private)", case);
+            assert_eq!(
+                num_constraints,
+                scope.num_constraints_in_scope(),
+                "{} (num_constraints)",
+                case
+            );
+            assert!(Circuit::is_satisfied(), "{} (is_satisfied)", case);
+        });
+    }
+
+    fn run_test<I: IntegerType>(
+        mode_condition: Mode,
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) where
+        Standard: Distribution<I>,
+    {
+        for i in 0..ITERATIONS {
+            let first: I = thread_rng().gen();
+            let second: I = thread_rng().gen();
+
+            for flag in [true, false] {
+                let expected = if flag { first } else { second };
+                let condition = Boolean::<Circuit>::new(mode_condition, flag);
+                let a = Integer::<Circuit, I>::new(mode_a, first);
+                let b = Integer::<Circuit, I>::new(mode_b, second);
+
+                let name = format!(
+                    "Ternary: {:?} ? {:?} : {:?} {} {}",
+                    mode_condition, mode_a, mode_b, flag, i
+                );
+                check_ternary(
+                    &name,
+                    expected,
+                    condition,
+                    a,
+                    b,
+                    num_constants,
+                    num_public,
+                    num_private,
+                    num_constraints,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_u8_ternary() {
+        run_test::<u8>(Mode::Constant, Mode::Public, Mode::Private, 0, 0, 0, 0);
+        run_test::<u8>(Mode::Public, Mode::Public, Mode::Private, 0, 0, 8, 16);
+        run_test::<u8>(Mode::Private, Mode::Private, Mode::Private, 0, 0, 8, 16);
+    }
+
+    #[test]
+    fn test_i64_ternary() {
+        run_test::<i64>(Mode::Constant, Mode::Private, Mode::Private, 0, 0, 0, 0);
+        run_test::<i64>(Mode::Public, Mode::Public, Mode::Private, 0, 0, 64, 128);
+        run_test::<i64>(Mode::Private, Mode::Private, Mode::Private, 0, 0, 64, 128);
+    }
+}
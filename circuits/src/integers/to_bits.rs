@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> ToBits for Integer<E, I> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the little-endian bit representation of `self`, in two's complement.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.bits_le.clone()
+    }
+
+    /// Outputs the big-endian bit representation of `self`, in two's complement.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_le = self.to_bits_le();
+        bits_le.reverse();
+        bits_le
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use itertools::Itertools;
+    use rand::{distributions::Standard, prelude::Distribution, thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_to_bits<I: IntegerType>(mode: Mode)
+    where
+        Standard: Distribution<I>,
+    {
+        for i in 0..ITERATIONS {
+            let expected: I = thread_rng().gen();
+            let candidate = Integer::<Circuit, I>::new(mode, expected);
+
+            Circuit::scoped(&format!("{} {:?} {}", std::any::type_name::<I>(), mode, i), |scope| {
+                let candidate_bits = candidate.to_bits_le();
+                assert_eq!(I::BITS, candidate_bits.len());
+                for (expected_bit, candidate_bit) in expected.to_bits_le().iter().zip_eq(candidate_bits.iter()) {
+                    assert_eq!(*expected_bit, candidate_bit.eject_value());
+                }
+                assert_eq!(
+                    expected,
+                    Integer::<Circuit, I>::from_bits_le(candidate_bits).eject_value()
+                );
+
+                let mut candidate_bits = candidate.to_bits_be();
+                candidate_bits.reverse();
+                assert_eq!(
+                    expected,
+                    Integer::<Circuit, I>::from_bits_le(candidate_bits).eject_value()
+                );
+
+                assert_eq!(0, scope.num_constants_in_scope());
+                assert_eq!(0, scope.num_public_in_scope());
+                assert_eq!(0, scope.num_private_in_scope());
+                assert_eq!(0, scope.num_constraints_in_scope());
+            });
+        }
+    }
+
+    #[test]
+    fn test_to_bits() {
+        check_to_bits::<u8>(Mode::Constant);
+        check_to_bits::<i16>(Mode::Public);
+        check_to_bits::<u32>(Mode::Private);
+        check_to_bits::<i64>(Mode::Constant);
+        check_to_bits::<u128>(Mode::Public);
+        check_to_bits::<i128>(Mode::Private);
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Zero for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self::Boolean;
+
+    fn zero() -> Self {
+        Integer::new(Mode::Constant, I::zero())
+    }
+
+    fn is_zero(&self) -> Self::Output {
+        match self.is_constant() {
+            true => Boolean::new(Mode::Constant, self.eject_value() == I::zero()),
+            false => self.to_field().is_zero(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    #[test]
+    fn test_zero() {
+        Circuit::scoped("Zero", |scope| {
+            let candidate = U64::<Circuit>::zero();
+            assert_eq!(0u64, candidate.eject_value());
+            assert!(candidate.is_constant());
+
+            assert_eq!(64, scope.num_constants_in_scope());
+            assert_eq!(0, scope.num_public_in_scope());
+            assert_eq!(0, scope.num_private_in_scope());
+            assert_eq!(0, scope.num_constraints_in_scope());
+        });
+    }
+
+    #[test]
+    fn test_is_zero() {
+        let candidate = I32::<Circuit>::new(Mode::Private, 0);
+        assert!(candidate.is_zero().eject_value());
+
+        let candidate = I32::<Circuit>::new(Mode::Private, -1);
+        assert!(!candidate.is_zero().eject_value());
+
+        assert!(Circuit::is_satisfied());
+    }
+}
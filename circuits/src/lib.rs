@@ -32,6 +32,9 @@ pub use fields::*;
 pub mod group;
 pub use group::*;
 
+pub mod integers;
+pub use integers::*;
+
 // TODO (howardwu): This is temporary until the models interface is stabilized.
 #[allow(unused)]
 pub mod models;
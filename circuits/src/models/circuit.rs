@@ -15,177 +15,495 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::models::*;
-use snarkvm_curves::{
-    edwards_bls12::{EdwardsAffine, EdwardsParameters, Fq, Fr},
-    AffineCurve,
-};
+use snarkvm_curves::{edwards_bls12, edwards_bw6, AffineCurve};
 
-use once_cell::unsync::OnceCell;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, marker::PhantomData, mem, rc::Rc};
 
-thread_local! {
-    static CB: OnceCell<RefCell<Circuit>> = OnceCell::new();
+/// An environment, whose active circuit on the current thread may be replaced by a circuit instance.
+pub trait CircuitEnvironment: Environment {
+    /// Sets the active circuit on the current thread to the given scope, and returns the previous scope.
+    #[doc(hidden)]
+    fn replace_cs(scope: CircuitScope<Self::BaseField>) -> CircuitScope<Self::BaseField>;
 }
 
-#[derive(Clone)]
-pub struct Circuit(CircuitScope<Fq>);
-
-impl Circuit {
-    pub(super) fn cs() -> CircuitScope<<Self as Environment>::BaseField> {
-        CB.with(|cb| {
-            cb.get_or_init(|| {
-                let scope = CircuitScope::<<Self as Environment>::BaseField>::new(
-                    Rc::new(RefCell::new(ConstraintSystem::new())),
-                    "Circuit::new".to_string(),
-                    None,
-                );
-                RefCell::new(Circuit(scope))
-            })
-            .borrow()
-            .0
-            .clone()
-        })
-    }
+macro_rules! circuit_environment {
+    ($circuit:ident, $storage:ident, $affine:ty, $parameters:ty, $base_field:ty, $scalar_field:ty) => {
+        thread_local! {
+            /// The active circuit on the current thread, which is used by the environment.
+            static $storage: RefCell<CircuitScope<$base_field>> = RefCell::new(CircuitInstance::<$circuit>::new().scope);
+        }
+
+        #[derive(Clone)]
+        pub struct $circuit;
+
+        impl $circuit {
+            pub(super) fn cs() -> CircuitScope<<Self as Environment>::BaseField> {
+                $storage.with(|circuit| circuit.borrow().clone())
+            }
+
+            ///
+            /// Resets the active circuit on the current thread to a new, empty circuit.
+            ///
+            pub fn reset_circuit() {
+                Self::replace_cs(CircuitInstance::<Self>::new().scope);
+            }
+
+            ///
+            /// Returns the constraint system of the active circuit on the current thread,
+            /// and resets the active circuit to a new, empty circuit.
+            ///
+            pub fn take_constraint_system() -> ConstraintSystem<<Self as Environment>::BaseField> {
+                let scope = Self::replace_cs(CircuitInstance::<Self>::new().scope);
+                CircuitInstance::<Self>::from(scope).into_constraint_system()
+            }
+
+            /// Returns a nested breakdown of the cost of the circuit by scope.
+            pub fn to_cost_report() -> CostReport {
+                Self::cs().cs.borrow().to_cost_report()
+            }
+
+            pub fn print_circuit() {
+                println!("{:?}", Self::cs().cs.borrow());
+            }
+        }
+
+        impl CircuitEnvironment for $circuit {
+            fn replace_cs(scope: CircuitScope<Self::BaseField>) -> CircuitScope<Self::BaseField> {
+                $storage.with(|circuit| circuit.replace(scope))
+            }
+        }
+
+        impl Environment for $circuit {
+            type Affine = $affine;
+            type AffineParameters = $parameters;
+            type BaseField = $base_field;
+            type ScalarField = $scalar_field;
+
+            /// Returns the `zero` constant.
+            fn zero() -> LinearCombination<Self::BaseField> {
+                LinearCombination::zero()
+            }
+
+            /// Returns the `one` constant.
+            fn one() -> LinearCombination<Self::BaseField> {
+                LinearCombination::one()
+            }
+
+            fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
+                match mode {
+                    Mode::Constant => Self::cs().new_constant(value),
+                    Mode::Public => Self::cs().new_public(value),
+                    Mode::Private => Self::cs().new_private(value),
+                }
+            }
+
+            fn scope(name: &str) -> CircuitScope<Self::BaseField> {
+                let scope = Self::cs().new_scope(name);
+                Self::replace_cs(scope.clone());
+                scope
+            }
+
+            fn scoped<Fn>(name: &str, logic: Fn)
+            where
+                Fn: FnOnce(CircuitScope<Self::BaseField>),
+            {
+                // Fetch a copy of the current environment.
+                let current = Self::cs();
+
+                // Set the entire environment to the new scope, and run the logic.
+                let scope = current.clone().new_scope(name);
+                Self::replace_cs(scope.clone());
+                logic(scope);
+
+                // Return the entire environment to the previous scope.
+                Self::replace_cs(current);
+            }
+
+            fn enforce<Fn, A, B, C>(constraint: Fn)
+            where
+                Fn: FnOnce() -> (A, B, C),
+                A: Into<LinearCombination<Self::BaseField>>,
+                B: Into<LinearCombination<Self::BaseField>>,
+                C: Into<LinearCombination<Self::BaseField>>,
+            {
+                Self::cs().enforce(constraint)
+            }
+
+            fn is_satisfied() -> bool {
+                Self::cs().is_satisfied()
+            }
+
+            fn num_constants() -> usize {
+                Self::cs().num_constants()
+            }
+
+            fn num_public() -> usize {
+                Self::cs().num_public()
+            }
+
+            fn num_private() -> usize {
+                Self::cs().num_private()
+            }
+
+            fn num_constraints() -> usize {
+                Self::cs().num_constraints()
+            }
+
+            fn num_constants_in_scope(scope: &Scope) -> usize {
+                Self::cs().cs.borrow().num_constants_in_scope(scope)
+            }
+
+            fn num_public_in_scope(scope: &Scope) -> usize {
+                Self::cs().cs.borrow().num_public_in_scope(scope)
+            }
+
+            fn num_private_in_scope(scope: &Scope) -> usize {
+                Self::cs().cs.borrow().num_private_in_scope(scope)
+            }
 
-    #[cfg(test)]
-    pub fn reset_circuit() {
-        CB.with(|cb| {
-            (*cb.get().unwrap().borrow_mut()).0 = CircuitScope::<<Self as Environment>::BaseField>::new(
+            fn num_constraints_in_scope(scope: &Scope) -> usize {
+                Self::cs().cs.borrow().num_constraints_in_scope(scope)
+            }
+
+            fn affine_from_x_coordinate(x: Self::BaseField) -> Self::Affine {
+                if let Some(element) = Self::Affine::from_x_coordinate(x, true) {
+                    if element.is_in_correct_subgroup_assuming_on_curve() {
+                        return element;
+                    }
+                }
+
+                if let Some(element) = Self::Affine::from_x_coordinate(x, false) {
+                    if element.is_in_correct_subgroup_assuming_on_curve() {
+                        return element;
+                    }
+                }
+
+                Self::halt(format!(
+                    "Failed to recover an affine element from an x-coordinate of {:?}",
+                    x
+                ))
+            }
+
+            fn halt<S: Into<String>, T>(message: S) -> T {
+                let error = message.into();
+                eprintln!("{}", &error);
+                panic!("{}", &error)
+            }
+        }
+    };
+}
+
+// The environment for inner circuits, over the scalar field of BLS12-377, with the Edwards-BLS12 curve.
+circuit_environment!(
+    Circuit,
+    CIRCUIT,
+    edwards_bls12::EdwardsAffine,
+    edwards_bls12::EdwardsParameters,
+    edwards_bls12::Fq,
+    edwards_bls12::Fr
+);
+
+// The environment for outer circuits, over the scalar field of BW6-761, with the Edwards-BW6 curve.
+circuit_environment!(
+    OuterCircuit,
+    OUTER_CIRCUIT,
+    edwards_bw6::EdwardsAffine,
+    edwards_bw6::EdwardsParameters,
+    edwards_bw6::Fq,
+    edwards_bw6::Fr
+);
+
+///
+/// An instance of a circuit, which owns its own constraint system.
+///
+/// Gadgets are synthesized into the active circuit of the environment on the current thread.
+/// A thread may build any number of independent instances, by entering each instance to make it the
+/// active circuit, and any number of threads may build instances in parallel.
+///
+/// Note: The active circuit is held in a thread-local, and an instance shares its constraint system with it
+/// through an `Rc`. As such, an instance is neither `Send` nor `Sync`, and must be built and consumed on the
+/// thread that created it. A gadget belongs to the instance that was active when it was created, and must only
+/// be used while that instance is active, although instances may be entered in any interleaved or nested order.
+///
+pub struct CircuitInstance<E: CircuitEnvironment> {
+    scope: CircuitScope<E::BaseField>,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: CircuitEnvironment> CircuitInstance<E> {
+    ///
+    /// Initializes a new instance of a circuit, with an empty constraint system.
+    ///
+    pub fn new() -> Self {
+        Self {
+            scope: CircuitScope::new(
                 Rc::new(RefCell::new(ConstraintSystem::new())),
                 "Circuit::new".to_string(),
                 None,
-            );
-        });
-
-        assert_eq!(0, Self::cs().num_constants());
-        assert_eq!(1, Self::cs().num_public());
-        assert_eq!(0, Self::cs().num_private());
-        assert_eq!(0, Self::cs().num_constraints());
-    }
-
-    pub fn print_circuit() {
-        println!("{:?}", Self::cs().cs.borrow());
+            ),
+            _phantom: PhantomData,
+        }
     }
-}
 
-impl Environment for Circuit {
-    type Affine = EdwardsAffine;
-    type AffineParameters = EdwardsParameters;
-    type BaseField = Fq;
-    type ScalarField = Fr;
+    ///
+    /// Runs the given logic with this instance as the active circuit on the current thread,
+    /// and restores the previously active circuit afterwards.
+    ///
+    pub fn enter<T, Fn: FnOnce() -> T>(&self, logic: Fn) -> T {
+        /// Restores the previously active circuit when dropped, including when the logic halts.
+        struct Guard<E: CircuitEnvironment>(Option<CircuitScope<E::BaseField>>);
+
+        impl<E: CircuitEnvironment> Drop for Guard<E> {
+            fn drop(&mut self) {
+                if let Some(previous) = self.0.take() {
+                    E::replace_cs(previous);
+                }
+            }
+        }
 
-    /// Returns the `zero` constant.
-    fn zero() -> LinearCombination<Self::BaseField> {
-        LinearCombination::zero()
+        let _guard = Guard::<E>(Some(E::replace_cs(self.scope.clone())));
+        logic()
     }
 
-    /// Returns the `one` constant.
-    fn one() -> LinearCombination<Self::BaseField> {
-        LinearCombination::one()
+    /// Returns `true` if all constraints in the instance are satisfied.
+    pub fn is_satisfied(&self) -> bool {
+        self.scope.is_satisfied()
     }
 
-    fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
-        match mode {
-            Mode::Constant => Self::cs().new_constant(value),
-            Mode::Public => Self::cs().new_public(value),
-            Mode::Private => Self::cs().new_private(value),
-        }
+    /// Returns the number of constants in the instance.
+    pub fn num_constants(&self) -> usize {
+        self.scope.num_constants()
     }
 
-    fn scope(name: &str) -> CircuitScope<Self::BaseField> {
-        CB.with(|cb| {
-            let scope = Self::cs().new_scope(name);
-            (*cb.get().unwrap().borrow_mut()).0 = scope.clone();
-            scope
-        })
+    /// Returns the number of public variables in the instance.
+    pub fn num_public(&self) -> usize {
+        self.scope.num_public()
     }
 
-    fn scoped<Fn>(name: &str, logic: Fn)
-    where
-        Fn: FnOnce(CircuitScope<Self::BaseField>),
-    {
-        CB.with(|cb| {
-            // Fetch a copy of the current environment.
-            let current = Self::cs();
+    /// Returns the number of private variables in the instance.
+    pub fn num_private(&self) -> usize {
+        self.scope.num_private()
+    }
 
-            // Set the entire environment to the new scope, and run the logic.
-            let scope = current.clone().new_scope(name);
-            (*cb.get().unwrap().borrow_mut()).0 = scope.clone();
-            logic(scope);
+    /// Returns the number of constraints in the instance.
+    pub fn num_constraints(&self) -> usize {
+        self.scope.num_constraints()
+    }
 
-            // Return the entire environment to the previous scope.
-            (*cb.get().unwrap().borrow_mut()).0 = current;
-        });
+    /// Returns a nested breakdown of the cost of the instance by scope.
+    pub fn to_cost_report(&self) -> CostReport {
+        self.scope.cs.borrow().to_cost_report()
     }
 
-    fn enforce<Fn, A, B, C>(constraint: Fn)
-    where
-        Fn: FnOnce() -> (A, B, C),
-        A: Into<LinearCombination<Self::BaseField>>,
-        B: Into<LinearCombination<Self::BaseField>>,
-        C: Into<LinearCombination<Self::BaseField>>,
-    {
-        Self::cs().enforce(constraint)
+    ///
+    /// Returns the constraint system of the instance.
+    ///
+    /// If the instance is still the active circuit of an enclosing `enter`,
+    /// its constraint system is taken out, and the instance continues with an empty one.
+    ///
+    pub fn into_constraint_system(self) -> ConstraintSystem<E::BaseField> {
+        match Rc::try_unwrap(self.scope.cs) {
+            Ok(cs) => cs.into_inner(),
+            Err(cs) => mem::replace(&mut *cs.borrow_mut(), ConstraintSystem::new()),
+        }
     }
+}
 
-    fn is_satisfied() -> bool {
-        Self::cs().is_satisfied()
+impl<E: CircuitEnvironment> Default for CircuitInstance<E> {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    fn num_constants() -> usize {
-        Self::cs().num_constants()
+impl<E: CircuitEnvironment> From<CircuitScope<E::BaseField>> for CircuitInstance<E> {
+    fn from(scope: CircuitScope<E::BaseField>) -> Self {
+        Self {
+            scope,
+            _phantom: PhantomData,
+        }
     }
+}
 
-    fn num_public() -> usize {
-        Self::cs().num_public()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{traits::*, Affine, BaseField, Boolean};
+    use snarkvm_fields::{Field, One};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    /// Synthesizes a circuit with `num_constraints` constraints into the active circuit.
+    fn synthesize(num_constraints: usize) {
+        synthesize_in::<Circuit>(num_constraints)
     }
 
-    fn num_private() -> usize {
-        Self::cs().num_private()
+    /// Synthesizes a circuit with `num_constraints` constraints into the active circuit of the given environment.
+    fn synthesize_in<E: Environment>(num_constraints: usize) {
+        let a = BaseField::<E>::new(Mode::Private, E::BaseField::one());
+        for _ in 0..num_constraints {
+            E::assert_eq(&a, &a);
+        }
     }
 
-    fn num_constraints() -> usize {
-        Self::cs().num_constraints()
+    /// Synthesizes boolean, field, and group gadgets into the active circuit of the given environment.
+    fn check_gadgets<E: Environment>() {
+        let a = Boolean::<E>::new(Mode::Private, true);
+        let b = Boolean::<E>::new(Mode::Public, false);
+        assert!(a.xor(&b).eject_value());
+
+        let one = E::BaseField::one();
+        let c = BaseField::<E>::new(Mode::Private, one);
+        let d = BaseField::<E>::new(Mode::Private, one.double());
+        assert_eq!(one + one.double(), (c + d).eject_value());
+
+        let point: E::Affine = UniformRand::rand(&mut thread_rng());
+        let e = Affine::<E>::new(Mode::Private, point.to_x_coordinate(), None);
+        assert_eq!(point + point, e.double().eject_value());
     }
 
-    fn num_constants_in_scope(scope: &Scope) -> usize {
-        Self::cs().cs.borrow().num_constants_in_scope(scope)
+    #[test]
+    fn test_instances_are_independent() {
+        let first = CircuitInstance::<Circuit>::new();
+        let second = CircuitInstance::<Circuit>::new();
+
+        first.enter(|| synthesize(3));
+        second.enter(|| synthesize(5));
+        first.enter(|| synthesize(1));
+
+        assert_eq!(2, first.num_private());
+        assert_eq!(4, first.num_constraints());
+        assert_eq!(1, second.num_private());
+        assert_eq!(5, second.num_constraints());
+        assert!(first.is_satisfied());
+        assert!(second.is_satisfied());
+
+        let cs = first.into_constraint_system();
+        assert_eq!(1, cs.num_public());
+        assert_eq!(2, cs.num_private());
+        assert_eq!(4, cs.num_constraints());
     }
 
-    fn num_public_in_scope(scope: &Scope) -> usize {
-        Self::cs().cs.borrow().num_public_in_scope(scope)
+    #[test]
+    fn test_interleaved_instances() {
+        Circuit::reset_circuit();
+        let first = CircuitInstance::<Circuit>::new();
+        let second = CircuitInstance::<Circuit>::new();
+
+        // Create a gadget in each instance, and use each gadget after entering the other instance.
+        let one = <Circuit as Environment>::BaseField::one();
+        let a = first.enter(|| BaseField::<Circuit>::new(Mode::Private, one));
+        let b = second.enter(|| BaseField::<Circuit>::new(Mode::Public, one.double()));
+        first.enter(|| Circuit::assert_eq(&a, &a));
+
+        // Enter the first instance within the second, and ensure the second is active again afterwards.
+        second.enter(|| {
+            first.enter(|| Circuit::assert_eq(&a, &a));
+            Circuit::assert_eq(&b, &b);
+            assert_eq!(2, Circuit::num_public());
+        });
+
+        assert_eq!(1, first.num_public());
+        assert_eq!(1, first.num_private());
+        assert_eq!(2, first.num_constraints());
+        assert_eq!(2, second.num_public());
+        assert_eq!(0, second.num_private());
+        assert_eq!(1, second.num_constraints());
+        assert!(first.is_satisfied());
+        assert!(second.is_satisfied());
+
+        // Ensure the active circuit of the thread is unaffected.
+        assert_eq!(1, Circuit::num_public());
+        assert_eq!(0, Circuit::num_private());
+        assert_eq!(0, Circuit::num_constraints());
     }
 
-    fn num_private_in_scope(scope: &Scope) -> usize {
-        Self::cs().cs.borrow().num_private_in_scope(scope)
+    #[test]
+    fn test_enter_restores_the_active_circuit() {
+        Circuit::reset_circuit();
+        let _ = Boolean::<Circuit>::new(Mode::Private, true);
+        assert_eq!(1, Circuit::num_private());
+
+        let instance = CircuitInstance::<Circuit>::new();
+        instance.enter(|| {
+            assert_eq!(0, Circuit::num_private());
+            synthesize(2);
+            assert_eq!(1, Circuit::num_private());
+        });
+        assert_eq!(1, Circuit::num_private());
+        assert_eq!(1, Circuit::num_constraints());
+
+        // Ensure the active circuit is restored when the logic halts.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            instance.enter(|| Circuit::halt::<_, ()>("Halting in an instance"))
+        }));
+        assert!(result.is_err());
+        assert_eq!(1, Circuit::num_private());
     }
 
-    fn num_constraints_in_scope(scope: &Scope) -> usize {
-        Self::cs().cs.borrow().num_constraints_in_scope(scope)
+    #[test]
+    fn test_take_constraint_system() {
+        Circuit::reset_circuit();
+        synthesize(3);
+
+        let cs = Circuit::take_constraint_system();
+        assert_eq!(1, cs.num_private());
+        assert_eq!(3, cs.num_constraints());
+        assert!(cs.is_satisfied());
+
+        assert_eq!(0, Circuit::num_constants());
+        assert_eq!(1, Circuit::num_public());
+        assert_eq!(0, Circuit::num_private());
+        assert_eq!(0, Circuit::num_constraints());
     }
 
-    fn affine_from_x_coordinate(x: Self::BaseField) -> Self::Affine {
-        if let Some(element) = Self::Affine::from_x_coordinate(x, true) {
-            if element.is_in_correct_subgroup_assuming_on_curve() {
-                return element;
-            }
-        }
+    #[test]
+    fn test_outer_circuit() {
+        OuterCircuit::reset_circuit();
+        Circuit::reset_circuit();
 
-        if let Some(element) = Self::Affine::from_x_coordinate(x, false) {
-            if element.is_in_correct_subgroup_assuming_on_curve() {
-                return element;
-            }
-        }
+        check_gadgets::<OuterCircuit>();
+        assert!(OuterCircuit::num_constraints() > 0);
+        assert!(OuterCircuit::is_satisfied());
+
+        // Ensure the outer circuit is independent of the inner circuit.
+        assert_eq!(0, Circuit::num_private());
+        assert_eq!(0, Circuit::num_constraints());
 
-        Self::halt(format!(
-            "Failed to recover an affine element from an x-coordinate of {:?}",
-            x
-        ))
+        check_gadgets::<Circuit>();
+        assert_eq!(OuterCircuit::num_private(), Circuit::num_private());
+        assert_eq!(OuterCircuit::num_constraints(), Circuit::num_constraints());
+        assert!(Circuit::is_satisfied());
     }
 
-    fn halt<S: Into<String>, T>(message: S) -> T {
-        let error = message.into();
-        eprintln!("{}", &error);
-        panic!("{}", &error)
+    #[test]
+    fn test_outer_circuit_instance() {
+        let instance = CircuitInstance::<OuterCircuit>::new();
+        instance.enter(|| synthesize_in::<OuterCircuit>(3));
+        assert_eq!(1, instance.num_private());
+        assert_eq!(3, instance.num_constraints());
+
+        let cs = instance.into_constraint_system();
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_parallel_instances() {
+        let handles = (0..4)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    let instance = CircuitInstance::<Circuit>::new();
+                    instance.enter(|| synthesize(i + 1));
+                    instance.into_constraint_system()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            let cs = handle.join().unwrap();
+            assert_eq!(1, cs.num_private());
+            assert_eq!(i + 1, cs.num_constraints());
+            assert!(cs.is_satisfied());
+        }
     }
 }
@@ -55,6 +55,15 @@ impl CircuitCounter {
         }
     }
 
+    /// Returns every scope with a nonzero count, possibly more than once.
+    pub(super) fn scopes(&self) -> impl Iterator<Item = &Scope> {
+        self.constants
+            .keys()
+            .chain(self.public.keys())
+            .chain(self.private.keys())
+            .chain(self.constraints.keys())
+    }
+
     pub(super) fn num_constants_in_scope(&self, scope: &Scope) -> usize {
         match self.constants.get(scope) {
             Some(counter) => *counter,
@@ -30,6 +30,7 @@ pub struct CircuitScope<F: PrimeField> {
 
 impl<F: PrimeField> CircuitScope<F> {
     pub(super) fn new(circuit: Rc<RefCell<ConstraintSystem<F>>>, scope: Scope, previous: Option<Scope>) -> Self {
+        circuit.borrow_mut().push_scope(&scope);
         Self {
             cs: circuit,
             scope,
@@ -38,11 +39,7 @@ impl<F: PrimeField> CircuitScope<F> {
     }
 
     pub(super) fn new_scope(self, name: &str) -> Self {
-        Self {
-            cs: self.cs.clone(),
-            scope: format!("{}/{}", self.scope, name),
-            previous: Some(self.scope),
-        }
+        Self::new(self.cs.clone(), format!("{}/{}", self.scope, name), Some(self.scope))
     }
 
     pub(crate) fn new_constant(&mut self, value: F) -> Variable<F> {
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::models::{ConstraintSystem, LinearCombination, Variable};
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{One, PrimeField};
 
 use std::collections::HashMap;
 
@@ -29,12 +29,37 @@ impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for ConstraintSystem<
     fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<F>>(
         &self,
         cs: &mut CS,
+    ) -> Result<(), snarkvm_r1cs::SynthesisError> {
+        self.convert(cs, false)
+    }
+}
+
+impl<F: PrimeField> ConstraintSystem<F> {
+    ///
+    /// Synthesizes the constraints into the given `cs`, where the constant one of this system
+    /// is the constant one of `cs`, rather than its first public input. The public inputs of `cs`
+    /// are then exactly the public variables allocated in the circuit.
+    ///
+    pub fn generate_constraints_with_shared_one<CS: snarkvm_r1cs::ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), snarkvm_r1cs::SynthesisError> {
+        self.convert(cs, true)
+    }
+
+    fn convert<CS: snarkvm_r1cs::ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        share_one: bool,
     ) -> Result<(), snarkvm_r1cs::SynthesisError> {
         let mut converter = Converter {
             public: Default::default(),
             private: Default::default(),
         };
 
+        // The offset of the public variables in the second system, which is 1 if the constant one is allocated.
+        let offset = if share_one { 0 } else { 1 };
+
         // Ensure the given `cs` is starting off clean.
         assert_eq!(1, cs.num_public_variables());
         assert_eq!(0, cs.num_private_variables());
@@ -49,10 +74,19 @@ impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for ConstraintSystem<
                         "Public variables in first system must be processed in lexicographic order"
                     );
 
-                    let gadget = cs.alloc_input(|| format!("Public {}", i), || Ok(*value))?;
+                    let gadget = match share_one && *index == 0 {
+                        true => {
+                            assert!(
+                                value.is_one(),
+                                "The first public variable in the first system must be one"
+                            );
+                            CS::one()
+                        }
+                        false => cs.alloc_input(|| format!("Public {}", i), || Ok(*value))?,
+                    };
 
                     assert_eq!(
-                        snarkvm_r1cs::Index::Public((index + 1) as usize),
+                        snarkvm_r1cs::Index::Public((index + offset) as usize),
                         gadget.get_unchecked(),
                         "Public variables in the second system must match the first system (with an off-by-1 for the public case)"
                     );
@@ -114,7 +148,7 @@ impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for ConstraintSystem<
                         Variable::Public(index, _) => {
                             let gadget = converter.public.get(index).unwrap();
                             assert_eq!(
-                                snarkvm_r1cs::Index::Public((index + 1) as usize),
+                                snarkvm_r1cs::Index::Public((index + offset) as usize),
                                 gadget.get_unchecked(),
                                 "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
                             );
@@ -151,7 +185,7 @@ impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for ConstraintSystem<
         }
 
         // Ensure the given `cs` matches in size with the first system.
-        assert_eq!(self.num_public() + 1, cs.num_public_variables());
+        assert_eq!(self.num_public() + offset as usize, cs.num_public_variables());
         assert_eq!(self.num_private(), cs.num_private_variables());
         assert_eq!(self.num_constraints(), cs.num_constraints());
 
@@ -205,6 +239,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_constraint_converter_with_shared_one() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+
+        let mut cs = snarkvm_r1cs::TestConstraintSystem::new();
+        Circuit::cs()
+            .cs
+            .borrow()
+            .generate_constraints_with_shared_one(&mut cs)
+            .unwrap();
+        {
+            use snarkvm_r1cs::ConstraintSystem;
+            assert_eq!(Circuit::num_public(), cs.num_public_variables());
+            assert_eq!(Circuit::num_private(), cs.num_private_variables());
+            assert_eq!(Circuit::num_constraints(), cs.num_constraints());
+            assert!(cs.is_satisfied());
+        }
+    }
+
     #[test]
     fn test_groth16() {
         let _candidate_output = create_example_circuit::<Circuit>();
@@ -263,10 +316,11 @@ mod tests {
                 snarkvm_marlin::ahp::AHPForR1CS::<Fr, MarlinRecursiveMode>::max_degree(200, 200, 300).unwrap();
             let universal_srs = MarlinInst::universal_setup(max_degree, rng).unwrap();
 
-            let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &*Circuit::cs().cs.borrow()).unwrap();
+            let (index_pk, index_vk) =
+                MarlinInst::circuit_setup(&universal_srs, &*Circuit::cs().cs.borrow(), -1).unwrap();
             println!("Called circuit setup");
 
-            let proof = MarlinInst::prove(&index_pk, &*Circuit::cs().cs.borrow(), rng).unwrap();
+            let proof = MarlinInst::prove(&index_pk, &*Circuit::cs().cs.borrow(), rng, -1).unwrap();
             println!("Called prover");
 
             assert!(MarlinInst::verify(&index_vk, &[one, one], &proof).unwrap());
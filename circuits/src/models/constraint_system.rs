@@ -17,10 +17,10 @@
 use crate::models::*;
 use snarkvm_fields::traits::*;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 #[derive(Debug)]
-pub(super) struct ConstraintSystem<F: PrimeField> {
+pub struct ConstraintSystem<F: PrimeField> {
     constants: Vec<Variable<F>>,
     public: Vec<Variable<F>>,
     private: Vec<Variable<F>>,
@@ -43,6 +43,13 @@ impl<F: PrimeField> ConstraintSystem<F> {
         }
     }
 
+    /// Records the given scope, so that it is reported even if nothing is allocated in it.
+    pub(super) fn push_scope(&mut self, scope: &Scope) {
+        if !self.scopes.contains(scope) {
+            self.scopes.insert(scope.clone());
+        }
+    }
+
     pub(super) fn new_constant(&mut self, value: F, scope: Scope) -> Variable<F> {
         let variable = Variable::Constant(value);
         self.constants.push(variable);
@@ -83,7 +90,7 @@ impl<F: PrimeField> ConstraintSystem<F> {
         }
     }
 
-    pub(super) fn is_satisfied(&self) -> bool {
+    pub fn is_satisfied(&self) -> bool {
         for (a, b, c) in &self.constraints {
             let a = a.to_value();
             let b = b.to_value();
@@ -96,19 +103,19 @@ impl<F: PrimeField> ConstraintSystem<F> {
         true
     }
 
-    pub(super) fn num_constants(&self) -> usize {
+    pub fn num_constants(&self) -> usize {
         self.constants.len()
     }
 
-    pub(super) fn num_public(&self) -> usize {
+    pub fn num_public(&self) -> usize {
         self.public.len()
     }
 
-    pub(super) fn num_private(&self) -> usize {
+    pub fn num_private(&self) -> usize {
         self.private.len()
     }
 
-    pub(super) fn num_constraints(&self) -> usize {
+    pub fn num_constraints(&self) -> usize {
         self.constraints.len()
     }
 
@@ -128,6 +135,24 @@ impl<F: PrimeField> ConstraintSystem<F> {
         self.counter.num_constraints_in_scope(scope)
     }
 
+    /// Returns the values of the public variables, which are the public inputs to verify a proof.
+    pub fn to_public_inputs(&self) -> Vec<F> {
+        self.public.iter().map(Variable::value).collect()
+    }
+
+    /// Returns a nested breakdown of the cost of the constraint system by scope.
+    pub fn to_cost_report(&self) -> CostReport {
+        let scopes = self.scopes.iter().chain(self.counter.scopes()).collect::<BTreeSet<_>>();
+        CostReport::new(scopes.into_iter().map(|scope| {
+            (scope, Cost {
+                constants: self.num_constants_in_scope(scope),
+                public: self.num_public_in_scope(scope),
+                private: self.num_private_in_scope(scope),
+                constraints: self.num_constraints_in_scope(scope),
+            })
+        }))
+    }
+
     pub(super) fn to_public_variables(&self) -> &Vec<Variable<F>> {
         &self.public
     }
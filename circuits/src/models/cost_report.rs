@@ -0,0 +1,286 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::models::*;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{fmt, ops::AddAssign};
+
+/// The number of constants, public variables, private variables, and constraints in a circuit.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cost {
+    pub constants: usize,
+    pub public: usize,
+    pub private: usize,
+    pub constraints: usize,
+}
+
+impl AddAssign for Cost {
+    fn add_assign(&mut self, other: Self) {
+        self.constants += other.constants;
+        self.public += other.public;
+        self.private += other.private;
+        self.constraints += other.constraints;
+    }
+}
+
+///
+/// The cost of a scope, where `cost` counts what was allocated directly in the scope,
+/// and `total` also counts what was allocated in its nested scopes.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopeCost {
+    pub name: String,
+    pub cost: Cost,
+    pub total: Cost,
+    pub children: Vec<ScopeCost>,
+}
+
+impl ScopeCost {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            cost: Default::default(),
+            total: Default::default(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns the nested scope at the given path, relative to this scope, as in `a/b`.
+    pub fn find(&self, path: &str) -> Option<&ScopeCost> {
+        path.split('/').try_fold(self, |scope, name| {
+            scope.children.iter().find(|child| child.name == name)
+        })
+    }
+}
+
+///
+/// A nested breakdown of the cost of a circuit by scope, where the children of each scope
+/// are sorted by name, so that reports of two versions of a circuit may be compared line by line.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CostReport {
+    pub total: Cost,
+    pub scopes: Vec<ScopeCost>,
+}
+
+impl CostReport {
+    ///
+    /// Initializes a report from the cost of each scope, where each scope is a path of names separated by `/`.
+    ///
+    pub(super) fn new<'a, I: IntoIterator<Item = (&'a Scope, Cost)>>(costs: I) -> Self {
+        let mut total = Cost::default();
+        let mut scopes: Vec<ScopeCost> = Vec::new();
+
+        for (scope, cost) in costs {
+            total += cost;
+
+            let mut siblings = &mut scopes;
+            let mut path = scope.split('/').peekable();
+            while let Some(name) = path.next() {
+                let index = match siblings.binary_search_by(|sibling| sibling.name.as_str().cmp(name)) {
+                    Ok(index) => index,
+                    Err(index) => {
+                        siblings.insert(index, ScopeCost::new(name));
+                        index
+                    }
+                };
+
+                let node = &mut siblings[index];
+                node.total += cost;
+                if path.peek().is_none() {
+                    node.cost += cost;
+                }
+                siblings = &mut node.children;
+            }
+        }
+
+        Self { total, scopes }
+    }
+
+    /// Returns the scope at the given path, as in `Circuit::new/a/b`.
+    pub fn find(&self, path: &str) -> Option<&ScopeCost> {
+        let (name, rest) = match path.find('/') {
+            Some(index) => (&path[..index], Some(&path[index + 1..])),
+            None => (path, None),
+        };
+
+        let scope = self.scopes.iter().find(|scope| scope.name == name)?;
+        match rest {
+            Some(rest) => scope.find(rest),
+            None => Some(scope),
+        }
+    }
+
+    /// Returns the report in JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Returns a report from its JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+impl fmt::Display for CostReport {
+    ///
+    /// Prints a table of the total cost of each scope, where nested scopes are indented under their parent.
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn rows<'a>(scopes: &'a [ScopeCost], depth: usize, table: &mut Vec<(String, &'a Cost)>) {
+            for scope in scopes {
+                table.push((format!("{}{}", "  ".repeat(depth), scope.name), &scope.total));
+                rows(&scope.children, depth + 1, table);
+            }
+        }
+
+        let mut table = vec![];
+        rows(&self.scopes, 0, &mut table);
+        table.push(("Total".to_string(), &self.total));
+
+        let width = table
+            .iter()
+            .map(|(name, _)| name.len())
+            .chain(Some("Scope".len()))
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:<width$}  {:>10}  {:>10}  {:>10}  {:>11}",
+            "Scope",
+            "Constants",
+            "Public",
+            "Private",
+            "Constraints",
+            width = width
+        )?;
+        for (name, cost) in table {
+            writeln!(
+                f,
+                "{:<width$}  {:>10}  {:>10}  {:>10}  {:>11}",
+                name,
+                cost.constants,
+                cost.public,
+                cost.private,
+                cost.constraints,
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{traits::*, BaseField, Boolean, Circuit, CircuitInstance, Environment, Mode};
+    use snarkvm_fields::One as _;
+
+    type E = Circuit;
+
+    fn example() -> CircuitInstance<E> {
+        let instance = CircuitInstance::<E>::new();
+        instance.enter(|| {
+            let a = Boolean::<E>::new(Mode::Private, true);
+            E::scoped("xor", |_| {
+                let b = Boolean::<E>::new(Mode::Public, false);
+                let _ = a.xor(&b);
+            });
+            E::scoped("field", |_| {
+                let one = <E as Environment>::BaseField::one();
+                let x = BaseField::<E>::new(Mode::Private, one);
+                E::scoped("square", |_| {
+                    let _ = x.square();
+                });
+                E::scoped("add", |_| {
+                    let _ = &x + BaseField::new(Mode::Constant, one);
+                });
+            });
+        });
+        instance
+    }
+
+    #[test]
+    fn test_cost_report() {
+        let instance = example();
+        let report = instance.to_cost_report();
+
+        assert_eq!(instance.num_constants(), report.total.constants);
+        assert_eq!(instance.num_private(), report.total.private);
+        assert_eq!(instance.num_constraints(), report.total.constraints);
+        // The constant one is a public variable outside of any scope.
+        assert_eq!(instance.num_public(), report.total.public + 1);
+
+        assert_eq!(1, report.scopes.len());
+        let root = &report.scopes[0];
+        assert_eq!("Circuit::new", root.name);
+        assert_eq!(report.total, root.total);
+
+        // The children of a scope are sorted by name.
+        let names = root
+            .children
+            .iter()
+            .map(|child| child.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["field", "xor"], names);
+
+        let field = report.find("Circuit::new/field").unwrap();
+        assert_eq!(1, field.cost.private);
+        assert_eq!(0, field.cost.constraints);
+        assert_eq!(2, field.total.private);
+        assert_eq!(1, field.total.constraints);
+
+        let square = field.find("square").unwrap();
+        assert_eq!(square, report.find("Circuit::new/field/square").unwrap());
+        assert_eq!((1, 1), (square.total.private, square.total.constraints));
+
+        // Scopes are listed even when nothing was allocated in them.
+        let add = report.find("Circuit::new/field/add").unwrap();
+        assert_eq!(0, add.total.private);
+        assert_eq!(0, add.total.constraints);
+        assert!(add.children.is_empty());
+
+        let xor = report.find("Circuit::new/xor").unwrap();
+        assert_eq!((1, 1, 3), (xor.total.public, xor.total.private, xor.total.constraints));
+
+        assert!(report.find("Circuit::new/missing").is_none());
+        assert!(report.find("field").is_none());
+    }
+
+    #[test]
+    fn test_display() {
+        let report = example().to_cost_report();
+        let expected = r"Scope          Constants      Public     Private  Constraints
+Circuit::new           1           1           4            5
+  field                1           0           2            1
+    add                1           0           0            0
+    square             0           0           1            1
+  xor                  0           1           1            3
+Total                  1           1           4            5
+";
+        assert_eq!(expected, report.to_string());
+    }
+
+    #[test]
+    fn test_json() {
+        let report = example().to_cost_report();
+        let json = report.to_json().unwrap();
+        assert_eq!(report, crate::CostReport::from_json(&json).unwrap());
+        assert!(crate::CostReport::from_json("{}").is_err());
+    }
+}
@@ -18,7 +18,9 @@ use crate::{boolean::Boolean, models::*};
 use snarkvm_curves::{AffineCurve, TwistedEdwardsParameters};
 use snarkvm_fields::traits::*;
 
-#[derive(Copy, Clone, Debug)]
+use std::{fmt, str::FromStr};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Mode {
     Constant,
     Public,
@@ -31,6 +33,29 @@ impl Mode {
     }
 }
 
+impl FromStr for Mode {
+    type Err = anyhow::Error;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode {
+            "constant" => Ok(Self::Constant),
+            "public" => Ok(Self::Public),
+            "private" => Ok(Self::Private),
+            _ => Err(anyhow::anyhow!("Unknown mode '{}'", mode)),
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Constant => write!(f, "constant"),
+            Self::Public => write!(f, "public"),
+            Self::Private => write!(f, "private"),
+        }
+    }
+}
+
 pub trait Environment: Clone {
     type Affine: AffineCurve<BaseField = Self::BaseField>;
     type AffineParameters: TwistedEdwardsParameters<BaseField = Self::BaseField>;
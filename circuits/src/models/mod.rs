@@ -25,8 +25,11 @@ pub use circuit_scope::*;
 
 mod constraint_converter;
 
-mod constraint_system;
-use constraint_system::*;
+pub mod constraint_system;
+pub use constraint_system::*;
+
+pub mod cost_report;
+pub use cost_report::*;
 
 pub mod environment;
 pub use environment::*;
@@ -34,5 +37,8 @@ pub use environment::*;
 pub mod linear_combination;
 pub use linear_combination::*;
 
+pub mod native;
+pub use native::*;
+
 pub mod variable;
 pub use variable::*;
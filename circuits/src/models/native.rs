@@ -0,0 +1,552 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::models::*;
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+thread_local! {
+    /// The number of variables allocated natively on the current thread, which is used to index them.
+    static NATIVE_VARIABLES: Cell<Index> = Cell::new(0);
+
+    /// Whether every constraint enforced natively on the current thread has held.
+    static NATIVE_IS_SATISFIED: Cell<bool> = Cell::new(true);
+
+    /// The scope of the native environment on the current thread, whose constraint system remains empty.
+    static NATIVE_SCOPE: CircuitScope<<Circuit as Environment>::BaseField> =
+        CircuitScope::new(Rc::new(RefCell::new(ConstraintSystem::new())), "Native".to_string(), None);
+}
+
+///
+/// An environment over the same curve and fields as `Circuit`, which only computes witness values.
+///
+/// Variables keep their mode and value, but are not recorded, and constraints are checked on their values
+/// rather than recorded, so `eject_value` produces the same results as in `Circuit`, without any constraint
+/// bookkeeping. Where `Circuit` would be left unsatisfied, such as on an integer overflow, `Native` halts.
+/// A `programs::Function<Native>` may be evaluated to compute its outputs without synthesizing it.
+///
+#[derive(Clone)]
+pub struct Native;
+
+impl Native {
+    /// Returns the index of a new variable.
+    fn next_index() -> Index {
+        NATIVE_VARIABLES.with(|counter| {
+            let index = counter.get();
+            counter.set(index.wrapping_add(1));
+            index
+        })
+    }
+
+    /// Clears any violated constraint on the current thread, so that the environment is satisfied again.
+    pub fn reset() {
+        NATIVE_IS_SATISFIED.with(|is_satisfied| is_satisfied.set(true));
+    }
+}
+
+impl Environment for Native {
+    type Affine = <Circuit as Environment>::Affine;
+    type AffineParameters = <Circuit as Environment>::AffineParameters;
+    type BaseField = <Circuit as Environment>::BaseField;
+    type ScalarField = <Circuit as Environment>::ScalarField;
+
+    /// Returns the `zero` constant.
+    fn zero() -> LinearCombination<Self::BaseField> {
+        LinearCombination::zero()
+    }
+
+    /// Returns the `one` constant.
+    fn one() -> LinearCombination<Self::BaseField> {
+        LinearCombination::one()
+    }
+
+    fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
+        match mode {
+            Mode::Constant => Variable::Constant(value),
+            Mode::Public => Variable::Public(Self::next_index(), value),
+            Mode::Private => Variable::Private(Self::next_index(), value),
+        }
+    }
+
+    fn scope(_name: &str) -> CircuitScope<Self::BaseField> {
+        NATIVE_SCOPE.with(|scope| scope.clone())
+    }
+
+    fn scoped<Fn>(name: &str, logic: Fn)
+    where
+        Fn: FnOnce(CircuitScope<Self::BaseField>),
+    {
+        logic(Self::scope(name))
+    }
+
+    /// Halts if the constraint `(A * B) == C` does not hold on the values of its variables.
+    fn enforce<Fn, A, B, C>(constraint: Fn)
+    where
+        Fn: FnOnce() -> (A, B, C),
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>,
+    {
+        let (a, b, c) = constraint();
+        let (a, b, c) = (a.into(), b.into(), c.into());
+
+        if a.to_value() * b.to_value() != c.to_value() {
+            NATIVE_IS_SATISFIED.with(|is_satisfied| is_satisfied.set(false));
+            Self::halt("Failed to satisfy a constraint in the native environment")
+        }
+    }
+
+    /// Returns `false` if a constraint was violated on the current thread, since the last `Native::reset`.
+    fn is_satisfied() -> bool {
+        NATIVE_IS_SATISFIED.with(Cell::get)
+    }
+
+    fn num_constants() -> usize {
+        0
+    }
+
+    fn num_public() -> usize {
+        0
+    }
+
+    fn num_private() -> usize {
+        0
+    }
+
+    fn num_constraints() -> usize {
+        0
+    }
+
+    fn num_constants_in_scope(_scope: &Scope) -> usize {
+        0
+    }
+
+    fn num_public_in_scope(_scope: &Scope) -> usize {
+        0
+    }
+
+    fn num_private_in_scope(_scope: &Scope) -> usize {
+        0
+    }
+
+    fn num_constraints_in_scope(_scope: &Scope) -> usize {
+        0
+    }
+
+    fn affine_from_x_coordinate(x: Self::BaseField) -> Self::Affine {
+        Circuit::affine_from_x_coordinate(x)
+    }
+
+    fn halt<S: Into<String>, T>(message: S) -> T {
+        Circuit::halt(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        traits::*,
+        Affine,
+        BaseField,
+        Boolean,
+        CircuitInstance,
+        Function,
+        Integer,
+        IntegerType,
+        Literal,
+        ScalarField,
+        I8,
+        U8,
+    };
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_fields::One as _;
+    use snarkvm_utilities::UniformRand;
+
+    use num_traits::Inv;
+    use rand::thread_rng;
+    use std::{
+        fmt::Debug,
+        panic::{self, AssertUnwindSafe},
+    };
+
+    const ITERATIONS: usize = 10;
+
+    const MODES: [Mode; 3] = [Mode::Constant, Mode::Public, Mode::Private];
+
+    ///
+    /// Runs the given logic in a new circuit and natively, checks that both produce the same output,
+    /// and that the circuit is satisfied.
+    ///
+    fn check_modes<T: PartialEq + Debug>(in_circuit: impl FnOnce() -> T, natively: impl FnOnce() -> T) {
+        let instance = CircuitInstance::<Circuit>::new();
+        let expected = instance.enter(in_circuit);
+        assert!(instance.is_satisfied());
+
+        let candidate = natively();
+        assert_eq!(expected, candidate);
+    }
+
+    ///
+    /// Runs the given logic in a new circuit and natively, and checks that the circuit halts
+    /// or is left unsatisfied, and that the native environment halts.
+    ///
+    fn check_halts(in_circuit: impl FnOnce(), natively: impl FnOnce()) {
+        let instance = CircuitInstance::<Circuit>::new();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| instance.enter(in_circuit)));
+        assert!(result.is_err() || !instance.is_satisfied());
+
+        let result = panic::catch_unwind(AssertUnwindSafe(natively));
+        assert!(result.is_err());
+        Native::reset();
+    }
+
+    fn boolean_operations<E: Environment>(modes: (Mode, Mode, Mode), values: (bool, bool, bool)) -> Vec<bool> {
+        let a = Boolean::<E>::new(modes.0, values.0);
+        let b = Boolean::<E>::new(modes.1, values.1);
+        let condition = Boolean::<E>::new(modes.2, values.2);
+
+        vec![
+            a.and(&b).eject_value(),
+            a.or(&b).eject_value(),
+            a.nand(&b).eject_value(),
+            a.nor(&b).eject_value(),
+            a.xor(&b).eject_value(),
+            (!&a).eject_value(),
+            a.is_eq(&b).eject_value(),
+            Boolean::ternary(&condition, &a, &b).eject_value(),
+        ]
+    }
+
+    fn base_field_operations<E: Environment>(
+        modes: (Mode, Mode),
+        values: (E::BaseField, E::BaseField),
+    ) -> Vec<E::BaseField> {
+        let a = BaseField::<E>::new(modes.0, values.0);
+        let b = BaseField::<E>::new(modes.1, values.1);
+
+        vec![
+            (&a + &b).eject_value(),
+            (&a - &b).eject_value(),
+            (&a * &b).eject_value(),
+            (a.clone() / &b).eject_value(),
+            (-&a).eject_value(),
+            (&a).double().eject_value(),
+            a.square().eject_value(),
+            (&a).inv().eject_value(),
+            BaseField::ternary(&a.is_eq(&b), &a, &b).eject_value(),
+        ]
+    }
+
+    fn group_operations<E: Environment>(
+        modes: (Mode, Mode, Mode),
+        values: (E::Affine, E::Affine, E::ScalarField),
+    ) -> Vec<E::Affine> {
+        let new_affine =
+            |mode, value: E::Affine| Affine::<E>::new(mode, value.to_x_coordinate(), Some(value.to_y_coordinate()));
+        let a = new_affine(modes.0, values.0);
+        let b = new_affine(modes.1, values.1);
+        let scalar = ScalarField::<E>::new(modes.2, values.2);
+
+        vec![
+            (&a + &b).eject_value(),
+            (&a - &b).eject_value(),
+            (-&a).eject_value(),
+            (&a).double().eject_value(),
+            (&a * &scalar).eject_value(),
+            Affine::ternary(&a.is_eq(&b), &a, &b).eject_value(),
+        ]
+    }
+
+    fn scalar_field_operations<E: Environment>(
+        modes: (Mode, Mode),
+        values: (E::ScalarField, E::ScalarField),
+    ) -> Vec<E::ScalarField> {
+        let a = ScalarField::<E>::new(modes.0, values.0);
+        let b = ScalarField::<E>::new(modes.1, values.1);
+
+        vec![
+            (&a + &b).eject_value(),
+            (&a - &b).eject_value(),
+            (&a * &b).eject_value(),
+            (&a / &b).eject_value(),
+            (-&a).eject_value(),
+            (&a).double().eject_value(),
+            a.square().eject_value(),
+            (&a).inv().eject_value(),
+        ]
+    }
+
+    fn integer_operations<E: Environment, I: IntegerType>(modes: (Mode, Mode), values: (I, I)) -> Vec<I> {
+        let a = Integer::<E, I>::new(modes.0, values.0);
+        let b = Integer::<E, I>::new(modes.1, values.1);
+
+        vec![
+            a.add_wrapped(&b).eject_value(),
+            a.sub_wrapped(&b).eject_value(),
+            a.mul_wrapped(&b).eject_value(),
+            a.div_wrapped(&b).eject_value(),
+        ]
+    }
+
+    #[test]
+    fn test_boolean() {
+        for first in &MODES {
+            for second in &MODES {
+                for third in &MODES {
+                    for value in 0..8u8 {
+                        let modes = (*first, *second, *third);
+                        let values = (value & 1 == 1, value & 2 == 2, value & 4 == 4);
+                        check_modes(
+                            || boolean_operations::<Circuit>(modes, values),
+                            || boolean_operations::<Native>(modes, values),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_base_field() {
+        for first in &MODES {
+            for second in &MODES {
+                for _ in 0..ITERATIONS {
+                    let modes = (*first, *second);
+                    let values = (
+                        UniformRand::rand(&mut thread_rng()),
+                        UniformRand::rand(&mut thread_rng()),
+                    );
+                    check_modes(
+                        || base_field_operations::<Circuit>(modes, values),
+                        || base_field_operations::<Native>(modes, values),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_field() {
+        let one = <Circuit as Environment>::ScalarField::one();
+
+        for first in &MODES {
+            for second in &MODES {
+                let modes = (*first, *second);
+
+                // Include the largest scalar field elements, whose sums and products exceed the modulus.
+                let mut values = vec![(-one, -one), (-one, one), (one, -one)];
+                for _ in 0..ITERATIONS {
+                    values.push((
+                        UniformRand::rand(&mut thread_rng()),
+                        UniformRand::rand(&mut thread_rng()),
+                    ));
+                }
+
+                for values in values {
+                    check_modes(
+                        || scalar_field_operations::<Circuit>(modes, values),
+                        || scalar_field_operations::<Native>(modes, values),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_integers() {
+        for first in &MODES {
+            for second in &MODES {
+                let modes = (*first, *second);
+
+                // Include values that wrap around, which must not halt natively.
+                for values in [(100u8, 7u8), (u8::MAX, u8::MAX), (u8::MAX, 1)] {
+                    check_modes(
+                        || integer_operations::<Circuit, u8>(modes, values),
+                        || integer_operations::<Native, u8>(modes, values),
+                    );
+                }
+                for values in [(-100i8, 7i8), (i8::MIN, -1), (i8::MAX, i8::MIN)] {
+                    check_modes(
+                        || integer_operations::<Circuit, i8>(modes, values),
+                        || integer_operations::<Native, i8>(modes, values),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        for first in &MODES {
+            for second in &MODES {
+                let (first, second) = (*first, *second);
+
+                check_halts(
+                    || drop(U8::<Circuit>::new(first, u8::MAX).add_checked(&U8::new(second, 1))),
+                    || drop(U8::<Native>::new(first, u8::MAX).add_checked(&U8::new(second, 1))),
+                );
+                check_halts(
+                    || drop(I8::<Circuit>::new(first, i8::MAX).add_checked(&I8::new(second, 1))),
+                    || drop(I8::<Native>::new(first, i8::MAX).add_checked(&I8::new(second, 1))),
+                );
+                check_halts(
+                    || drop(I8::<Circuit>::new(first, i8::MIN).div_checked(&I8::new(second, -1))),
+                    || drop(I8::<Native>::new(first, i8::MIN).div_checked(&I8::new(second, -1))),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_integer_division_by_zero() {
+        for first in &MODES {
+            for second in &MODES {
+                let (first, second) = (*first, *second);
+
+                check_halts(
+                    || drop(U8::<Circuit>::new(first, 5).div_checked(&U8::new(second, 0))),
+                    || drop(U8::<Native>::new(first, 5).div_checked(&U8::new(second, 0))),
+                );
+                check_halts(
+                    || drop(U8::<Circuit>::new(first, 5).div_wrapped(&U8::new(second, 0))),
+                    || drop(U8::<Native>::new(first, 5).div_wrapped(&U8::new(second, 0))),
+                );
+                check_halts(
+                    || drop(I8::<Circuit>::new(first, -5).div_checked(&I8::new(second, 0))),
+                    || drop(I8::<Native>::new(first, -5).div_checked(&I8::new(second, 0))),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_satisfied() {
+        assert!(Native::is_satisfied());
+
+        let a = U8::<Native>::new(Mode::Private, u8::MAX);
+        let b = U8::<Native>::new(Mode::Private, 1);
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| a.add_checked(&b))).is_err());
+        assert!(!Native::is_satisfied());
+
+        Native::reset();
+        assert!(Native::is_satisfied());
+    }
+
+    #[test]
+    fn test_group() {
+        for first in &MODES {
+            for second in &MODES {
+                for third in &MODES {
+                    let modes = (*first, *second, *third);
+                    let values = (
+                        UniformRand::rand(&mut thread_rng()),
+                        UniformRand::rand(&mut thread_rng()),
+                        UniformRand::rand(&mut thread_rng()),
+                    );
+                    check_modes(
+                        || group_operations::<Circuit>(modes, values),
+                        || group_operations::<Native>(modes, values),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_function() {
+        fn outputs<E: Environment>(text: &str, inputs: &[String]) -> Vec<String> {
+            let function = text.parse::<Function<E>>().unwrap();
+            let inputs = inputs
+                .iter()
+                .map(|input| input.parse().unwrap())
+                .collect::<Vec<Literal<E>>>();
+            function
+                .evaluate(&inputs)
+                .unwrap()
+                .iter()
+                .map(|output| output.to_literal().to_string())
+                .collect()
+        }
+
+        for mode in &MODES {
+            let text = format!(
+                r"function everything:
+    input r0 as boolean.{mode};
+    input r1 as field.{mode};
+    input r2 as scalar.{mode};
+    input r3 as group.{mode};
+    sub r1 7field into r4;
+    mul r1 r4 into r5;
+    div r5 3field into r6;
+    neg r6 into r7;
+    double r7 into r8;
+    square r8 into r9;
+    inv r9 into r10;
+    is_eq r1 r10 into r11;
+    ternary r0 r1 r10 into r12;
+    or r11 r0 into r13;
+    xor r13 true into r14;
+    mul r3 r2 into r15;
+    add r15 r3 into r16;
+    output r12 as field;
+    output r14 as boolean;
+    output r16 as group;
+",
+                mode = mode
+            );
+
+            for _ in 0..ITERATIONS {
+                let inputs = [
+                    Literal::<Circuit>::Boolean(UniformRand::rand(&mut thread_rng())),
+                    Literal::BaseField(UniformRand::rand(&mut thread_rng())),
+                    Literal::ScalarField(UniformRand::rand(&mut thread_rng())),
+                    Literal::Group(UniformRand::rand(&mut thread_rng())),
+                ]
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+
+                check_modes(
+                    || outputs::<Circuit>(&text, &inputs),
+                    || outputs::<Native>(&text, &inputs),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_no_bookkeeping() {
+        let instance = CircuitInstance::<Circuit>::new();
+        instance.enter(|| {
+            let a = BaseField::<Native>::new(Mode::Private, UniformRand::rand(&mut thread_rng()));
+            let b = BaseField::<Native>::new(Mode::Public, UniformRand::rand(&mut thread_rng()));
+            Native::scoped("mul", |scope| {
+                let _ = &a * &b;
+                assert_eq!(0, scope.num_private_in_scope());
+                assert_eq!(0, scope.num_constraints_in_scope());
+            });
+            assert_eq!(0, Native::num_constraints());
+        });
+
+        // Ensure the active circuit is left untouched.
+        assert_eq!(0, instance.num_private());
+        assert_eq!(0, instance.num_constraints());
+    }
+}
@@ -0,0 +1,438 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::AffineCurve;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::{
+    convert::TryFrom,
+    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+};
+
+/// The version of the bytecode encoding of functions.
+const BYTECODE_VERSION: u8 = 0;
+
+/// The operand tag of a register.
+const OPERAND_REGISTER: u8 = 0;
+/// The operand tag of a literal.
+const OPERAND_LITERAL: u8 = 1;
+
+/// Returns an error for malformed bytecode.
+fn invalid_data<S: ToString>(message: S) -> Error {
+    Error::new(ErrorKind::InvalidData, message.to_string())
+}
+
+/// Returns the given length as a `u16`, or an error if it does not fit.
+fn to_u16(length: usize, items: &str) -> IoResult<u16> {
+    u16::try_from(length).map_err(|_| invalid_data(format!("Found {} {}, which exceeds {}", length, items, u16::MAX)))
+}
+
+impl ToBytes for Type {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        match self {
+            Self::Boolean => 0u8,
+            Self::BaseField => 1u8,
+            Self::Group => 2u8,
+            Self::ScalarField => 3u8,
+        }
+        .write_le(writer)
+    }
+}
+
+impl FromBytes for Type {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        match u8::read_le(reader)? {
+            0 => Ok(Self::Boolean),
+            1 => Ok(Self::BaseField),
+            2 => Ok(Self::Group),
+            3 => Ok(Self::ScalarField),
+            type_ => Err(invalid_data(format!("Unknown type {}", type_))),
+        }
+    }
+}
+
+impl ToBytes for Mode {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant => 0u8,
+            Self::Public => 1u8,
+            Self::Private => 2u8,
+        }
+        .write_le(writer)
+    }
+}
+
+impl FromBytes for Mode {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        match u8::read_le(reader)? {
+            0 => Ok(Self::Constant),
+            1 => Ok(Self::Public),
+            2 => Ok(Self::Private),
+            mode => Err(invalid_data(format!("Unknown mode {}", mode))),
+        }
+    }
+}
+
+impl<E: Environment> ToBytes for Literal<E> {
+    /// Writes the type of the literal, followed by its value, where a group element is written as its x-coordinate.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.to_type().write_le(&mut writer)?;
+        match self {
+            Self::Boolean(value) => value.write_le(&mut writer),
+            Self::BaseField(value) => value.write_le(&mut writer),
+            Self::Group(value) => value.to_x_coordinate().write_le(&mut writer),
+            Self::ScalarField(value) => value.write_le(&mut writer),
+        }
+    }
+}
+
+impl<E: Environment> FromBytes for Literal<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match Type::read_le(&mut reader)? {
+            Type::Boolean => Ok(Self::Boolean(FromBytes::read_le(&mut reader)?)),
+            Type::BaseField => Ok(Self::BaseField(FromBytes::read_le(&mut reader)?)),
+            Type::Group => {
+                let x_coordinate = FromBytes::read_le(&mut reader)?;
+                Ok(Self::Group(
+                    Self::group_from_x_coordinate(x_coordinate).map_err(invalid_data)?,
+                ))
+            }
+            Type::ScalarField => Ok(Self::ScalarField(FromBytes::read_le(&mut reader)?)),
+        }
+    }
+}
+
+impl<E: Environment> ToBytes for Function<E> {
+    ///
+    /// Writes the bytecode of the function, which consists of the version, the name, the number of registers,
+    /// the type and mode of each input, the opcode, operands, and destination register of each instruction,
+    /// and the output registers.
+    ///
+    /// Registers are numbered in the order they are defined, starting with the inputs.
+    /// Operands that are circuit values, rather than literals or registers, are written as constants.
+    ///
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let indices = self.register_indices();
+        let index = |register: &Register<E>| {
+            indices
+                .get(&(register.0).0)
+                .copied()
+                .ok_or_else(|| invalid_data("Register is not defined"))
+        };
+        let type_ = |register: &Register<E>| register.to_type().map_err(invalid_data);
+
+        BYTECODE_VERSION.write_le(&mut writer)?;
+
+        to_u16(self.name.len(), "bytes in the function name")?.write_le(&mut writer)?;
+        writer.write_all(self.name.as_bytes())?;
+
+        (indices.len() as u32).write_le(&mut writer)?;
+
+        to_u16(self.inputs.len(), "inputs")?.write_le(&mut writer)?;
+        for (register, mode) in &self.inputs {
+            type_(register)?.write_le(&mut writer)?;
+            mode.write_le(&mut writer)?;
+        }
+
+        (self.instructions.len() as u32).write_le(&mut writer)?;
+        for instruction in &self.instructions {
+            instruction.opcode().write_le(&mut writer)?;
+            for operand in instruction.operands() {
+                match operand {
+                    Value::Register(register) => {
+                        OPERAND_REGISTER.write_le(&mut writer)?;
+                        index(register)?.write_le(&mut writer)?;
+                    }
+                    value => {
+                        OPERAND_LITERAL.write_le(&mut writer)?;
+                        value.to_literal().write_le(&mut writer)?;
+                    }
+                }
+            }
+            index(instruction.destination())?.write_le(&mut writer)?;
+        }
+
+        to_u16(self.outputs.len(), "outputs")?.write_le(&mut writer)?;
+        for register in &self.outputs {
+            index(register)?.write_le(&mut writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: Environment> FromBytes for Function<E> {
+    ///
+    /// Reads the bytecode of a function, and rejects the bytecode if it is malformed,
+    /// including any register that is referenced before it is defined, or is defined out of order.
+    ///
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let version = u8::read_le(&mut reader)?;
+        if version != BYTECODE_VERSION {
+            return Err(invalid_data(format!("Unsupported bytecode version {}", version)));
+        }
+
+        let name_length = u16::read_le(&mut reader)?;
+        let mut name = vec![0u8; name_length as usize];
+        reader.read_exact(&mut name)?;
+        let name = String::from_utf8(name).map_err(|_| invalid_data("Function name is not valid UTF-8"))?;
+        if !is_identifier(&name) {
+            return Err(invalid_data(format!("Invalid function name '{}'", name)));
+        }
+
+        let mut function = Function::new(&name);
+        let num_registers = u32::read_le(&mut reader)? as usize;
+        let mut registers: Vec<Register<E>> = Vec::new();
+
+        // Ensures the next register to be defined is within the number of registers.
+        let check_definition = |registers: &Vec<Register<E>>| match registers.len() < num_registers {
+            true => Ok(()),
+            false => Err(invalid_data(format!(
+                "Expected {} registers, found more",
+                num_registers
+            ))),
+        };
+
+        // Returns the register with the given index, if it is already defined.
+        let register = |registers: &Vec<Register<E>>, index: u32| match registers.get(index as usize) {
+            Some(register) => Ok(register.clone()),
+            None => Err(invalid_data(format!("Register {} is not defined", index))),
+        };
+
+        let num_inputs = u16::read_le(&mut reader)?;
+        for _ in 0..num_inputs {
+            check_definition(&registers)?;
+            let type_ = Type::read_le(&mut reader)?;
+            let mode = Mode::read_le(&mut reader)?;
+            registers.push(function.new_input(type_, mode).map_err(invalid_data)?);
+        }
+
+        let num_instructions = u32::read_le(&mut reader)?;
+        for _ in 0..num_instructions {
+            check_definition(&registers)?;
+
+            let opcode = u16::read_le(&mut reader)?;
+            let num_operands = Instruction::<E>::num_operands(opcode)
+                .ok_or_else(|| invalid_data(format!("Unknown opcode {}", opcode)))?;
+
+            let mut operands = Vec::with_capacity(num_operands);
+            for _ in 0..num_operands {
+                match u8::read_le(&mut reader)? {
+                    OPERAND_REGISTER => {
+                        let index = u32::read_le(&mut reader)?;
+                        operands.push(Value::Register(register(&registers, index)?));
+                    }
+                    OPERAND_LITERAL => operands.push(Value::Literal(Literal::read_le(&mut reader)?)),
+                    tag => return Err(invalid_data(format!("Unknown operand tag {}", tag))),
+                }
+            }
+
+            let destination = u32::read_le(&mut reader)?;
+            if destination as usize != registers.len() {
+                let message = format!("Expected register {}, found register {}", registers.len(), destination);
+                return Err(invalid_data(message));
+            }
+
+            let destination = function.new_register();
+            let instruction = Instruction::from_opcode(opcode, operands, destination.clone()).map_err(invalid_data)?;
+            function.push_instruction(instruction).map_err(invalid_data)?;
+            registers.push(destination);
+        }
+
+        if registers.len() != num_registers {
+            return Err(invalid_data(format!(
+                "Expected {} registers, found {}",
+                num_registers,
+                registers.len()
+            )));
+        }
+
+        let num_outputs = u16::read_le(&mut reader)?;
+        for _ in 0..num_outputs {
+            let output = register(&registers, u32::read_le(&mut reader)?)?;
+            function.add_output(&output).map_err(invalid_data)?;
+        }
+
+        Ok(function)
+    }
+
+    /// Returns the function from its bytecode, and rejects any bytes after the bytecode.
+    fn from_bytes_le(mut bytes: &[u8]) -> anyhow::Result<Self> {
+        let function = Self::read_le(&mut bytes)?;
+        match bytes.is_empty() {
+            true => Ok(function),
+            false => Err(anyhow!(
+                "Found {} bytes after the bytecode of function '{}'",
+                bytes.len(),
+                function.name
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    type E = Circuit;
+
+    const FUNCTION: &str = r"function everything:
+    input r0 as boolean.constant;
+    input r1 as field.private;
+    input r2 as scalar.public;
+    input r3 as group.private;
+    store 7field into r4;
+    div r1 r4 into r5;
+    ternary r0 r1 r5 into r6;
+    is_eq r6 r1 into r7;
+    or r7 true into r8;
+    mul r3 r2 into r9;
+    mul 2scalar r9 into r10;
+    add r10 0group into r11;
+    output r8 as boolean;
+    output r11 as group;
+";
+
+    /// Returns the bytecode of a function with the given body.
+    fn to_bytecode(body: &str) -> Vec<u8> {
+        format!("function test:\n{}", body)
+            .parse::<Function<E>>()
+            .unwrap()
+            .to_bytes_le()
+            .unwrap()
+    }
+
+    /// Returns the bytecode of a function with one boolean input, which is negated into the output.
+    fn not_bytecode() -> Vec<u8> {
+        to_bytecode("    input r0 as boolean.private;\n    not r0 into r1;\n    output r1 as boolean;\n")
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let function: Function<E> = FUNCTION.parse().unwrap();
+        let bytes = function.to_bytes_le().unwrap();
+
+        let candidate = Function::<E>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(FUNCTION, candidate.to_string());
+        assert_eq!(bytes, candidate.to_bytes_le().unwrap());
+    }
+
+    #[test]
+    fn test_bytecode() {
+        let expected = [
+            vec![BYTECODE_VERSION],
+            vec![4, 0],
+            b"test".to_vec(),
+            vec![2, 0, 0, 0],
+            // The inputs.
+            vec![1, 0, 0, 2],
+            // The instructions.
+            vec![1, 0, 0, 0, 17, 0, OPERAND_REGISTER, 0, 0, 0, 0, 1, 0, 0, 0],
+            // The outputs.
+            vec![1, 0, 1, 0, 0, 0],
+        ]
+        .concat();
+        assert_eq!(expected, not_bytecode());
+    }
+
+    #[test]
+    fn test_malformed() {
+        let bytes = not_bytecode();
+        assert!(Function::<E>::from_bytes_le(&bytes).is_ok());
+
+        let check = |offset: usize, byte: u8| {
+            let mut bytes = bytes.clone();
+            bytes[offset] = byte;
+            let candidate = Function::<E>::from_bytes_le(&bytes);
+            assert!(
+                candidate.is_err(),
+                "Expected byte {} at {} to be rejected",
+                byte,
+                offset
+            );
+        };
+
+        // An unsupported version.
+        check(0, 1);
+        // An invalid function name.
+        check(3, b'-');
+        // Too few and too many registers.
+        check(7, 1);
+        check(7, 3);
+        // An unknown type and mode.
+        check(13, 4);
+        check(14, 3);
+        // An unknown opcode, and an instruction that is not type checked.
+        check(19, 18);
+        check(19, 1);
+        // An unknown operand tag.
+        check(21, 2);
+        // A register that is referenced before it is defined.
+        check(22, 1);
+        // A register that is defined out of order.
+        check(26, 2);
+        check(26, 0);
+        // An output register that is not defined.
+        check(32, 2);
+
+        // A truncated bytecode.
+        assert!(Function::<E>::from_bytes_le(&bytes[..bytes.len() - 1]).is_err());
+
+        // A bytecode with trailing bytes.
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(Function::<E>::from_bytes_le(&trailing).is_err());
+    }
+
+    #[test]
+    fn test_malformed_literals() {
+        // An invalid boolean.
+        let mut bytes = to_bytecode("    store true into r0;\n");
+        assert!(Function::<E>::from_bytes_le(&bytes).is_ok());
+        let offset = bytes.len() - 7;
+        assert_eq!(1, bytes[offset]);
+        bytes[offset] = 2;
+        assert!(Function::<E>::from_bytes_le(&bytes).is_err());
+
+        // A non-canonical field element.
+        let mut bytes = to_bytecode("    store 1field into r0;\n");
+        assert!(Function::<E>::from_bytes_le(&bytes).is_ok());
+        let offset = bytes.len() - 38;
+        bytes[offset..offset + 32].copy_from_slice(&[0xff; 32]);
+        assert!(Function::<E>::from_bytes_le(&bytes).is_err());
+
+        // An x-coordinate that is not of a group element.
+        let mut bytes = to_bytecode("    store 0group into r0;\n");
+        assert!(Function::<E>::from_bytes_le(&bytes).is_ok());
+        let x_coordinate = (1u64..)
+            .map(<E as Environment>::BaseField::from)
+            .find(|x| Literal::<E>::group_from_x_coordinate(*x).is_err())
+            .unwrap();
+        let offset = bytes.len() - 38;
+        bytes[offset..offset + 32].copy_from_slice(&x_coordinate.to_bytes_le().unwrap());
+        assert!(Function::<E>::from_bytes_le(&bytes).is_err());
+    }
+}
@@ -0,0 +1,486 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{CircuitEnvironment, CircuitInstance, ConstraintSystem};
+use snarkvm_algorithms::{SNARK, SRS};
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::One;
+use snarkvm_r1cs::{ConstraintSynthesizer, SynthesisError};
+
+use rand::{CryptoRng, Rng};
+use std::panic::{self, AssertUnwindSafe};
+
+///
+/// A function synthesized on concrete inputs, as a constraint system with its outputs.
+///
+/// The outputs of the function are allocated as public variables after the public inputs,
+/// so a proof of the assignment attests to the outputs.
+///
+/// An assignment is a `ConstraintSynthesizer`, so it may be given directly to a SNARK,
+/// or synthesized into another constraint system (which must start off empty).
+/// The constant one of the function is the constant one of the constraint system it is synthesized into,
+/// so the public inputs of a proof are only the public inputs and the outputs of the function.
+///
+pub struct Assignment<E: CircuitEnvironment> {
+    constraint_system: ConstraintSystem<E::BaseField>,
+    outputs: Vec<Literal<E>>,
+}
+
+impl<E: CircuitEnvironment> Assignment<E> {
+    /// Returns the constraint system of the assignment.
+    pub fn constraint_system(&self) -> &ConstraintSystem<E::BaseField> {
+        &self.constraint_system
+    }
+
+    /// Returns the outputs of the function.
+    pub fn outputs(&self) -> &[Literal<E>] {
+        &self.outputs
+    }
+
+    /// Returns the public inputs to verify a proof of the assignment.
+    pub fn to_public_inputs(&self) -> Vec<E::BaseField> {
+        // Skip the constant one, which is shared with the proof system.
+        self.constraint_system.to_public_inputs().into_iter().skip(1).collect()
+    }
+}
+
+impl<E: CircuitEnvironment> ConstraintSynthesizer<E::BaseField> for Assignment<E> {
+    fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<E::BaseField>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), SynthesisError> {
+        self.constraint_system.generate_constraints_with_shared_one(cs)
+    }
+}
+
+///
+/// A proof of a function on concrete inputs, with the public inputs and outputs it attests to.
+///
+/// An execution is checked with `Function::verify`, against the verifying key from `Function::setup`.
+///
+/// The keys and proof are those of the given SNARK, so the `CircuitFunction` of `snarkvm-dpc`
+/// implements its `Function` trait with an execution under the `ProgramSNARK` of a network.
+///
+pub struct Execution<E: CircuitEnvironment, S: SNARK<ScalarField = E::BaseField>> {
+    inputs: Vec<Literal<E>>,
+    outputs: Vec<Literal<E>>,
+    proof: S::Proof,
+}
+
+impl<E: CircuitEnvironment, S: SNARK<ScalarField = E::BaseField>> Execution<E, S> {
+    /// Returns the public inputs of the function.
+    pub fn inputs(&self) -> &[Literal<E>] {
+        &self.inputs
+    }
+
+    /// Returns the outputs of the function.
+    pub fn outputs(&self) -> &[Literal<E>] {
+        &self.outputs
+    }
+
+    /// Returns the proof.
+    pub fn proof(&self) -> &S::Proof {
+        &self.proof
+    }
+}
+
+impl<E: CircuitEnvironment> Function<E> {
+    ///
+    /// Evaluates the function on the given inputs in a new circuit instance,
+    /// and returns the resulting constraint system with the outputs of the function.
+    ///
+    pub fn synthesize(&self, inputs: &[Literal<E>]) -> Result<Assignment<E>> {
+        let (instance, outputs) = self.synthesize_unchecked(inputs)?;
+
+        if !instance.is_satisfied() {
+            return Err(anyhow!("Function '{}' is not satisfied by the given inputs", self.name));
+        }
+
+        Ok(Assignment {
+            constraint_system: instance.into_constraint_system(),
+            outputs,
+        })
+    }
+
+    ///
+    /// Derives the proving and verifying keys of the function from the given SRS.
+    ///
+    /// The keys depend only on the function, as it is synthesized on default inputs:
+    /// `false` for booleans, one for field elements, and the generator for group elements.
+    /// As constant inputs are fixed into the circuit, a function with constant inputs is rejected.
+    /// A function that halts on the default inputs, such as one that inverts `r0 - 1field`, is also rejected.
+    ///
+    pub fn setup<S: SNARK<ScalarField = E::BaseField>, R: Rng + CryptoRng>(
+        &self,
+        srs: &mut SRS<R, S::UniversalSetupParameters>,
+    ) -> Result<(S::ProvingKey, S::VerifyingKey)> {
+        if self.inputs.iter().any(|(_, mode)| mode.is_constant()) {
+            return Err(anyhow!(
+                "Function '{}' has a constant input, which cannot be set up",
+                self.name
+            ));
+        }
+
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|(register, _)| Ok(Self::default_input(register.to_type()?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let (instance, outputs) = self.synthesize_unchecked(&inputs)?;
+        let assignment = Assignment {
+            constraint_system: instance.into_constraint_system(),
+            outputs,
+        };
+
+        Ok(S::setup(&assignment, srs, -1)?)
+    }
+
+    ///
+    /// Synthesizes the function on the given inputs, and proves the assignment
+    /// under the given proving key from `Function::setup`.
+    ///
+    /// The SNARK may be the `ProgramSNARK` of a network, whose scalar field is the base field of `Circuit`.
+    ///
+    pub fn prove<S: SNARK<ScalarField = E::BaseField>, R: Rng + CryptoRng>(
+        &self,
+        proving_key: &S::ProvingKey,
+        inputs: &[Literal<E>],
+        rng: &mut R,
+    ) -> Result<Execution<E, S>> {
+        let assignment = self.synthesize(inputs)?;
+        let proof = S::prove(proving_key, &assignment, rng, -1)?;
+
+        let inputs = self
+            .inputs
+            .iter()
+            .zip(inputs)
+            .filter(|((_, mode), _)| *mode == Mode::Public)
+            .map(|(_, input)| *input)
+            .collect();
+
+        Ok(Execution {
+            inputs,
+            outputs: assignment.outputs,
+            proof,
+        })
+    }
+
+    ///
+    /// Returns `true` if the execution is a valid proof of the function, for its public inputs and outputs,
+    /// under the given verifying key from `Function::setup`.
+    ///
+    pub fn verify<S: SNARK<ScalarField = E::BaseField>>(
+        &self,
+        verifying_key: &S::VerifyingKey,
+        execution: &Execution<E, S>,
+    ) -> Result<bool>
+    where
+        S::VerifierInput: From<Vec<E::BaseField>>,
+    {
+        let public_inputs = S::VerifierInput::from(self.to_public_inputs(&execution.inputs, &execution.outputs)?);
+        Ok(S::verify(verifying_key, &public_inputs, &execution.proof)?)
+    }
+
+    ///
+    /// Returns the public inputs to verify a proof of the function,
+    /// given the values of its public inputs and outputs.
+    ///
+    pub fn to_public_inputs(&self, inputs: &[Literal<E>], outputs: &[Literal<E>]) -> Result<Vec<E::BaseField>> {
+        let input_types = self
+            .inputs
+            .iter()
+            .filter(|(_, mode)| *mode == Mode::Public)
+            .map(|(register, _)| register.to_type())
+            .collect::<Result<Vec<_>>>()?;
+        let output_types = self.outputs.iter().map(Register::to_type).collect::<Result<Vec<_>>>()?;
+
+        let types = inputs.iter().chain(outputs).map(Literal::to_type);
+        if inputs.len() != input_types.len()
+            || outputs.len() != output_types.len()
+            || !types.eq(input_types.into_iter().chain(output_types))
+        {
+            return Err(anyhow!(
+                "The public inputs and outputs do not match the signature of function '{}'",
+                self.name
+            ));
+        }
+
+        // Allocate the values in the order they are allocated during synthesis.
+        let instance = CircuitInstance::<E>::new();
+        instance.enter(|| {
+            for literal in inputs.iter().chain(outputs) {
+                literal.to_value(Mode::Public);
+            }
+        });
+        Ok(instance
+            .into_constraint_system()
+            .to_public_inputs()
+            .into_iter()
+            .skip(1)
+            .collect())
+    }
+
+    ///
+    /// Evaluates the function on the given inputs in a new circuit instance, and allocates each output
+    /// as a public variable, enforced to be equal to the output. Returns the instance with the outputs.
+    ///
+    /// If a gadget halts on the inputs, such as on the inverse of zero, the halt is returned as an error.
+    ///
+    fn synthesize_unchecked(&self, inputs: &[Literal<E>]) -> Result<(CircuitInstance<E>, Vec<Literal<E>>)> {
+        let instance = CircuitInstance::<E>::new();
+        let outputs = panic::catch_unwind(AssertUnwindSafe(|| {
+            instance.enter(|| -> Result<Vec<Literal<E>>> {
+                self.evaluate(inputs)?.iter().map(Self::enforce_public_output).collect()
+            })
+        }))
+        .map_err(|halt| {
+            let message = match (halt.downcast_ref::<String>(), halt.downcast_ref::<&str>()) {
+                (Some(message), _) => message.as_str(),
+                (None, Some(message)) => message,
+                (None, None) => "unknown halt",
+            };
+            anyhow!("Function '{}' halted on the given inputs: {}", self.name, message)
+        })??;
+        Ok((instance, outputs))
+    }
+
+    /// Allocates the given output as a public variable, and enforces that it is equal to the output.
+    fn enforce_public_output(output: &Value<E>) -> Result<Literal<E>> {
+        let literal = output.to_literal();
+        let is_equal = match (output, literal.to_value(Mode::Public)) {
+            (Value::Boolean(output), Value::Boolean(public)) => output.is_eq(&public),
+            (Value::BaseField(output), Value::BaseField(public)) => output.is_eq(&public),
+            (Value::Group(output), Value::Group(public)) => output.is_eq(&public),
+            (Value::ScalarField(output), Value::ScalarField(public)) => output.is_eq(&public),
+            _ => {
+                return Err(anyhow!(
+                    "Failed to allocate the output {} as a public variable",
+                    literal
+                ))
+            }
+        };
+        E::assert(&is_equal);
+        Ok(literal)
+    }
+
+    /// Returns the default input of the given type, used to set up the function.
+    pub fn default_input(type_: Type) -> Literal<E> {
+        match type_ {
+            Type::Boolean => Literal::Boolean(false),
+            Type::BaseField => Literal::BaseField(E::BaseField::one()),
+            Type::Group => Literal::Group(E::Affine::prime_subgroup_generator()),
+            Type::ScalarField => Literal::ScalarField(E::ScalarField::one()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_marlin::{
+        ahp::AHPForR1CS,
+        constraints::snark::MarlinSNARK,
+        fiat_shamir::{FiatShamirAlgebraicSpongeRng, PoseidonSponge},
+        marlin::MarlinRecursiveMode,
+    };
+    use snarkvm_polycommit::sonic_pc::SonicKZG10;
+    use snarkvm_utilities::rand::test_rng;
+
+    use rand::rngs::StdRng;
+
+    type E = Circuit;
+
+    type Marlin = MarlinSNARK<
+        Fr,
+        Fq,
+        SonicKZG10<Bls12_377>,
+        FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>,
+        MarlinRecursiveMode,
+        Vec<Fr>,
+    >;
+
+    const EXAMPLE: &str = r"function example:
+    input r0 as field.public;
+    input r1 as field.private;
+    mul r0 r1 into r2;
+    add r2 r0 into r3;
+    square r3 into r4;
+    is_eq r4 r1 into r5;
+    output r4 as field;
+    output r5 as boolean;
+";
+
+    fn universal_srs() -> <Marlin as SNARK>::UniversalSetupParameters {
+        let max_degree = AHPForR1CS::<Fr, MarlinRecursiveMode>::max_degree(100, 100, 100).unwrap();
+        Marlin::universal_setup(&max_degree, &mut test_rng()).unwrap()
+    }
+
+    #[test]
+    fn test_synthesize() {
+        let function: Function<E> = EXAMPLE.parse().unwrap();
+
+        let inputs = [Literal::BaseField(2u64.into()), Literal::BaseField(3u64.into())];
+        let assignment = function.synthesize(&inputs).unwrap();
+        assert_eq!(
+            &[Literal::BaseField(64u64.into()), Literal::Boolean(false)],
+            assignment.outputs()
+        );
+
+        // The public inputs are the public input of the function, and the outputs.
+        let expected: Vec<<E as Environment>::BaseField> = vec![2u64.into(), 64u64.into(), 0u64.into()];
+        assert_eq!(expected, assignment.to_public_inputs());
+        assert_eq!(
+            expected,
+            function.to_public_inputs(&inputs[..1], assignment.outputs()).unwrap()
+        );
+        assert!(assignment.constraint_system().is_satisfied());
+        assert!(assignment.constraint_system().num_constraints() > 0);
+
+        // Ensure the active circuit is left untouched.
+        assert_eq!(0, E::num_constraints());
+
+        let mut cs = snarkvm_r1cs::TestConstraintSystem::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+
+        // Ensure the inputs are checked.
+        assert!(function.synthesize(&inputs[..1]).is_err());
+        assert!(function.to_public_inputs(&inputs, assignment.outputs()).is_err());
+        assert!(function
+            .to_public_inputs(&inputs[..1], &assignment.outputs()[..1])
+            .is_err());
+        assert!(function
+            .to_public_inputs(&[Literal::Boolean(true)], assignment.outputs())
+            .is_err());
+    }
+
+    #[test]
+    fn test_prove() {
+        let function: Function<E> = EXAMPLE.parse().unwrap();
+        let universal_srs = universal_srs();
+        let rng = &mut test_rng();
+
+        let (proving_key, verifying_key) = function
+            .setup::<Marlin, StdRng>(&mut SRS::Universal(&universal_srs))
+            .unwrap();
+
+        let inputs = [Literal::BaseField(2u64.into()), Literal::BaseField(3u64.into())];
+        let execution = function.prove::<Marlin, _>(&proving_key, &inputs, rng).unwrap();
+        assert_eq!(&inputs[..1], execution.inputs());
+        assert_eq!(
+            &[Literal::BaseField(64u64.into()), Literal::Boolean(false)],
+            execution.outputs()
+        );
+        assert!(function.verify(&verifying_key, &execution).unwrap());
+
+        // Ensure the proof does not verify for a different public input.
+        let tampered = Execution::<E, Marlin> {
+            inputs: vec![Literal::BaseField(5u64.into())],
+            outputs: execution.outputs().to_vec(),
+            proof: execution.proof().clone(),
+        };
+        assert!(!function.verify(&verifying_key, &tampered).unwrap());
+
+        // Ensure the proof does not verify for a different output.
+        let tampered = Execution::<E, Marlin> {
+            inputs: execution.inputs().to_vec(),
+            outputs: vec![Literal::BaseField(64u64.into()), Literal::Boolean(true)],
+            proof: execution.proof().clone(),
+        };
+        assert!(!function.verify(&verifying_key, &tampered).unwrap());
+
+        // Ensure a proof for different private inputs verifies under the same verifying key.
+        let inputs = [Literal::BaseField(2u64.into()), Literal::BaseField(4u64.into())];
+        let execution = function.prove::<Marlin, _>(&proving_key, &inputs, rng).unwrap();
+        assert_eq!(
+            &[Literal::BaseField(100u64.into()), Literal::Boolean(false)],
+            execution.outputs()
+        );
+        assert!(function.verify(&verifying_key, &execution).unwrap());
+
+        // Ensure a proof of another function does not verify under the verifying key.
+        let other: Function<E> = EXAMPLE
+            .replace("square r3 into r4", "double r3 into r4")
+            .parse()
+            .unwrap();
+        let (other_proving_key, _) = other
+            .setup::<Marlin, StdRng>(&mut SRS::Universal(&universal_srs))
+            .unwrap();
+        let execution = other.prove::<Marlin, _>(&other_proving_key, &inputs, rng).unwrap();
+        assert!(!function.verify(&verifying_key, &execution).unwrap());
+    }
+
+    #[test]
+    fn test_setup_rejects_constant_inputs() {
+        let function: Function<E> = EXAMPLE.replace("field.public", "field.constant").parse().unwrap();
+        assert!(function
+            .setup::<Marlin, StdRng>(&mut SRS::Universal(&universal_srs()))
+            .is_err());
+    }
+
+    #[test]
+    fn test_setup_rejects_halting_on_default_inputs() {
+        let function: Function<E> = r"function inverse:
+    input r0 as field.public;
+    sub r0 1field into r1;
+    inv r1 into r2;
+    output r2 as field;
+"
+        .parse()
+        .unwrap();
+        assert!(function
+            .setup::<Marlin, StdRng>(&mut SRS::Universal(&universal_srs()))
+            .is_err());
+
+        // Ensure the function still synthesizes on inputs it does not halt on.
+        let assignment = function.synthesize(&[Literal::BaseField(2u64.into())]).unwrap();
+        assert_eq!(&[Literal::BaseField(1u64.into())], assignment.outputs());
+    }
+
+    #[test]
+    fn test_prove_rejects_halting_inputs() {
+        let function: Function<E> = r"function divide:
+    input r0 as field.public;
+    input r1 as field.private;
+    div r0 r1 into r2;
+    output r2 as field;
+"
+        .parse()
+        .unwrap();
+        let rng = &mut test_rng();
+
+        let universal_srs = universal_srs();
+        let (proving_key, verifying_key) = function
+            .setup::<Marlin, StdRng>(&mut SRS::Universal(&universal_srs))
+            .unwrap();
+
+        let inputs = [Literal::BaseField(6u64.into()), Literal::BaseField(0u64.into())];
+        assert!(function.synthesize(&inputs).is_err());
+        assert!(function.prove::<Marlin, _>(&proving_key, &inputs, rng).is_err());
+
+        // Ensure the active circuit is left untouched after the halt.
+        assert_eq!(0, E::num_constraints());
+
+        let inputs = [Literal::BaseField(6u64.into()), Literal::BaseField(3u64.into())];
+        let execution = function.prove::<Marlin, _>(&proving_key, &inputs, rng).unwrap();
+        assert_eq!(&[Literal::BaseField(2u64.into())], execution.outputs());
+        assert!(function.verify(&verifying_key, &execution).unwrap());
+    }
+}
@@ -0,0 +1,564 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use num_traits::Inv;
+
+/// The mnemonics of the instructions, indexed by opcode.
+const MNEMONICS: [&str; 18] = [
+    "store", "add", "sub", "mul", "div", "neg", "double", "square", "inv", "is_eq", "is_neq", "ternary", "and", "or",
+    "nand", "nor", "xor", "not",
+];
+
+pub enum Instruction<E: Environment> {
+    /// Stores `value` into `register`, if `register` is not already set.
+    Store(Value<E>, Register<E>),
+    /// Adds `first` with `second`, storing the outcome in `register`.
+    Add(Value<E>, Value<E>, Register<E>),
+    /// Subtracts `second` from `first`, storing the outcome in `register`.
+    Sub(Value<E>, Value<E>, Register<E>),
+    /// Multiplies `first` with `second`, storing the outcome in `register`.
+    /// A group element may be multiplied with a scalar field element.
+    Mul(Value<E>, Value<E>, Register<E>),
+    /// Divides `first` by `second`, storing the outcome in `register`.
+    Div(Value<E>, Value<E>, Register<E>),
+    /// Negates `value`, storing the outcome in `register`.
+    Neg(Value<E>, Register<E>),
+    /// Doubles `value`, storing the outcome in `register`.
+    Double(Value<E>, Register<E>),
+    /// Squares `value`, storing the outcome in `register`.
+    Square(Value<E>, Register<E>),
+    /// Inverts `value`, storing the outcome in `register`.
+    Inv(Value<E>, Register<E>),
+    /// Checks if `first` is equal to `second`, storing the outcome in `register`.
+    IsEq(Value<E>, Value<E>, Register<E>),
+    /// Checks if `first` is not equal to `second`, storing the outcome in `register`.
+    IsNeq(Value<E>, Value<E>, Register<E>),
+    /// Selects `first` if `condition` is `true`, and `second` otherwise, storing the outcome in `register`.
+    Ternary(Value<E>, Value<E>, Value<E>, Register<E>),
+    /// Performs a bitwise AND on `first` and `second`, storing the outcome in `register`.
+    And(Value<E>, Value<E>, Register<E>),
+    /// Performs a bitwise OR on `first` and `second`, storing the outcome in `register`.
+    Or(Value<E>, Value<E>, Register<E>),
+    /// Performs a bitwise NAND on `first` and `second`, storing the outcome in `register`.
+    Nand(Value<E>, Value<E>, Register<E>),
+    /// Performs a bitwise NOR on `first` and `second`, storing the outcome in `register`.
+    Nor(Value<E>, Value<E>, Register<E>),
+    /// Performs a bitwise XOR on `first` and `second`, storing the outcome in `register`.
+    Xor(Value<E>, Value<E>, Register<E>),
+    /// Performs a bitwise NOT on `value`, storing the outcome in `register`.
+    Not(Value<E>, Register<E>),
+}
+
+impl<E: Environment> Instruction<E> {
+    ///
+    /// Returns the opcode of the instruction.
+    ///
+    /// Opcodes are stable, and new instructions must be assigned new opcodes.
+    ///
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::Store(..) => 0,
+            Self::Add(..) => 1,
+            Self::Sub(..) => 2,
+            Self::Mul(..) => 3,
+            Self::Div(..) => 4,
+            Self::Neg(..) => 5,
+            Self::Double(..) => 6,
+            Self::Square(..) => 7,
+            Self::Inv(..) => 8,
+            Self::IsEq(..) => 9,
+            Self::IsNeq(..) => 10,
+            Self::Ternary(..) => 11,
+            Self::And(..) => 12,
+            Self::Or(..) => 13,
+            Self::Nand(..) => 14,
+            Self::Nor(..) => 15,
+            Self::Xor(..) => 16,
+            Self::Not(..) => 17,
+        }
+    }
+
+    /// Returns the mnemonic of the instruction.
+    pub fn mnemonic(&self) -> &'static str {
+        MNEMONICS[self.opcode() as usize]
+    }
+
+    /// Returns the opcode of the instruction with the given mnemonic.
+    pub(super) fn opcode_of(mnemonic: &str) -> Option<u16> {
+        MNEMONICS
+            .iter()
+            .position(|candidate| *candidate == mnemonic)
+            .map(|opcode| opcode as u16)
+    }
+
+    /// Returns the number of operands of the instruction with the given opcode.
+    pub(super) fn num_operands(opcode: u16) -> Option<usize> {
+        match opcode {
+            0 | 5 | 6 | 7 | 8 | 17 => Some(1),
+            1 | 2 | 3 | 4 | 9 | 10 | 12 | 13 | 14 | 15 | 16 => Some(2),
+            11 => Some(3),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Initializes a new instruction from the given opcode, operands, and destination register.
+    ///
+    /// The operands are type checked when the instruction is added to a function.
+    ///
+    pub fn from_opcode(opcode: u16, operands: Vec<Value<E>>, destination: Register<E>) -> Result<Self> {
+        let num_operands = Self::num_operands(opcode).ok_or_else(|| anyhow!("Unknown opcode {}", opcode))?;
+        if operands.len() != num_operands {
+            return Err(anyhow!(
+                "Instruction '{}' expects {} operands, found {}",
+                MNEMONICS[opcode as usize],
+                num_operands,
+                operands.len()
+            ));
+        }
+
+        let mut operands = operands.into_iter();
+        let mut next = || operands.next().unwrap();
+
+        Ok(match opcode {
+            0 => Self::Store(next(), destination),
+            1 => Self::Add(next(), next(), destination),
+            2 => Self::Sub(next(), next(), destination),
+            3 => Self::Mul(next(), next(), destination),
+            4 => Self::Div(next(), next(), destination),
+            5 => Self::Neg(next(), destination),
+            6 => Self::Double(next(), destination),
+            7 => Self::Square(next(), destination),
+            8 => Self::Inv(next(), destination),
+            9 => Self::IsEq(next(), next(), destination),
+            10 => Self::IsNeq(next(), next(), destination),
+            11 => Self::Ternary(next(), next(), next(), destination),
+            12 => Self::And(next(), next(), destination),
+            13 => Self::Or(next(), next(), destination),
+            14 => Self::Nand(next(), next(), destination),
+            15 => Self::Nor(next(), next(), destination),
+            16 => Self::Xor(next(), next(), destination),
+            17 => Self::Not(next(), destination),
+            _ => unreachable!(),
+        })
+    }
+
+    /// Returns the operands of the instruction, in order.
+    pub(super) fn operands(&self) -> Vec<&Value<E>> {
+        match self {
+            Self::Store(value, _)
+            | Self::Neg(value, _)
+            | Self::Double(value, _)
+            | Self::Square(value, _)
+            | Self::Inv(value, _)
+            | Self::Not(value, _) => vec![value],
+            Self::Add(first, second, _)
+            | Self::Sub(first, second, _)
+            | Self::Mul(first, second, _)
+            | Self::Div(first, second, _)
+            | Self::IsEq(first, second, _)
+            | Self::IsNeq(first, second, _)
+            | Self::And(first, second, _)
+            | Self::Or(first, second, _)
+            | Self::Nand(first, second, _)
+            | Self::Nor(first, second, _)
+            | Self::Xor(first, second, _) => vec![first, second],
+            Self::Ternary(condition, first, second, _) => vec![condition, first, second],
+        }
+    }
+
+    /// Returns the register that stores the outcome of the instruction.
+    pub(super) fn destination(&self) -> &Register<E> {
+        match self {
+            Self::Store(_, register)
+            | Self::Neg(_, register)
+            | Self::Double(_, register)
+            | Self::Square(_, register)
+            | Self::Inv(_, register)
+            | Self::Not(_, register)
+            | Self::Add(_, _, register)
+            | Self::Sub(_, _, register)
+            | Self::Mul(_, _, register)
+            | Self::Div(_, _, register)
+            | Self::IsEq(_, _, register)
+            | Self::IsNeq(_, _, register)
+            | Self::And(_, _, register)
+            | Self::Or(_, _, register)
+            | Self::Nand(_, _, register)
+            | Self::Nor(_, _, register)
+            | Self::Xor(_, _, register)
+            | Self::Ternary(_, _, _, register) => register,
+        }
+    }
+
+    ///
+    /// Returns the type of the outcome of the instruction,
+    /// or an error if the instruction does not support the types of its operands.
+    ///
+    pub(super) fn output_type(&self) -> Result<Type> {
+        let types = self
+            .operands()
+            .iter()
+            .map(|operand| operand.to_type())
+            .collect::<Result<Vec<_>>>()?;
+
+        let output_type = match (self, types.as_slice()) {
+            (Self::Store(..), [a]) => Some(*a),
+            (Self::Add(..) | Self::Sub(..), [a, b]) if a == b && *a != Type::Boolean => Some(*a),
+            (Self::Mul(..) | Self::Div(..), [a, b]) if a == b && a.is_field() => Some(*a),
+            (Self::Mul(..), [Type::Group, Type::ScalarField] | [Type::ScalarField, Type::Group]) => Some(Type::Group),
+            (Self::Neg(..) | Self::Double(..), [a]) if *a != Type::Boolean => Some(*a),
+            (Self::Square(..) | Self::Inv(..), [a]) if a.is_field() => Some(*a),
+            (Self::IsEq(..) | Self::IsNeq(..), [a, b]) if a == b => Some(Type::Boolean),
+            (Self::Ternary(..), [Type::Boolean, a, b]) if a == b => Some(*a),
+            (
+                Self::And(..) | Self::Or(..) | Self::Nand(..) | Self::Nor(..) | Self::Xor(..),
+                [Type::Boolean, Type::Boolean],
+            ) => Some(Type::Boolean),
+            (Self::Not(..), [Type::Boolean]) => Some(Type::Boolean),
+            _ => None,
+        };
+
+        output_type.ok_or_else(|| {
+            anyhow!(
+                "Instruction '{}' does not support operands of type {:?}",
+                self.mnemonic(),
+                types
+            )
+        })
+    }
+
+    ///
+    /// Evaluates the instruction, storing the outcome in the destination register.
+    ///
+    /// The operands are type checked when the instruction is added to a function.
+    ///
+    pub(super) fn evaluate(&self) {
+        let operands = self.operands().into_iter().map(Value::to_value).collect::<Vec<_>>();
+
+        let output = match (self, operands.as_slice()) {
+            (Self::Store(..), [value]) => value.clone(),
+
+            (Self::Add(..), [Value::BaseField(a), Value::BaseField(b)]) => Value::BaseField(a + b),
+            (Self::Add(..), [Value::ScalarField(a), Value::ScalarField(b)]) => Value::ScalarField(a + b),
+            (Self::Add(..), [Value::Group(a), Value::Group(b)]) => Value::Group(a + b),
+
+            (Self::Sub(..), [Value::BaseField(a), Value::BaseField(b)]) => Value::BaseField(a - b),
+            (Self::Sub(..), [Value::ScalarField(a), Value::ScalarField(b)]) => Value::ScalarField(a - b),
+            (Self::Sub(..), [Value::Group(a), Value::Group(b)]) => Value::Group(a - b),
+
+            (Self::Mul(..), [Value::BaseField(a), Value::BaseField(b)]) => Value::BaseField(a * b),
+            (Self::Mul(..), [Value::ScalarField(a), Value::ScalarField(b)]) => Value::ScalarField(a * b),
+            (Self::Mul(..), [Value::Group(a), Value::ScalarField(b)]) => Value::Group(a * b),
+            (Self::Mul(..), [Value::ScalarField(a), Value::Group(b)]) => Value::Group(a * b),
+
+            (Self::Div(..), [Value::BaseField(a), Value::BaseField(b)]) => Value::BaseField(a.clone() / b),
+            (Self::Div(..), [Value::ScalarField(a), Value::ScalarField(b)]) => Value::ScalarField(a / b),
+
+            (Self::Neg(..), [Value::BaseField(a)]) => Value::BaseField(-a),
+            (Self::Neg(..), [Value::ScalarField(a)]) => Value::ScalarField(-a),
+            (Self::Neg(..), [Value::Group(a)]) => Value::Group(-a),
+
+            (Self::Double(..), [Value::BaseField(a)]) => Value::BaseField(a.double()),
+            (Self::Double(..), [Value::ScalarField(a)]) => Value::ScalarField(a.double()),
+            (Self::Double(..), [Value::Group(a)]) => Value::Group(a.double()),
+
+            (Self::Square(..), [Value::BaseField(a)]) => Value::BaseField(a.square()),
+            (Self::Square(..), [Value::ScalarField(a)]) => Value::ScalarField(a.square()),
+
+            (Self::Inv(..), [Value::BaseField(a)]) => Value::BaseField(a.inv()),
+            (Self::Inv(..), [Value::ScalarField(a)]) => Value::ScalarField(a.inv()),
+
+            (Self::IsEq(..), [a, b]) => Value::Boolean(Self::is_eq(a, b)),
+            (Self::IsNeq(..), [a, b]) => Value::Boolean(!Self::is_eq(a, b)),
+
+            (Self::Ternary(..), [Value::Boolean(condition), a, b]) => match (a, b) {
+                (Value::Boolean(a), Value::Boolean(b)) => Value::Boolean(Boolean::ternary(condition, a, b)),
+                (Value::BaseField(a), Value::BaseField(b)) => Value::BaseField(BaseField::ternary(condition, a, b)),
+                (Value::ScalarField(a), Value::ScalarField(b)) => {
+                    Value::ScalarField(ScalarField::ternary(condition, a, b))
+                }
+                (Value::Group(a), Value::Group(b)) => Value::Group(Affine::ternary(condition, a, b)),
+                _ => unreachable!(),
+            },
+
+            (Self::And(..), [Value::Boolean(a), Value::Boolean(b)]) => Value::Boolean(a.and(b)),
+            (Self::Or(..), [Value::Boolean(a), Value::Boolean(b)]) => Value::Boolean(a.or(b)),
+            (Self::Nand(..), [Value::Boolean(a), Value::Boolean(b)]) => Value::Boolean(a.nand(b)),
+            (Self::Nor(..), [Value::Boolean(a), Value::Boolean(b)]) => Value::Boolean(a.nor(b)),
+            (Self::Xor(..), [Value::Boolean(a), Value::Boolean(b)]) => Value::Boolean(a.xor(b)),
+            (Self::Not(..), [Value::Boolean(a)]) => Value::Boolean(!a),
+
+            _ => unreachable!(
+                "Instruction '{}' is type checked when it is added to a function",
+                self.mnemonic()
+            ),
+        };
+
+        self.destination().store(&output);
+    }
+
+    /// Returns `true` if `first` is equal to `second`, for operands of the same type.
+    fn is_eq(first: &Value<E>, second: &Value<E>) -> Boolean<E> {
+        match (first, second) {
+            (Value::Boolean(a), Value::Boolean(b)) => a.is_eq(b),
+            (Value::BaseField(a), Value::BaseField(b)) => a.is_eq(b),
+            (Value::ScalarField(a), Value::ScalarField(b)) => a.is_eq(b),
+            (Value::Group(a), Value::Group(b)) => a.is_eq(b),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Mode};
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_fields::Field;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type E = Circuit;
+
+    /// Builds a function with the given inputs and a single instruction, and evaluates the output.
+    fn check_instruction(
+        inputs: Vec<Value<E>>,
+        instruction: impl FnOnce(Vec<Value<E>>, Register<E>) -> Instruction<E>,
+    ) -> Result<Value<E>> {
+        let mut function = Function::new("check_instruction");
+        let mut operands = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            operands.push(Value::Register(function.new_value(input)?));
+        }
+
+        let output = function.new_register();
+        function.push_instruction(instruction(operands, output.clone()))?;
+        function.add_output(&output)?;
+
+        Ok(function.evaluate(&[])?.remove(0))
+    }
+
+    fn base_field(value: u64) -> Value<E> {
+        Value::BaseField(BaseField::new(
+            Mode::Private,
+            <E as Environment>::BaseField::from(value),
+        ))
+    }
+
+    fn scalar_field(value: u64) -> Value<E> {
+        Value::ScalarField(ScalarField::new(
+            Mode::Private,
+            <E as Environment>::ScalarField::from(value),
+        ))
+    }
+
+    fn boolean(value: bool) -> Value<E> {
+        Value::Boolean(Boolean::new(Mode::Private, value))
+    }
+
+    fn expect_base_field(value: Value<E>) -> <E as Environment>::BaseField {
+        match value {
+            Value::BaseField(value) => value.eject_value(),
+            _ => panic!("Expected a base field element"),
+        }
+    }
+
+    fn expect_boolean(value: Value<E>) -> bool {
+        match value {
+            Value::Boolean(value) => value.eject_value(),
+            _ => panic!("Expected a boolean"),
+        }
+    }
+
+    #[test]
+    fn test_opcodes_are_unique() {
+        let mut function = Function::<E>::new("test");
+        let r = function.new_register();
+        let v = || Value::Register(r.clone());
+
+        let instructions = vec![
+            Instruction::Store(v(), r.clone()),
+            Instruction::Add(v(), v(), r.clone()),
+            Instruction::Sub(v(), v(), r.clone()),
+            Instruction::Mul(v(), v(), r.clone()),
+            Instruction::Div(v(), v(), r.clone()),
+            Instruction::Neg(v(), r.clone()),
+            Instruction::Double(v(), r.clone()),
+            Instruction::Square(v(), r.clone()),
+            Instruction::Inv(v(), r.clone()),
+            Instruction::IsEq(v(), v(), r.clone()),
+            Instruction::IsNeq(v(), v(), r.clone()),
+            Instruction::Ternary(v(), v(), v(), r.clone()),
+            Instruction::And(v(), v(), r.clone()),
+            Instruction::Or(v(), v(), r.clone()),
+            Instruction::Nand(v(), v(), r.clone()),
+            Instruction::Nor(v(), v(), r.clone()),
+            Instruction::Xor(v(), v(), r.clone()),
+            Instruction::Not(v(), r.clone()),
+        ];
+
+        for (expected, instruction) in instructions.iter().enumerate() {
+            assert_eq!(expected as u16, instruction.opcode());
+            assert_eq!(
+                Some(expected as u16),
+                Instruction::<E>::opcode_of(instruction.mnemonic())
+            );
+            assert_eq!(
+                Some(instruction.operands().len()),
+                Instruction::<E>::num_operands(expected as u16)
+            );
+        }
+        assert_eq!(None, Instruction::<E>::num_operands(instructions.len() as u16));
+    }
+
+    #[test]
+    fn test_field_arithmetic() {
+        let check = |instruction: fn(Vec<Value<E>>, Register<E>) -> Instruction<E>, expected: u64| {
+            let output = check_instruction(vec![base_field(6), base_field(3)], instruction).unwrap();
+            assert_eq!(<E as Environment>::BaseField::from(expected), expect_base_field(output));
+        };
+        check(|o, r| Instruction::Add(o[0].clone(), o[1].clone(), r), 9);
+        check(|o, r| Instruction::Sub(o[0].clone(), o[1].clone(), r), 3);
+        check(|o, r| Instruction::Mul(o[0].clone(), o[1].clone(), r), 18);
+        check(|o, r| Instruction::Div(o[0].clone(), o[1].clone(), r), 2);
+        check(|o, r| Instruction::Double(o[0].clone(), r), 12);
+        check(|o, r| Instruction::Square(o[1].clone(), r), 9);
+
+        let output = check_instruction(vec![base_field(6)], |o, r| Instruction::Neg(o[0].clone(), r)).unwrap();
+        assert_eq!(-<E as Environment>::BaseField::from(6u64), expect_base_field(output));
+
+        let output = check_instruction(vec![base_field(6)], |o, r| Instruction::Inv(o[0].clone(), r)).unwrap();
+        assert_eq!(
+            <E as Environment>::BaseField::from(6u64).inverse().unwrap(),
+            expect_base_field(output)
+        );
+
+        let output = check_instruction(vec![scalar_field(6), scalar_field(3)], |o, r| {
+            Instruction::Div(o[0].clone(), o[1].clone(), r)
+        })
+        .unwrap();
+        match output {
+            Value::ScalarField(output) => {
+                assert_eq!(<E as Environment>::ScalarField::from(2u64), output.eject_value())
+            }
+            _ => panic!("Expected a scalar field element"),
+        }
+        assert!(E::is_satisfied());
+    }
+
+    #[test]
+    fn test_group_scalar_multiplication() {
+        let point: <E as Environment>::Affine = UniformRand::rand(&mut thread_rng());
+        let scalar: <E as Environment>::ScalarField = UniformRand::rand(&mut thread_rng());
+
+        let group = Value::Group(Affine::new(Mode::Private, point.to_x_coordinate(), None));
+        let inputs = vec![group, Value::ScalarField(ScalarField::new(Mode::Private, scalar))];
+
+        let output = check_instruction(inputs.clone(), |o, r| Instruction::Mul(o[0].clone(), o[1].clone(), r));
+        match output.unwrap() {
+            Value::Group(output) => assert_eq!(point * scalar, output.eject_value()),
+            _ => panic!("Expected a group element"),
+        }
+
+        let output = check_instruction(inputs, |o, r| Instruction::Mul(o[1].clone(), o[0].clone(), r));
+        match output.unwrap() {
+            Value::Group(output) => assert_eq!(point * scalar, output.eject_value()),
+            _ => panic!("Expected a group element"),
+        }
+    }
+
+    #[test]
+    fn test_boolean_logic() {
+        for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+            let check = |instruction: fn(Value<E>, Value<E>, Register<E>) -> Instruction<E>, expected: bool| {
+                let output = check_instruction(vec![boolean(a), boolean(b)], |o, r| {
+                    instruction(o[0].clone(), o[1].clone(), r)
+                });
+                assert_eq!(expected, expect_boolean(output.unwrap()));
+            };
+            check(Instruction::And, a & b);
+            check(Instruction::Or, a | b);
+            check(Instruction::Nand, !(a & b));
+            check(Instruction::Nor, !(a | b));
+            check(Instruction::Xor, a ^ b);
+            check(Instruction::IsEq, a == b);
+            check(Instruction::IsNeq, a != b);
+
+            let output = check_instruction(vec![boolean(a)], |o, r| Instruction::Not(o[0].clone(), r));
+            assert_eq!(!a, expect_boolean(output.unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_ternary() {
+        for condition in [true, false] {
+            let inputs = vec![boolean(condition), base_field(1), base_field(2)];
+            let output = check_instruction(inputs, |o, r| {
+                Instruction::Ternary(o[0].clone(), o[1].clone(), o[2].clone(), r)
+            });
+            let expected = if condition { 1u64 } else { 2u64 };
+            assert_eq!(
+                <E as Environment>::BaseField::from(expected),
+                expect_base_field(output.unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn test_type_check() {
+        // Mismatched operand types.
+        let output = check_instruction(vec![base_field(1), scalar_field(1)], |o, r| {
+            Instruction::Add(o[0].clone(), o[1].clone(), r)
+        });
+        assert!(output.is_err());
+
+        // Unsupported operand types.
+        let output = check_instruction(vec![boolean(true), boolean(true)], |o, r| {
+            Instruction::Add(o[0].clone(), o[1].clone(), r)
+        });
+        assert!(output.is_err());
+        let output = check_instruction(vec![base_field(1)], |o, r| Instruction::Not(o[0].clone(), r));
+        assert!(output.is_err());
+
+        // A non-boolean condition.
+        let output = check_instruction(vec![base_field(1), base_field(1), base_field(2)], |o, r| {
+            Instruction::Ternary(o[0].clone(), o[1].clone(), o[2].clone(), r)
+        });
+        assert!(output.is_err());
+
+        // An unassigned register operand.
+        let mut function = Function::<E>::new("test");
+        let unassigned = function.new_register();
+        let output = function.new_register();
+        let instruction = Instruction::Neg(Value::Register(unassigned), output.clone());
+        assert!(function.push_instruction(instruction).is_err());
+
+        // A destination register that is already assigned.
+        let input = function.new_value(base_field(1)).unwrap();
+        let instruction = Instruction::Neg(Value::Register(input.clone()), input);
+        assert!(function.push_instruction(instruction).is_err());
+
+        // A register allocated by another function.
+        let foreign = Function::<E>::new("test").new_register();
+        assert!(function
+            .push_instruction(Instruction::Store(base_field(1), foreign))
+            .is_err());
+    }
+}
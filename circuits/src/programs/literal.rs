@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::AffineCurve;
+
+use derivative::Derivative;
+
+/// A native value, which is allocated as a circuit value when a function is evaluated.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = ""),
+    Clone(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+pub enum Literal<E: Environment> {
+    Boolean(bool),
+    BaseField(E::BaseField),
+    Group(E::Affine),
+    ScalarField(E::ScalarField),
+}
+
+impl<E: Environment> Literal<E> {
+    /// Returns the type of the literal.
+    pub fn to_type(&self) -> Type {
+        match self {
+            Self::Boolean(..) => Type::Boolean,
+            Self::BaseField(..) => Type::BaseField,
+            Self::Group(..) => Type::Group,
+            Self::ScalarField(..) => Type::ScalarField,
+        }
+    }
+
+    /// Allocates the literal as a circuit value in the given mode.
+    pub fn to_value(&self, mode: Mode) -> Value<E> {
+        match self {
+            Self::Boolean(value) => Value::Boolean(Boolean::new(mode, *value)),
+            Self::BaseField(value) => Value::BaseField(BaseField::new(mode, *value)),
+            Self::Group(value) => Value::Group(Affine::new(
+                mode,
+                value.to_x_coordinate(),
+                Some(value.to_y_coordinate()),
+            )),
+            Self::ScalarField(value) => Value::ScalarField(ScalarField::new(mode, *value)),
+        }
+    }
+
+    ///
+    /// Returns the group element in the prime-order subgroup with the given x-coordinate,
+    /// or an error if no such element exists.
+    ///
+    pub(super) fn group_from_x_coordinate(x: E::BaseField) -> Result<E::Affine> {
+        [true, false]
+            .iter()
+            .filter_map(|greatest| E::Affine::from_x_coordinate(x, *greatest))
+            .find(|element| element.is_in_correct_subgroup_assuming_on_curve())
+            .ok_or_else(|| anyhow!("Failed to recover a group element from an x-coordinate of {}", x))
+    }
+}
+
+impl<E: Environment> FromStr for Literal<E> {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses a literal, which is either `true`, `false`, or a decimal number followed by its type,
+    /// as in `5field`, `5scalar`, or `5group`, where a group element is given by its x-coordinate.
+    ///
+    fn from_str(literal: &str) -> Result<Self> {
+        let invalid = || anyhow!("Invalid literal '{}'", literal);
+
+        match literal {
+            "true" => Ok(Self::Boolean(true)),
+            "false" => Ok(Self::Boolean(false)),
+            _ => {
+                let (value, type_) = match literal.find(|c: char| !c.is_ascii_digit()) {
+                    Some(0) | None => return Err(invalid()),
+                    Some(index) => literal.split_at(index),
+                };
+
+                match type_.parse::<Type>().map_err(|_| invalid())? {
+                    Type::BaseField => Ok(Self::BaseField(value.parse().map_err(|_| invalid())?)),
+                    Type::Group => Ok(Self::Group(Self::group_from_x_coordinate(
+                        value.parse().map_err(|_| invalid())?,
+                    )?)),
+                    Type::ScalarField => Ok(Self::ScalarField(value.parse().map_err(|_| invalid())?)),
+                    Type::Boolean => Err(invalid()),
+                }
+            }
+        }
+    }
+}
+
+impl<E: Environment> fmt::Display for Literal<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Boolean(value) => write!(f, "{}", value),
+            Self::BaseField(value) => write!(f, "{}{}", value, Type::BaseField),
+            Self::Group(value) => write!(f, "{}{}", value.to_x_coordinate(), Type::Group),
+            Self::ScalarField(value) => write!(f, "{}{}", value, Type::ScalarField),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type E = Circuit;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_round_trip(expected: Literal<E>) {
+        let candidate = expected.to_string().parse::<Literal<E>>().unwrap();
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        check_round_trip(Literal::Boolean(true));
+        check_round_trip(Literal::Boolean(false));
+
+        for _ in 0..ITERATIONS {
+            check_round_trip(Literal::BaseField(UniformRand::rand(&mut thread_rng())));
+            check_round_trip(Literal::ScalarField(UniformRand::rand(&mut thread_rng())));
+            check_round_trip(Literal::Group(UniformRand::rand(&mut thread_rng())));
+        }
+        check_round_trip(Literal::Group(<E as Environment>::Affine::prime_subgroup_generator()));
+        check_round_trip(Literal::Group(<E as Environment>::Affine::zero()));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Literal::<E>::BaseField(5u64.into()), "5field".parse().unwrap());
+        assert_eq!(Literal::<E>::ScalarField(0u64.into()), "0scalar".parse().unwrap());
+
+        assert!("field".parse::<Literal<E>>().is_err());
+        assert!("5".parse::<Literal<E>>().is_err());
+        assert!("5boolean".parse::<Literal<E>>().is_err());
+        assert!("5u8".parse::<Literal<E>>().is_err());
+        assert!("05field".parse::<Literal<E>>().is_err());
+        assert!("-5field".parse::<Literal<E>>().is_err());
+        assert!("True".parse::<Literal<E>>().is_err());
+    }
+
+    #[test]
+    fn test_to_value() {
+        let point: <E as Environment>::Affine = UniformRand::rand(&mut thread_rng());
+        let literal = Literal::<E>::Group(point);
+        assert_eq!(Type::Group, literal.to_type());
+
+        match literal.to_value(Mode::Private) {
+            Value::Group(value) => assert_eq!(point, value.eject_value()),
+            _ => panic!("Expected a group element"),
+        }
+        assert_eq!(literal, literal.to_value(Mode::Public).to_literal());
+    }
+}
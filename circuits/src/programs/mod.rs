@@ -14,16 +14,76 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{traits::*, Affine, BaseField, Boolean, Environment};
+mod bytecode;
 
+pub mod execution;
+pub use execution::*;
+
+pub mod instruction;
+pub use instruction::*;
+
+pub mod literal;
+pub use literal::*;
+
+pub mod parser;
+pub use parser::*;
+
+mod printer;
+
+use crate::{traits::*, Affine, BaseField, Boolean, Environment, Mode, ScalarField};
+
+use anyhow::{anyhow, Result};
 use once_cell::unsync::OnceCell;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc, str::FromStr};
+
+/// The type of a value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Type {
+    Boolean,
+    BaseField,
+    Group,
+    ScalarField,
+}
+
+impl Type {
+    /// Returns `true` if the type is a field type.
+    fn is_field(&self) -> bool {
+        matches!(self, Self::BaseField | Self::ScalarField)
+    }
+}
+
+impl FromStr for Type {
+    type Err = anyhow::Error;
+
+    fn from_str(type_: &str) -> Result<Self> {
+        match type_ {
+            "boolean" => Ok(Self::Boolean),
+            "field" => Ok(Self::BaseField),
+            "group" => Ok(Self::Group),
+            "scalar" => Ok(Self::ScalarField),
+            _ => Err(anyhow!("Unknown type '{}'", type_)),
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Boolean => write!(f, "boolean"),
+            Self::BaseField => write!(f, "field"),
+            Self::Group => write!(f, "group"),
+            Self::ScalarField => write!(f, "scalar"),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub enum Value<E: Environment> {
     Boolean(Boolean<E>),
     BaseField(BaseField<E>),
     Group(Affine<E>),
+    ScalarField(ScalarField<E>),
+    Literal(Literal<E>),
     Register(Register<E>),
 }
 
@@ -33,9 +93,37 @@ impl<E: Environment> Value<E> {
         matches!(self, Self::Register(..))
     }
 
-    /// Returns the value from a register, otherwise passes the loaded value through.
+    /// Returns the type of the value, or the assigned type of the register.
+    fn to_type(&self) -> Result<Type> {
+        match self {
+            Self::Boolean(..) => Ok(Type::Boolean),
+            Self::BaseField(..) => Ok(Type::BaseField),
+            Self::Group(..) => Ok(Type::Group),
+            Self::ScalarField(..) => Ok(Type::ScalarField),
+            Self::Literal(literal) => Ok(literal.to_type()),
+            Self::Register(register) => register.to_type(),
+        }
+    }
+
+    /// Returns the native value, loading the value from a register if necessary.
+    pub fn to_literal(&self) -> Literal<E> {
+        match self {
+            Self::Boolean(value) => Literal::Boolean(value.eject_value()),
+            Self::BaseField(value) => Literal::BaseField(value.eject_value()),
+            Self::Group(value) => Literal::Group(value.eject_value()),
+            Self::ScalarField(value) => Literal::ScalarField(value.eject_value()),
+            Self::Literal(literal) => *literal,
+            Self::Register(register) => register.load().to_literal(),
+        }
+    }
+
+    ///
+    /// Returns the value from a register, or allocates a literal as a constant,
+    /// otherwise passes the loaded value through.
+    ///
     fn to_value(&self) -> Value<E> {
         match self {
+            Self::Literal(literal) => literal.to_value(Mode::Constant),
             Self::Register(register) => register.load(),
             value => value.clone(),
         }
@@ -63,10 +151,21 @@ impl<E: Environment> Register<E> {
     fn load(&self) -> Value<E> {
         self.1.borrow().load(self.0).clone()
     }
+
+    /// Returns the type assigned to the register.
+    fn to_type(&self) -> Result<Type> {
+        self.1.borrow().register_type(self.0)
+    }
+
+    /// Returns `true` if the register is allocated in the given memory.
+    fn is_in(&self, memory: &Rc<RefCell<Memory<E>>>) -> bool {
+        Rc::ptr_eq(&self.1, memory)
+    }
 }
 
 pub struct Memory<E: Environment> {
     registers: Vec<OnceCell<Value<E>>>,
+    types: Vec<Option<Type>>,
 }
 
 impl<E: Environment> Memory<E> {
@@ -74,6 +173,7 @@ impl<E: Environment> Memory<E> {
     fn new() -> Self {
         Self {
             registers: Default::default(),
+            types: Default::default(),
         }
     }
 
@@ -81,9 +181,31 @@ impl<E: Environment> Memory<E> {
     fn new_register(&mut self) -> Locator {
         let locator = Locator(self.registers.len() as u32);
         self.registers.push(OnceCell::new());
+        self.types.push(None);
         locator
     }
 
+    /// Assigns the given type to the register, if the register is not already assigned.
+    fn assign_register(&mut self, locator: Locator, type_: Type) -> Result<()> {
+        match self.types.get_mut(locator.0 as usize) {
+            Some(Some(..)) => Err(anyhow!("Register {} is already assigned", locator.0)),
+            Some(register_type) => {
+                *register_type = Some(type_);
+                Ok(())
+            }
+            None => Err(anyhow!("Failed to locate register {}", locator.0)),
+        }
+    }
+
+    /// Returns the type assigned to the register.
+    fn register_type(&self, locator: Locator) -> Result<Type> {
+        match self.types.get(locator.0 as usize) {
+            Some(Some(type_)) => Ok(*type_),
+            Some(None) => Err(anyhow!("Register {} is not assigned", locator.0)),
+            None => Err(anyhow!("Failed to locate register {}", locator.0)),
+        }
+    }
+
     /// Returns `true` if the register at the given locator is already set.
     fn is_register_set(&self, locator: Locator) -> bool {
         match self.registers.get(locator.0 as usize) {
@@ -118,104 +240,180 @@ impl<E: Environment> Memory<E> {
         }
     }
 
+    /// Clears the values in all registers, leaving their assigned types.
+    fn clear(&mut self) {
+        self.registers.iter_mut().for_each(|register| {
+            register.take();
+        });
+    }
+
     /// Returns the number of registers allocated.
     fn num_registers(&self) -> u32 {
         self.registers.len() as u32
     }
 }
 
-pub enum Instruction<E: Environment> {
-    /// Stores `value` into `register`, if `register` is not already set.
-    Store(Value<E>, Register<E>),
-    /// Adds `first` with `second`, storing the outcome in `register`.
-    Add(Value<E>, Value<E>, Register<E>),
-}
-
-impl<E: Environment> Instruction<E> {
-    /// Returns the opcode of the instruction.
-    fn opcode(&self) -> u16 {
-        match self {
-            Self::Store(..) => 0,
-            Self::Add(..) => 1,
-        }
-    }
-
-    /// Evaluates the instruction.
-    fn evaluate(&self) {
-        match self {
-            Self::Store(..) => self.store(),
-            Self::Add(..) => self.add(),
-        }
-    }
-
-    /// Stores `value` into `register`, if `register` is not already set.
-    fn store(&self) {
-        match self {
-            Self::Store(value, register) => register.store(value),
-            _ => unreachable!(),
-        };
-    }
-
-    /// Adds `first` with `second`, storing the outcome in `register`.
-    fn add(&self) {
-        // Load the values and register.
-        let (first, second, register) = match self {
-            Self::Add(first, second, register) => (first, second, register),
-            _ => unreachable!(),
-        };
-
-        // Perform the operation.
-        match (first.to_value(), second.to_value()) {
-            (Value::BaseField(a), Value::BaseField(b)) => register.store(&Value::BaseField(a + b)),
-            (Value::Group(a), Value::Group(b)) => register.store(&Value::Group(a + b)),
-            _ => unreachable!(),
-        }
-    }
-}
-
 pub struct Function<E: Environment> {
+    name: String,
     memory: Rc<RefCell<Memory<E>>>,
+    inputs: Vec<(Register<E>, Mode)>,
     instructions: Vec<Instruction<E>>,
+    outputs: Vec<Register<E>>,
 }
 
 impl<E: Environment> Function<E> {
-    /// Initializes a new instance of a function.
-    fn new() -> Self {
+    /// Initializes a new instance of a function with the given name.
+    pub fn new(name: &str) -> Self {
         Self {
+            name: name.to_string(),
             memory: Rc::new(RefCell::new(Memory::new())),
+            inputs: Vec::new(),
             instructions: Vec::new(),
+            outputs: Vec::new(),
         }
     }
 
+    /// Returns the name of the function.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the types and modes of the inputs of the function, in order.
+    pub fn input_types(&self) -> Result<Vec<(Type, Mode)>> {
+        self.inputs
+            .iter()
+            .map(|(register, mode)| Ok((register.to_type()?, *mode)))
+            .collect()
+    }
+
+    /// Returns the types of the outputs of the function, in order.
+    pub fn output_types(&self) -> Result<Vec<Type>> {
+        self.outputs.iter().map(Register::to_type).collect()
+    }
+
     /// Allocates a new register in memory, returning the new register.
-    fn new_register(&mut self) -> Register<E> {
+    pub fn new_register(&mut self) -> Register<E> {
         let locator = self.memory.borrow_mut().new_register();
         Register(locator, self.memory.clone())
     }
 
-    /// Allocates a new register, adds an instruction to store the given input, and returns the new register.
-    fn new_input(&mut self, input: Value<E>) -> Register<E> {
+    ///
+    /// Allocates a new register for an input of the given type and mode, and returns the new register.
+    ///
+    /// The value of the input is given when the function is evaluated.
+    ///
+    pub fn new_input(&mut self, type_: Type, mode: Mode) -> Result<Register<E>> {
+        let register = self.new_register();
+        self.memory.borrow_mut().assign_register(register.0, type_)?;
+        self.inputs.push((register.clone(), mode));
+        Ok(register)
+    }
+
+    /// Allocates a new register, adds an instruction to store the given value, and returns the new register.
+    pub fn new_value(&mut self, value: Value<E>) -> Result<Register<E>> {
         let register = self.new_register();
-        self.push_instruction(Instruction::Store(input, register.clone()));
-        register
+        self.push_instruction(Instruction::Store(value, register.clone()))?;
+        Ok(register)
     }
 
-    /// Adds the given instruction.
-    fn push_instruction(&mut self, instruction: Instruction<E>) {
+    ///
+    /// Adds the given instruction, after type checking its operands,
+    /// and assigns the type of its output to the destination register.
+    ///
+    pub fn push_instruction(&mut self, instruction: Instruction<E>) -> Result<()> {
+        // Ensure every register in the instruction is allocated by this function.
+        let destination = instruction.destination();
+        let registers = instruction.operands().into_iter().filter_map(|operand| match operand {
+            Value::Register(register) => Some(register),
+            _ => None,
+        });
+        for register in registers.chain(std::iter::once(destination)) {
+            if !register.is_in(&self.memory) {
+                return Err(anyhow!("Register {} is not allocated by this function", (register.0).0));
+            }
+        }
+
+        // Type check the instruction, and assign its output type to the destination register.
+        let output_type = instruction.output_type()?;
+        self.memory.borrow_mut().assign_register(destination.0, output_type)?;
+
         self.instructions.push(instruction);
+        Ok(())
+    }
+
+    /// Adds the given register as an output of the function.
+    pub fn add_output(&mut self, register: &Register<E>) -> Result<()> {
+        if !register.is_in(&self.memory) {
+            return Err(anyhow!("Register {} is not allocated by this function", (register.0).0));
+        }
+
+        // Ensure the register is assigned, so that it is set when the function is evaluated.
+        register.to_type()?;
+
+        self.outputs.push(register.clone());
+        Ok(())
     }
 
-    /// Evaluates the function.
-    fn evaluate(&self) {
+    ///
+    /// Evaluates the function on the given inputs, and returns the outputs.
+    ///
+    /// Each input is allocated with the mode it was declared with.
+    ///
+    pub fn evaluate(&self, inputs: &[Literal<E>]) -> Result<Vec<Value<E>>> {
+        if inputs.len() != self.inputs.len() {
+            return Err(anyhow!(
+                "Function '{}' expects {} inputs, found {}",
+                self.name,
+                self.inputs.len(),
+                inputs.len()
+            ));
+        }
+
+        for (i, ((register, _), input)) in self.inputs.iter().zip(inputs).enumerate() {
+            let expected = register.to_type()?;
+            if input.to_type() != expected {
+                return Err(anyhow!(
+                    "Input {} of function '{}' expects a {}, found {}",
+                    i,
+                    self.name,
+                    expected,
+                    input
+                ));
+            }
+        }
+
+        // Clear the registers of any previous evaluation.
+        self.memory.borrow_mut().clear();
+
+        for ((register, mode), input) in self.inputs.iter().zip(inputs) {
+            register.store(&input.to_value(*mode));
+        }
+
         for instruction in &self.instructions {
             instruction.evaluate();
         }
+
+        Ok(self.outputs.iter().map(Register::load).collect())
     }
 
     /// Returns the number of registers allocated.
     fn num_registers(&self) -> u32 {
         self.memory.borrow().num_registers()
     }
+
+    ///
+    /// Returns the index of each defined register, keyed by its locator,
+    /// where registers are numbered in the order they are defined, starting with the inputs.
+    ///
+    fn register_indices(&self) -> HashMap<u32, u32> {
+        let inputs = self.inputs.iter().map(|(register, _)| register);
+        let destinations = self.instructions.iter().map(Instruction::destination);
+        inputs
+            .chain(destinations)
+            .enumerate()
+            .map(|(index, register)| ((register.0).0, index as u32))
+            .collect()
+    }
 }
 
 pub struct HelloWorld<E: Environment> {
@@ -225,14 +423,14 @@ pub struct HelloWorld<E: Environment> {
 
 impl<E: Environment> HelloWorld<E> {
     /// Initializes a new instance of `HelloWorld` with the given inputs.
-    pub fn new(inputs: [Value<E>; 2]) -> Self {
-        let mut function = Function::new();
+    pub fn new(inputs: [Value<E>; 2]) -> Result<Self> {
+        let mut function = Function::new("hello_world");
         let mut outputs = Vec::new();
 
         // Allocate a new register for each input, and store each input in the register.
         let mut registers = Vec::with_capacity(2);
         for input in inputs {
-            registers.push(function.new_input(input));
+            registers.push(function.new_value(input)?);
         }
 
         // Add the values in the registers, storing the result in a newly allocated register.
@@ -243,15 +441,16 @@ impl<E: Environment> HelloWorld<E> {
 
             let instruction = Instruction::Add(first, second, output.clone());
 
-            function.push_instruction(instruction);
+            function.push_instruction(instruction)?;
+            function.add_output(&output)?;
             outputs.push(output);
         }
 
-        Self { function, outputs }
+        Ok(Self { function, outputs })
     }
 
-    pub fn run(&self) {
-        self.function.evaluate();
+    pub fn run(&self) -> Result<Vec<Value<E>>> {
+        self.function.evaluate(&[])
     }
 }
 
@@ -265,8 +464,8 @@ mod tests {
         let first = Value::BaseField(BaseField::<Circuit>::one());
         let second = Value::BaseField(BaseField::one());
 
-        let function = HelloWorld::new([first, second]);
-        function.run();
+        let function = HelloWorld::new([first, second]).unwrap();
+        function.run().unwrap();
 
         let expected = BaseField::one() + BaseField::one();
         match function.outputs[0].load() {
@@ -274,4 +473,11 @@ mod tests {
             _ => panic!("Failed to load output"),
         }
     }
+
+    #[test]
+    fn test_hello_world_type_check() {
+        let first = Value::Boolean(Boolean::<Circuit>::new(Mode::Private, true));
+        let second = Value::Boolean(Boolean::new(Mode::Private, false));
+        assert!(HelloWorld::new([first, second]).is_err());
+    }
 }
@@ -0,0 +1,455 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use thiserror::Error;
+
+/// An error in the text of a function, at the given line and column.
+#[derive(Debug, Error)]
+#[error("{line}:{column}: {message}")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// A token in the text of a function, with its line and column, starting from 1.
+#[derive(Copy, Clone)]
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Token<'a> {
+    /// Returns an error at the position of the token.
+    fn error<S: ToString>(&self, message: S) -> ParseError {
+        self.error_at(0, message)
+    }
+
+    /// Returns an error at the given character offset into the token.
+    fn error_at<S: ToString>(&self, offset: usize, message: S) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column + offset,
+            message: message.to_string(),
+        }
+    }
+}
+
+///
+/// Splits the text into tokens, which are separated by whitespace.
+/// The characters `:` and `;` are tokens of their own, and `//` starts a comment to the end of the line.
+///
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line = match line.find("//") {
+            Some(index) => &line[..index],
+            None => line,
+        };
+
+        let token = |start: usize, end: usize| Token {
+            text: &line[start..end],
+            line: i + 1,
+            column: line[..start].chars().count() + 1,
+        };
+
+        let mut start = None;
+        for (index, c) in line.char_indices() {
+            if c.is_whitespace() || c == ':' || c == ';' {
+                if let Some(start) = start.take() {
+                    tokens.push(token(start, index));
+                }
+                if c == ':' || c == ';' {
+                    tokens.push(token(index, index + 1));
+                }
+            } else if start.is_none() {
+                start = Some(index);
+            }
+        }
+        if let Some(start) = start {
+            tokens.push(token(start, line.len()));
+        }
+    }
+
+    tokens
+}
+
+///
+/// Groups the tokens into statements, each of which is returned with its terminating `:` or `;`.
+///
+fn split_statements(tokens: Vec<Token<'_>>) -> Result<Vec<(Vec<Token<'_>>, Token<'_>)>, ParseError> {
+    let mut statements = Vec::new();
+    let mut statement = Vec::new();
+
+    for token in tokens {
+        match token.text {
+            ":" | ";" => match statement.is_empty() {
+                true => return Err(token.error(format!("Expected a statement before '{}'", token.text))),
+                false => statements.push((std::mem::take(&mut statement), token)),
+            },
+            _ => statement.push(token),
+        }
+    }
+
+    match statement.last() {
+        Some(last) => Err(last.error_at(last.text.chars().count(), "Expected ';'")),
+        None => Ok(statements),
+    }
+}
+
+/// Returns `true` if the given text is a valid function name.
+pub(super) fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => chars.all(|c| c.is_ascii_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
+
+/// Returns the index of the register named by the token, if the token is a register, as in `r0`.
+fn register_index(token: &Token) -> Option<usize> {
+    let digits = token.text.strip_prefix('r')?;
+    let is_canonical = digits == "0" || !digits.starts_with('0');
+    match !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) && is_canonical {
+        true => digits.parse().ok(),
+        false => None,
+    }
+}
+
+/// The state of the parser, while the function is built statement by statement.
+struct Parser<E: Environment> {
+    function: Function<E>,
+    /// The registers defined so far, indexed by their number in the text.
+    registers: Vec<Register<E>>,
+}
+
+impl<E: Environment> Parser<E> {
+    /// Returns the defined register named by the token.
+    fn register(&self, token: &Token) -> Result<Register<E>, ParseError> {
+        match register_index(token) {
+            Some(index) => match self.registers.get(index) {
+                Some(register) => Ok(register.clone()),
+                None => Err(token.error(format!("Register '{}' is not defined", token.text))),
+            },
+            None => Err(token.error(format!("Expected a register, found '{}'", token.text))),
+        }
+    }
+
+    /// Ensures the token names the next register to be defined, as registers are defined in order.
+    fn check_definition(&self, token: &Token) -> Result<(), ParseError> {
+        match register_index(token) == Some(self.registers.len()) {
+            true => Ok(()),
+            false => Err(token.error(format!(
+                "Expected register 'r{}', found '{}'",
+                self.registers.len(),
+                token.text
+            ))),
+        }
+    }
+
+    /// Returns the type named by the given text, which starts the token.
+    fn type_(token: &Token, text: &str) -> Result<Type, ParseError> {
+        text.parse().map_err(|error| token.error(error))
+    }
+
+    /// Parses `input <register> as <type>.<mode>`.
+    fn parse_input(&mut self, statement: &[Token]) -> Result<(), ParseError> {
+        let (register, annotation) = match statement {
+            [_, register, as_, annotation] if as_.text == "as" => (register, annotation),
+            _ => return Err(statement[0].error("Expected 'input <register> as <type>.<mode>'")),
+        };
+
+        self.check_definition(register)?;
+
+        let (type_, mode) = match annotation.text.split_once('.') {
+            Some((type_, mode)) => {
+                let type_ = Self::type_(annotation, type_)?;
+                let offset = annotation.text.chars().count() - mode.chars().count();
+                (type_, mode.parse().map_err(|error| annotation.error_at(offset, error))?)
+            }
+            None => return Err(annotation.error(format!("Expected '<type>.<mode>', found '{}'", annotation.text))),
+        };
+
+        let register = self
+            .function
+            .new_input(type_, mode)
+            .map_err(|error| register.error(error))?;
+        self.registers.push(register);
+        Ok(())
+    }
+
+    /// Parses `output <register> as <type>`.
+    fn parse_output(&mut self, statement: &[Token]) -> Result<(), ParseError> {
+        let (token, annotation) = match statement {
+            [_, register, as_, annotation] if as_.text == "as" => (register, annotation),
+            _ => return Err(statement[0].error("Expected 'output <register> as <type>'")),
+        };
+
+        let register = self.register(token)?;
+        let expected = Self::type_(annotation, annotation.text)?;
+        match register.to_type() {
+            Ok(type_) if type_ == expected => (),
+            Ok(type_) => {
+                let message = format!("Expected '{}' to be a {}, found {}", token.text, expected, type_);
+                return Err(annotation.error(message));
+            }
+            Err(error) => return Err(token.error(error)),
+        }
+
+        self.function.add_output(&register).map_err(|error| token.error(error))
+    }
+
+    /// Parses `<mnemonic> <operand> ... into <register>`.
+    fn parse_instruction(&mut self, statement: &[Token]) -> Result<(), ParseError> {
+        let mnemonic = &statement[0];
+        let opcode = Instruction::<E>::opcode_of(mnemonic.text)
+            .ok_or_else(|| mnemonic.error(format!("Unknown instruction '{}'", mnemonic.text)))?;
+
+        let (operands, destination) = match &statement[1..] {
+            [operands @ .., into, destination] if into.text == "into" => (operands, destination),
+            _ => return Err(mnemonic.error(format!("Expected '{} <operand> ... into <register>'", mnemonic.text))),
+        };
+
+        let operands = operands
+            .iter()
+            .map(|operand| match register_index(operand) {
+                Some(..) => self.register(operand).map(Value::Register),
+                None => operand
+                    .text
+                    .parse()
+                    .map(Value::Literal)
+                    .map_err(|error| operand.error(error)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.check_definition(destination)?;
+        let register = self.function.new_register();
+
+        let instruction =
+            Instruction::from_opcode(opcode, operands, register.clone()).map_err(|error| mnemonic.error(error))?;
+        self.function
+            .push_instruction(instruction)
+            .map_err(|error| mnemonic.error(error))?;
+
+        self.registers.push(register);
+        Ok(())
+    }
+}
+
+impl<E: Environment> FromStr for Function<E> {
+    type Err = ParseError;
+
+    ///
+    /// Parses a function from its text, as in:
+    /// ```text
+    /// function hello_world:
+    ///     input r0 as field.private;
+    ///     input r1 as field.public;
+    ///     add r0 r1 into r2;
+    ///     output r2 as field;
+    /// ```
+    ///
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut statements = split_statements(tokenize(text))?.into_iter();
+
+        // Parse the function name.
+        let function = match statements.next() {
+            Some((statement, terminator)) => match statement.as_slice() {
+                [keyword, name] if keyword.text == "function" => match (is_identifier(name.text), terminator.text) {
+                    (true, ":") => Function::new(name.text),
+                    (true, _) => return Err(terminator.error("Expected ':'")),
+                    (false, _) => return Err(name.error(format!("Invalid function name '{}'", name.text))),
+                },
+                _ => return Err(statement[0].error("Expected 'function <name>:'")),
+            },
+            None => {
+                return Err(ParseError {
+                    line: 1,
+                    column: 1,
+                    message: "Expected 'function <name>:'".to_string(),
+                });
+            }
+        };
+
+        let mut parser = Parser {
+            function,
+            registers: Vec::new(),
+        };
+
+        for (statement, terminator) in statements {
+            if statement[0].text == "function" {
+                return Err(statement[0].error("Expected one function"));
+            }
+            if terminator.text != ";" {
+                return Err(terminator.error("Expected ';'"));
+            }
+
+            match statement[0].text {
+                "input" => parser.parse_input(&statement)?,
+                "output" => parser.parse_output(&statement)?,
+                _ => parser.parse_instruction(&statement)?,
+            }
+        }
+
+        Ok(parser.function)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    type E = Circuit;
+
+    const HELLO_WORLD: &str = r"function hello_world:
+    input r0 as field.private;
+    input r1 as field.public;
+    add r0 r1 into r2;
+    output r2 as field;
+";
+
+    fn check_error(text: &str, line: usize, column: usize) {
+        match text.parse::<Function<E>>() {
+            Ok(_) => panic!("Expected an error at {}:{}", line, column),
+            Err(error) => {
+                assert_eq!((line, column), (error.line, error.column), "{}", error);
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let function: Function<E> = HELLO_WORLD.parse().unwrap();
+        assert_eq!("hello_world", function.name());
+        assert_eq!(HELLO_WORLD, function.to_string());
+
+        let text = r"function everything:
+    input r0 as boolean.constant;
+    input r1 as field.private;
+    input r2 as scalar.public;
+    input r3 as group.private;
+    store 7field into r4;
+    sub r1 r4 into r5;
+    mul r1 r5 into r6;
+    div r6 3field into r7;
+    neg r7 into r8;
+    double r8 into r9;
+    square r9 into r10;
+    inv r10 into r11;
+    is_eq r1 r11 into r12;
+    is_neq r1 r11 into r13;
+    ternary r0 r1 r11 into r14;
+    and r12 r13 into r15;
+    or r12 true into r16;
+    nand r15 r16 into r17;
+    nor r15 r16 into r18;
+    xor r17 r18 into r19;
+    not r19 into r20;
+    mul r3 r2 into r21;
+    mul 2scalar r21 into r22;
+    output r20 as boolean;
+    output r22 as group;
+";
+        let function: Function<E> = text.parse().unwrap();
+        assert_eq!(text, function.to_string());
+    }
+
+    #[test]
+    fn test_whitespace_and_comments() {
+        let text = concat!(
+            "// A comment.\n  function   hello_world :\n",
+            "\tinput r0 as field.private ;input r1 as field.public;\n\n",
+            "  add r0 r1\n into r2; // Adds.\n output r2 as field;"
+        );
+        let function: Function<E> = text.parse().unwrap();
+        assert_eq!(HELLO_WORLD, function.to_string());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let function: Function<E> = HELLO_WORLD.parse().unwrap();
+
+        let inputs = [Literal::BaseField(2u64.into()), Literal::BaseField(3u64.into())];
+        let outputs = function.evaluate(&inputs).unwrap();
+        assert_eq!(1, outputs.len());
+        assert_eq!(Literal::BaseField(5u64.into()), outputs[0].to_literal());
+
+        // Ensure the function may be evaluated again.
+        let inputs = [Literal::BaseField(4u64.into()), Literal::BaseField(3u64.into())];
+        assert_eq!(
+            Literal::BaseField(7u64.into()),
+            function.evaluate(&inputs).unwrap()[0].to_literal()
+        );
+
+        // Ensure the inputs are checked.
+        assert!(function.evaluate(&inputs[..1]).is_err());
+        assert!(function
+            .evaluate(&[Literal::Boolean(true), Literal::BaseField(3u64.into())])
+            .is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        check_error("", 1, 1);
+        check_error("input r0 as field.private;", 1, 1);
+        check_error("function 0hello:", 1, 10);
+        check_error("function hello;", 1, 15);
+        check_error("function hello:\n    input r0 as field.private", 2, 30);
+        check_error("function hello:\n    input r1 as field.private;", 2, 11);
+        check_error("function hello:\n    input r0 as u8.private;", 2, 17);
+        check_error("function hello:\n    input r0 as field.secret;", 2, 23);
+        check_error("function hello:\n    input r0 as field;", 2, 17);
+        check_error("function hello:\n    input r0 field.private;", 2, 5);
+        check_error("function hello:\n    ;", 2, 5);
+
+        let prefix = "function hello:\n    input r0 as field.private;\n    input r1 as boolean.public;\n";
+        check_error(&format!("{}    sqrt r0 into r2;", prefix), 4, 5);
+        check_error(&format!("{}    add r0 r0 r2;", prefix), 4, 5);
+        check_error(&format!("{}    add r0 r2 into r2;", prefix), 4, 12);
+        check_error(&format!("{}    add r0 5u8 into r2;", prefix), 4, 12);
+        check_error(&format!("{}    add r0 r0 into r3;", prefix), 4, 20);
+        check_error(&format!("{}    add r0 r0 r0 into r2;", prefix), 4, 5);
+        check_error(&format!("{}    add r0 r1 into r2;", prefix), 4, 5);
+        check_error(&format!("{}    output r2 as field;", prefix), 4, 12);
+        check_error(&format!("{}    output r1 as field;", prefix), 4, 18);
+        check_error(&format!("{}function hello:", prefix), 4, 1);
+    }
+
+    #[test]
+    fn test_print_renumbers_registers() {
+        // Build a function whose registers are allocated out of order.
+        let mut function = Function::<E>::new("renumbered");
+        let output = function.new_register();
+        let input = function.new_input(Type::Boolean, Mode::Private).unwrap();
+        function
+            .push_instruction(Instruction::Not(Value::Register(input), output.clone()))
+            .unwrap();
+        function.add_output(&output).unwrap();
+
+        let expected = r"function renumbered:
+    input r0 as boolean.private;
+    not r0 into r1;
+    output r1 as boolean;
+";
+        assert_eq!(expected, function.to_string());
+        assert_eq!(expected, expected.parse::<Function<E>>().unwrap().to_string());
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> fmt::Display for Function<E> {
+    ///
+    /// Prints the text of the function, which parses back into the same function.
+    ///
+    /// Registers are renumbered in the order they are defined, starting with the inputs.
+    /// Operands that are circuit values, rather than literals or registers, are printed as constants.
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let indices = self.register_indices();
+        let name = |register: &Register<E>| match indices.get(&(register.0).0) {
+            Some(index) => Ok(format!("r{}", index)),
+            None => Err(fmt::Error),
+        };
+        let type_ = |register: &Register<E>| register.to_type().map_err(|_| fmt::Error);
+
+        writeln!(f, "function {}:", self.name)?;
+
+        for (register, mode) in &self.inputs {
+            writeln!(f, "    input {} as {}.{};", name(register)?, type_(register)?, mode)?;
+        }
+
+        for instruction in &self.instructions {
+            write!(f, "    {}", instruction.mnemonic())?;
+            for operand in instruction.operands() {
+                match operand {
+                    Value::Register(register) => write!(f, " {}", name(register)?)?,
+                    value => write!(f, " {}", value.to_literal())?,
+                }
+            }
+            writeln!(f, " into {};", name(instruction.destination())?)?;
+        }
+
+        for register in &self.outputs {
+            writeln!(f, "    output {} as {};", name(register)?, type_(register)?)?;
+        }
+
+        Ok(())
+    }
+}
@@ -47,6 +47,63 @@ pub trait BaseFieldTrait:
 {
 }
 
+/// Representation of a scalar field.
+pub trait ScalarFieldTrait:
+    Add
+    + AddAssign
+    + Clone
+    + Debug
+    + Div
+    + DivAssign
+    + Double
+    + Equal
+    + Inv
+    + Mul
+    + MulAssign
+    + Neg
+    + One
+    + Square
+    + Sub
+    + SubAssign
+    + Ternary
+    + ToBits
+    + Zero
+{
+}
+
+/// Representation of an integer.
+pub trait IntegerTrait:
+    Add
+    + AddAssign
+    + AddChecked
+    + AddWrapped
+    + And
+    + Clone
+    + Compare
+    + Debug
+    + Div
+    + DivAssign
+    + DivChecked
+    + DivWrapped
+    + Equal
+    + Mul
+    + MulAssign
+    + MulChecked
+    + MulWrapped
+    + Not
+    + One
+    + Or
+    + Sub
+    + SubAssign
+    + SubChecked
+    + SubWrapped
+    + Ternary
+    + ToBits
+    + Xor
+    + Zero
+{
+}
+
 /// Representation of the zero value.
 pub trait Zero {
     type Boolean: BooleanTrait;
@@ -159,3 +216,117 @@ pub trait ToBits {
 
     fn to_bits_be(&self) -> Vec<Self::Boolean>;
 }
+
+/// Binary operator for performing `a + b`, halting on overflow.
+pub trait AddChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self + other`, halting on overflow.
+    fn add_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a + b`, wrapping on overflow.
+pub trait AddWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self + other`, wrapping around at the boundary of the type.
+    fn add_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a - b`, halting on underflow.
+pub trait SubChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self - other`, halting on underflow.
+    fn sub_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a - b`, wrapping on underflow.
+pub trait SubWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self - other`, wrapping around at the boundary of the type.
+    fn sub_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a * b`, halting on overflow.
+pub trait MulChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self * other`, halting on overflow.
+    fn mul_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a * b`, wrapping on overflow.
+pub trait MulWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self * other`, wrapping around at the boundary of the type.
+    fn mul_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a / b`, halting on overflow.
+pub trait DivChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self / other`, halting on division by zero or overflow.
+    fn div_checked(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a / b`, wrapping on overflow.
+pub trait DivWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self / other`, wrapping around at the boundary of the type, and halting on division by zero.
+    fn div_wrapped(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a << b`, halting on an out-of-range shift.
+pub trait ShlChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self << rhs`, halting if `rhs` is not less than the number of bits in `self`.
+    fn shl_checked(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a << b`, masking the shift.
+pub trait ShlWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self << rhs`, masking `rhs` to the number of bits in `self`.
+    fn shl_wrapped(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a >> b`, halting on an out-of-range shift.
+pub trait ShrChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self >> rhs`, halting if `rhs` is not less than the number of bits in `self`.
+    fn shr_checked(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for performing `a >> b`, masking the shift.
+pub trait ShrWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self >> rhs`, masking `rhs` to the number of bits in `self`.
+    fn shr_wrapped(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Trait for ordering comparisons.
+pub trait Compare<Rhs: ?Sized = Self> {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_lt(&self, other: &Rhs) -> Self::Output;
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_gt(&self, other: &Rhs) -> Self::Output;
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_le(&self, other: &Rhs) -> Self::Output;
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_ge(&self, other: &Rhs) -> Self::Output;
+}
@@ -14,10 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Address, Network, PrivateKey, ViewKey};
+use crate::{AccountError, Address, Keystore, Network, PrivateKey, ViewKey};
 
 use rand::{CryptoRng, Rng};
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 #[derive(Derivative)]
 #[derivative(Clone(bound = "N: Network"))]
@@ -47,6 +47,16 @@ impl<N: Network> Account<N> {
     pub fn address(&self) -> Address<N> {
         self.address
     }
+
+    /// Returns the account from the given keystore JSON string, decrypting it with the given password.
+    pub fn from_keystore(keystore: &str, password: &str) -> Result<Self, AccountError> {
+        Ok(Keystore::from_str(keystore)?.decrypt(password)?.into())
+    }
+
+    /// Returns a keystore JSON string of the account, encrypted under the given password.
+    pub fn to_keystore<R: Rng + CryptoRng>(&self, password: &str, rng: &mut R) -> Result<String, AccountError> {
+        Ok(Keystore::new(&self.private_key, password, rng)?.to_string())
+    }
 }
 
 impl<N: Network> From<PrivateKey<N>> for Account<N> {
@@ -17,8 +17,14 @@
 pub static ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT: &str = "AleoAccountEncryptionAndSignatureScheme0";
 pub static ACCOUNT_SEED_SK_SIG_DOMAIN: &str = "AleoAccountSeedSignatureSecretKey0";
 pub static ACCOUNT_SEED_R_SIG_DOMAIN: &str = "AleoAccountSeedSignatureRandomizer0";
+pub static ACCOUNT_SEED_CHILD_DOMAIN: &str = "AleoAccountSeedChild0";
+pub static ACCOUNT_SEED_CHAIN_CODE_DOMAIN: &str = "AleoAccountSeedChainCode0";
+pub static ACCOUNT_SEED_PASSPHRASE_DOMAIN: &str = "AleoAccountSeedPassphrase0";
+pub static SIGNED_MESSAGE_DOMAIN: &str = "AleoSignedMessage0";
 
 pub static PRIVATE_KEY_PREFIX: [u8; 11] = [127, 134, 189, 116, 210, 221, 210, 137, 145, 18, 253]; // APrivateKey1
+pub static EXTENDED_PRIVATE_KEY_PREFIX: [u8; 10] = [66, 131, 8, 205, 125, 213, 57, 205, 108, 62]; // AXPrivateKey1
 pub static _COMPUTE_KEY_PREFIX: [u8; 10] = [109, 249, 98, 224, 36, 15, 213, 187, 79, 190]; // AComputeKey1
 pub static VIEW_KEY_PREFIX: [u8; 7] = [14, 138, 223, 204, 247, 224, 122]; // AViewKey1
 pub static ADDRESS_PREFIX: &str = "aleo";
+pub static SIGNED_MESSAGE_PREFIX: &str = "aleomsg";
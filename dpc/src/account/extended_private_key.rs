@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account_format,
+    AccountError,
+    Network,
+    PrivateKey,
+    ACCOUNT_SEED_CHAIN_CODE_DOMAIN,
+    ACCOUNT_SEED_CHILD_DOMAIN,
+};
+use snarkvm_algorithms::traits::PRF;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use base58::{FromBase58, ToBase58};
+use std::{
+    fmt,
+    io::{Read, Result as IoResult, Write},
+    str::FromStr,
+};
+
+/// A derivation path of child indices from a master key, written as `m/0/5`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// Returns the child indices of the derivation path.
+    pub fn indices(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl From<Vec<u32>> for DerivationPath {
+    fn from(indices: Vec<u32>) -> Self {
+        Self(indices)
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = AccountError;
+
+    /// Reads in a derivation path string, such as `m/0/5`.
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut components = path.split('/');
+        if components.next() != Some("m") {
+            return Err(AccountError::InvalidDerivationPath(path.to_string()));
+        }
+
+        components
+            .map(|index| match index.chars().all(|c| c.is_ascii_digit()) {
+                true => index.parse::<u32>().ok(),
+                false => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(Self)
+            .ok_or_else(|| AccountError::InvalidDerivationPath(path.to_string()))
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "m")?;
+        for index in &self.0 {
+            write!(f, "/{}", index)?;
+        }
+        Ok(())
+    }
+}
+
+/// An account seed extended with a chain code, from which child account seeds are derived.
+///
+/// Each child seed is derived from its parent seed by the account seed PRF, using the parent chain code
+/// and the child index, such that a single master seed deterministically derives a tree of accounts.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct ExtendedPrivateKey<N: Network> {
+    /// The depth of the key in the derivation tree.
+    depth: u8,
+    /// The index of the key in its parent, or `0` for a master key.
+    child_index: u32,
+    /// The chain code of the key.
+    chain_code: N::ProgramScalarField,
+    /// The account seed of the key.
+    seed: N::AccountSeed,
+}
+
+impl<N: Network> ExtendedPrivateKey<N> {
+    /// Returns the master extended private key for the given master seed.
+    pub fn new(master_seed: &N::AccountSeed) -> Result<Self, AccountError> {
+        // Construct the chain code domain separator.
+        let chain_code_domain =
+            N::ProgramScalarField::from_bytes_le_mod_order(ACCOUNT_SEED_CHAIN_CODE_DOMAIN.as_bytes());
+
+        Ok(Self {
+            depth: 0,
+            child_index: 0,
+            chain_code: N::AccountSeedPRF::evaluate(master_seed, &vec![chain_code_domain])?,
+            seed: master_seed.clone(),
+        })
+    }
+
+    /// Returns the child extended private key at the given index.
+    pub fn derive_child(&self, index: u32) -> Result<Self, AccountError> {
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or(AccountError::InvalidDerivationDepth(self.depth))?;
+
+        // Construct the child seed and chain code domain separators.
+        let child_domain = N::ProgramScalarField::from_bytes_le_mod_order(ACCOUNT_SEED_CHILD_DOMAIN.as_bytes());
+        let chain_code_domain =
+            N::ProgramScalarField::from_bytes_le_mod_order(ACCOUNT_SEED_CHAIN_CODE_DOMAIN.as_bytes());
+        let index_input = N::ProgramScalarField::from(index as u64);
+
+        // Derive the child seed, and convert it into an account seed.
+        let child_seed = N::AccountSeedPRF::evaluate(&self.seed, &vec![child_domain, self.chain_code, index_input])?;
+        let child_seed = FromBytes::read_le(&child_seed.to_bytes_le()?[..])?;
+
+        Ok(Self {
+            depth,
+            child_index: index,
+            chain_code: N::AccountSeedPRF::evaluate(&self.seed, &vec![
+                chain_code_domain,
+                self.chain_code,
+                index_input,
+            ])?,
+            seed: child_seed,
+        })
+    }
+
+    /// Returns the descendant extended private key at the given derivation path from this master key.
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, AccountError> {
+        if self.depth != 0 {
+            return Err(AccountError::InvalidDerivationDepth(self.depth));
+        }
+
+        path.indices()
+            .iter()
+            .try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Returns the depth of the key in the derivation tree.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the index of the key in its parent, or `0` for a master key.
+    pub fn child_index(&self) -> u32 {
+        self.child_index
+    }
+
+    /// Returns a reference to the chain code.
+    pub fn chain_code(&self) -> &N::ProgramScalarField {
+        &self.chain_code
+    }
+
+    /// Returns a reference to the account seed.
+    pub fn seed(&self) -> &N::AccountSeed {
+        &self.seed
+    }
+
+    /// Returns the account private key.
+    pub fn to_private_key(&self) -> PrivateKey<N> {
+        PrivateKey::from(&self.seed)
+    }
+}
+
+impl<N: Network> FromBytes for ExtendedPrivateKey<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let depth = FromBytes::read_le(&mut reader)?;
+        let child_index = FromBytes::read_le(&mut reader)?;
+        let chain_code = FromBytes::read_le(&mut reader)?;
+        let seed = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self {
+            depth,
+            child_index,
+            chain_code,
+            seed,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for ExtendedPrivateKey<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.depth.write_le(&mut writer)?;
+        self.child_index.write_le(&mut writer)?;
+        self.chain_code.write_le(&mut writer)?;
+        self.seed.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromStr for ExtendedPrivateKey<N> {
+    type Err = AccountError;
+
+    /// Reads in an extended private key string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s.from_base58()?;
+        if data.len() != 79 {
+            return Err(AccountError::InvalidByteLength(data.len()));
+        }
+
+        if data[0..10] != account_format::EXTENDED_PRIVATE_KEY_PREFIX {
+            return Err(AccountError::InvalidPrefixBytes(data[0..10].to_vec()));
+        }
+
+        Ok(FromBytes::read_le(&data[10..79])?)
+    }
+}
+
+impl<N: Network> fmt::Display for ExtendedPrivateKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut extended_private_key = [0u8; 79];
+        extended_private_key[0..10].copy_from_slice(&account_format::EXTENDED_PRIVATE_KEY_PREFIX);
+        self.write_le(&mut extended_private_key[10..79])
+            .expect("extended private key formatting failed");
+
+        write!(f, "{}", extended_private_key.to_base58())
+    }
+}
+
+impl<N: Network> fmt::Debug for ExtendedPrivateKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ExtendedPrivateKey {{ depth: {:?}, child_index: {:?}, seed: {:?} }}",
+            self.depth, self.child_index, self.seed
+        )
+    }
+}
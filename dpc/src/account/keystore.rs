@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountError, Address, Network, PrivateKey};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use chacha20poly1305::{
+    aead::{Aead, NewAead, Payload},
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+};
+use rand::{CryptoRng, Rng};
+use scrypt::{scrypt, Params};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// The version of the keystore format.
+pub const KEYSTORE_VERSION: u8 = 1;
+
+/// The name of the key derivation function used by the keystore.
+const KDF_NAME: &str = "scrypt";
+/// The name of the cipher used by the keystore.
+const CIPHER_NAME: &str = "chacha20poly1305";
+
+/// The number of bytes in the key derivation salt.
+const SALT_SIZE_IN_BYTES: usize = 32;
+/// The number of bytes in the cipher nonce.
+const NONCE_SIZE_IN_BYTES: usize = 12;
+
+/// The parameters of the scrypt key derivation function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeystoreKdf {
+    name: String,
+    log_n: u8,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+/// The parameters of the authenticated cipher.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeystoreCipher {
+    name: String,
+    nonce: String,
+}
+
+/// A password-encrypted account private key.
+///
+/// The account seed is encrypted with ChaCha20-Poly1305, under a key derived from the password with scrypt.
+/// The version, network ID, address, and key derivation parameters of the keystore are authenticated
+/// as associated data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    version: u8,
+    network_id: u16,
+    address: String,
+    kdf: KeystoreKdf,
+    cipher: KeystoreCipher,
+    ciphertext: String,
+}
+
+impl Keystore {
+    /// The default scrypt cost parameter, as log2(N).
+    pub const DEFAULT_LOG_N: u8 = 15;
+    /// The default scrypt parallelization parameter.
+    pub const DEFAULT_P: u32 = 1;
+    /// The default scrypt block size parameter.
+    pub const DEFAULT_R: u32 = 8;
+    /// The maximum scrypt cost parameter, as log2(N), accepted when decrypting a keystore.
+    pub const MAX_LOG_N: u8 = 20;
+    /// The maximum scrypt parallelization parameter accepted when decrypting a keystore.
+    pub const MAX_P: u32 = 16;
+    /// The maximum scrypt block size parameter accepted when decrypting a keystore.
+    pub const MAX_R: u32 = 16;
+    /// The minimum scrypt cost parameter, as log2(N), accepted when decrypting a keystore.
+    pub const MIN_LOG_N: u8 = 14;
+    /// The minimum scrypt parallelization parameter accepted when decrypting a keystore.
+    pub const MIN_P: u32 = 1;
+    /// The minimum scrypt block size parameter accepted when decrypting a keystore.
+    pub const MIN_R: u32 = 8;
+
+    /// Returns a new keystore, encrypting the given private key under the given password.
+    pub fn new<N: Network, R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        password: &str,
+        rng: &mut R,
+    ) -> Result<Self, AccountError> {
+        let salt: [u8; SALT_SIZE_IN_BYTES] = rng.gen();
+        let nonce: [u8; NONCE_SIZE_IN_BYTES] = rng.gen();
+
+        let mut keystore = Self {
+            version: KEYSTORE_VERSION,
+            network_id: N::NETWORK_ID,
+            address: private_key.to_address().to_string(),
+            kdf: KeystoreKdf {
+                name: KDF_NAME.to_string(),
+                log_n: Self::DEFAULT_LOG_N,
+                r: Self::DEFAULT_R,
+                p: Self::DEFAULT_P,
+                salt: hex::encode(salt),
+            },
+            cipher: KeystoreCipher {
+                name: CIPHER_NAME.to_string(),
+                nonce: hex::encode(nonce),
+            },
+            ciphertext: String::new(),
+        };
+
+        let key = keystore.derive_key(password)?;
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .encrypt(Nonce::from_slice(&nonce), Payload {
+                msg: &private_key.seed.to_bytes_le()?,
+                aad: &keystore.to_associated_data(),
+            })
+            .map_err(|_| AccountError::Crate("chacha20poly1305", "Failed to encrypt the keystore".to_string()))?;
+        keystore.ciphertext = hex::encode(ciphertext);
+
+        Ok(keystore)
+    }
+
+    /// Returns the private key in the keystore, decrypting it with the given password.
+    pub fn decrypt<N: Network>(&self, password: &str) -> Result<PrivateKey<N>, AccountError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(AccountError::InvalidKeystoreVersion(self.version));
+        }
+        if self.network_id != N::NETWORK_ID {
+            return Err(AccountError::InvalidKeystoreNetwork(N::NETWORK_ID, self.network_id));
+        }
+        if self.kdf.name != KDF_NAME || self.cipher.name != CIPHER_NAME {
+            return Err(AccountError::Message(format!(
+                "Unsupported keystore algorithms: {} and {}",
+                self.kdf.name, self.cipher.name
+            )));
+        }
+
+        let nonce = decode_hex::<NONCE_SIZE_IN_BYTES>(&self.cipher.nonce)?;
+        let ciphertext = hex::decode(&self.ciphertext).map_err(|error| AccountError::Message(error.to_string()))?;
+
+        let key = self.derive_key(password)?;
+        let seed = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .decrypt(Nonce::from_slice(&nonce), Payload {
+                msg: &ciphertext,
+                aad: &self.to_associated_data(),
+            })
+            .map_err(|_| AccountError::InvalidKeystorePassword)?;
+
+        // Ensure the decrypted private key corresponds to the address of the keystore.
+        let private_key = PrivateKey::from(&FromBytes::read_le(&seed[..])?);
+        if private_key.to_address() != Address::from_str(&self.address)? {
+            return Err(AccountError::Message(
+                "Keystore address does not match its private key".to_string(),
+            ));
+        }
+
+        Ok(private_key)
+    }
+
+    /// Returns the version of the keystore format.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the network ID of the keystore.
+    pub fn network_id(&self) -> u16 {
+        self.network_id
+    }
+
+    /// Returns the address of the account in the keystore.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Returns the cipher key, derived from the given password.
+    fn derive_key(&self, password: &str) -> Result<[u8; 32], AccountError> {
+        // Ensure the parameters are bounded, as the cost of scrypt is exponential in `log_n`,
+        // and ensure they are not weakened below the minimum cost of a brute-force search of the password.
+        if !(Self::MIN_LOG_N..=Self::MAX_LOG_N).contains(&self.kdf.log_n)
+            || !(Self::MIN_R..=Self::MAX_R).contains(&self.kdf.r)
+            || !(Self::MIN_P..=Self::MAX_P).contains(&self.kdf.p)
+        {
+            return Err(AccountError::InvalidKeystoreKdf(format!(
+                "log_n = {}, r = {}, p = {} is not within log_n = {}..={}, r = {}..={}, p = {}..={}",
+                self.kdf.log_n,
+                self.kdf.r,
+                self.kdf.p,
+                Self::MIN_LOG_N,
+                Self::MAX_LOG_N,
+                Self::MIN_R,
+                Self::MAX_R,
+                Self::MIN_P,
+                Self::MAX_P
+            )));
+        }
+
+        let salt = decode_hex::<SALT_SIZE_IN_BYTES>(&self.kdf.salt)?;
+        let params = Params::new(self.kdf.log_n, self.kdf.r, self.kdf.p)
+            .map_err(|error| AccountError::InvalidKeystoreKdf(error.to_string()))?;
+
+        let mut key = [0u8; 32];
+        scrypt(password.as_bytes(), &salt, &params, &mut key)
+            .map_err(|error| AccountError::Crate("scrypt", error.to_string()))?;
+        Ok(key)
+    }
+
+    /// Returns the associated data authenticated by the cipher.
+    fn to_associated_data(&self) -> Vec<u8> {
+        let mut associated_data = vec![self.version];
+        associated_data.extend_from_slice(&self.network_id.to_le_bytes());
+        associated_data.extend_from_slice(self.address.as_bytes());
+        associated_data.push(self.kdf.log_n);
+        associated_data.extend_from_slice(&self.kdf.r.to_le_bytes());
+        associated_data.extend_from_slice(&self.kdf.p.to_le_bytes());
+        associated_data.extend_from_slice(self.kdf.salt.as_bytes());
+        associated_data
+    }
+}
+
+impl FromStr for Keystore {
+    type Err = AccountError;
+
+    /// Reads in a keystore JSON string.
+    fn from_str(keystore: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(keystore).map_err(|error| AccountError::Crate("serde_json", error.to_string()))
+    }
+}
+
+impl fmt::Display for Keystore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err::<fmt::Error, _>(serde::ser::Error::custom)?
+        )
+    }
+}
+
+/// Returns the bytes of the given hex string, ensuring it contains exactly `SIZE` bytes.
+fn decode_hex<const SIZE: usize>(input: &str) -> Result<[u8; SIZE], AccountError> {
+    let mut buffer = [0u8; SIZE];
+    hex::decode_to_slice(input, &mut buffer).map_err(|error| AccountError::Message(error.to_string()))?;
+    Ok(buffer)
+}
@@ -28,9 +28,21 @@ pub use address::*;
 pub mod compute_key;
 pub use compute_key::*;
 
+pub mod extended_private_key;
+pub use extended_private_key::*;
+
+pub mod keystore;
+pub use keystore::*;
+
 pub mod private_key;
 pub use private_key::*;
 
+pub mod signed_message;
+pub use signed_message::*;
+
+pub mod vanity;
+pub use vanity::*;
+
 pub mod view_key;
 pub use view_key::*;
 
@@ -20,6 +20,7 @@ use crate::{
     Address,
     ComputeKey,
     Network,
+    ACCOUNT_SEED_PASSPHRASE_DOMAIN,
     ACCOUNT_SEED_R_SIG_DOMAIN,
     ACCOUNT_SEED_SK_SIG_DOMAIN,
 };
@@ -28,6 +29,8 @@ use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 
 use base58::{FromBase58, ToBase58};
+use bip39::{Language, Mnemonic};
+use blake2::{Blake2s, Digest};
 use rand::{CryptoRng, Rng};
 use std::{fmt, str::FromStr};
 
@@ -38,7 +41,7 @@ use std::{fmt, str::FromStr};
     Eq(bound = "N: Network")
 )]
 pub struct PrivateKey<N: Network> {
-    seed: N::AccountSeed,
+    pub(super) seed: N::AccountSeed,
     pub(super) sk_sig: N::ProgramScalarField,
     pub(super) r_sig: N::ProgramScalarField,
 }
@@ -50,6 +53,57 @@ impl<N: Network> PrivateKey<N> {
         Self::from(&N::AccountSeed::rand(rng))
     }
 
+    /// Returns the account private key from the mnemonic phrase of its account seed,
+    /// in the BIP39 English word list.
+    ///
+    /// This is not BIP39 seed derivation: the mnemonic phrase encodes the account seed itself as its entropy,
+    /// rather than deriving a seed with PBKDF2-HMAC-SHA512, so that it may be restored from `to_mnemonic`.
+    /// As such, the phrase is not interchangeable with BIP39 wallets.
+    ///
+    /// An empty passphrase restores the private key that the mnemonic phrase was created from,
+    /// while a non-empty passphrase derives a distinct private key from the account seed PRF.
+    pub fn from_mnemonic(words: &str, passphrase: &str) -> Result<Self, AccountError> {
+        let mnemonic = Mnemonic::parse_in(Language::English, words).map_err(|error| match error {
+            bip39::Error::UnknownWord(index) => {
+                AccountError::InvalidMnemonicWord(words.split_whitespace().nth(index).unwrap_or_default().to_string())
+            }
+            bip39::Error::BadWordCount(count) => AccountError::InvalidMnemonicWordCount(count),
+            bip39::Error::InvalidChecksum => AccountError::InvalidMnemonicChecksum,
+            error => AccountError::Crate("bip39", error.to_string()),
+        })?;
+
+        // Recover the account seed from the mnemonic entropy.
+        let entropy = mnemonic.to_entropy();
+        if entropy.len() != N::AccountSeed::default().to_bytes_le()?.len() {
+            return Err(AccountError::InvalidMnemonicWordCount(mnemonic.word_count()));
+        }
+        let seed: N::AccountSeed = FromBytes::read_le(&entropy[..]).map_err(|_| AccountError::InvalidMnemonicSeed)?;
+
+        match passphrase.is_empty() {
+            true => Ok(Self::from(&seed)),
+            false => {
+                // Construct the passphrase domain separator and input.
+                let passphrase_domain =
+                    N::ProgramScalarField::from_bytes_le_mod_order(ACCOUNT_SEED_PASSPHRASE_DOMAIN.as_bytes());
+                let passphrase_input =
+                    N::ProgramScalarField::from_bytes_le_mod_order(&Blake2s::digest(passphrase.as_bytes()));
+
+                // Derive the account seed for the passphrase.
+                let seed = N::AccountSeedPRF::evaluate(&seed, &vec![passphrase_domain, passphrase_input])?;
+                Ok(Self::from(&FromBytes::read_le(&seed.to_bytes_le()?[..])?))
+            }
+        }
+    }
+
+    /// Returns the mnemonic phrase of the account seed, in the BIP39 English word list.
+    ///
+    /// The account seed is encoded as BIP39 entropy, so the phrase is only restored by `from_mnemonic`.
+    pub fn to_mnemonic(&self) -> Result<String, AccountError> {
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &self.seed.to_bytes_le()?)
+            .map_err(|error| AccountError::Crate("bip39", error.to_string()))?;
+        Ok(mnemonic.to_string())
+    }
+
     /// Returns `true` if the private key is well-formed. Otherwise, returns `false`.
     pub fn is_valid(&self) -> bool {
         self.to_compute_key().is_valid()
@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account_format, AccountError, Address, Network, PrivateKey};
+use snarkvm_utilities::{
+    fmt,
+    io::{Read, Result as IoResult, Write},
+    str::FromStr,
+    FromBytes,
+    FromBytesDeserializer,
+    ToBytes,
+    ToBytesSerializer,
+};
+
+use bech32::{self, FromBase32, ToBase32};
+use rand::{CryptoRng, Rng};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// An off-chain message, signed by an account private key.
+///
+/// The signature is computed over the domain separator, the network ID, and the length of the
+/// message, followed by the message, so it cannot be replayed as a signature for another network,
+/// or as a signature on raw bytes, such as the signature of a `Request`.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct SignedMessage<N: Network> {
+    signer: Address<N>,
+    message: Vec<u8>,
+    signature: N::AccountSignature,
+}
+
+impl<N: Network> SignedMessage<N> {
+    /// The maximum number of bytes in a signed message.
+    pub const MAX_MESSAGE_SIZE_IN_BYTES: usize = 1 << 16;
+
+    /// Signs the given message with the given account private key.
+    pub fn sign<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self, AccountError> {
+        if message.len() > Self::MAX_MESSAGE_SIZE_IN_BYTES {
+            return Err(AccountError::InvalidByteLength(message.len()));
+        }
+
+        Ok(Self {
+            signer: private_key.to_address(),
+            message: message.to_vec(),
+            signature: private_key.sign(&Self::to_signature_message(message), rng)?,
+        })
+    }
+
+    /// Returns `true` if the signature is valid for the message and the signer.
+    pub fn verify(&self) -> Result<bool, AccountError> {
+        self.signer
+            .verify_signature(&Self::to_signature_message(&self.message), &self.signature)
+    }
+
+    /// Returns the address of the signer.
+    pub fn signer(&self) -> Address<N> {
+        self.signer
+    }
+
+    /// Returns a reference to the message.
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    /// Returns a reference to the signature.
+    pub fn signature(&self) -> &N::AccountSignature {
+        &self.signature
+    }
+
+    /// Returns the bytes signed for the given message, as (domain || network ID || message length || message).
+    fn to_signature_message(message: &[u8]) -> Vec<u8> {
+        let domain = account_format::SIGNED_MESSAGE_DOMAIN.as_bytes();
+
+        let mut signature_message = Vec::with_capacity(domain.len() + 2 + 8 + message.len());
+        signature_message.extend_from_slice(domain);
+        signature_message.extend_from_slice(&N::NETWORK_ID.to_le_bytes());
+        signature_message.extend_from_slice(&(message.len() as u64).to_le_bytes());
+        signature_message.extend_from_slice(message);
+        signature_message
+    }
+}
+
+impl<N: Network> FromBytes for SignedMessage<N> {
+    /// Reads in a signed message buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let network_id: u16 = FromBytes::read_le(&mut reader)?;
+        if network_id != N::NETWORK_ID {
+            return Err(AccountError::Message(format!("Signed message is for network {}", network_id)).into());
+        }
+
+        let signer = FromBytes::read_le(&mut reader)?;
+
+        let num_bytes: u32 = FromBytes::read_le(&mut reader)?;
+        if num_bytes as usize > Self::MAX_MESSAGE_SIZE_IN_BYTES {
+            return Err(AccountError::InvalidByteLength(num_bytes as usize).into());
+        }
+        let mut message = vec![0u8; num_bytes as usize];
+        reader.read_exact(&mut message)?;
+
+        let signature = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self {
+            signer,
+            message,
+            signature,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for SignedMessage<N> {
+    /// Writes a signed message buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        N::NETWORK_ID.write_le(&mut writer)?;
+        self.signer.write_le(&mut writer)?;
+        (self.message.len() as u32).write_le(&mut writer)?;
+        writer.write_all(&self.message)?;
+        self.signature.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromStr for SignedMessage<N> {
+    type Err = AccountError;
+
+    /// Reads in a signed message string.
+    fn from_str(signed_message: &str) -> Result<Self, Self::Err> {
+        let (hrp, data, variant) = bech32::decode(signed_message)?;
+        if hrp != account_format::SIGNED_MESSAGE_PREFIX {
+            return Err(AccountError::InvalidPrefix(hrp));
+        }
+        if variant != bech32::Variant::Bech32m {
+            return Err(AccountError::InvalidVariant);
+        }
+        if data.is_empty() {
+            return Err(AccountError::InvalidByteLength(0));
+        }
+
+        let buffer = Vec::from_base32(&data)?;
+        Ok(Self::read_le(&buffer[..])?)
+    }
+}
+
+impl<N: Network> fmt::Display for SignedMessage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Convert the signed message to bytes.
+        let signed_message = self.to_bytes_le().expect("Failed to write signed message as bytes");
+
+        bech32::encode(
+            account_format::SIGNED_MESSAGE_PREFIX,
+            signed_message.to_base32(),
+            bech32::Variant::Bech32m,
+        )
+        .expect("Failed to encode in bech32m")
+        .fmt(f)
+    }
+}
+
+impl<N: Network> Serialize for SignedMessage<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for SignedMessage<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "signed message"),
+        }
+    }
+}
@@ -290,18 +290,32 @@ mod testnet1 {
 
 #[cfg(test)]
 mod testnet2 {
-    use crate::{testnet2::Testnet2, Account, Address, Network, PrivateKey, ViewKey};
+    use crate::{
+        testnet2::Testnet2,
+        Account,
+        AccountError,
+        Address,
+        DerivationPath,
+        ExtendedPrivateKey,
+        Network,
+        PrivateKey,
+        SignedMessage,
+        VanityPattern,
+        ViewKey,
+        KEYSTORE_VERSION,
+    };
     use snarkvm_algorithms::prelude::*;
     use snarkvm_curves::AffineCurve;
-    use snarkvm_utilities::{FromBytes, ToBytes};
+    use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 
     use rand::{thread_rng, Rng, SeedableRng};
     use rand_chacha::ChaChaRng;
-    use std::str::FromStr;
+    use std::{str::FromStr, sync::atomic::AtomicBool};
 
     const ALEO_TESTNET2_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
     const ALEO_TESTNET2_VIEW_KEY: &str = "AViewKey1iAf6a7fv6ELA4ECwAth1hDNUJJNNoWNThmREjpybqder";
     const ALEO_TESTNET2_ADDRESS: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+    const ALEO_TESTNET2_MNEMONIC: &str = "good embody coyote crowd cage evoke broken scare tattoo forget year purpose embody region course link lift ridge expect punch together venue day humble";
 
     const ITERATIONS: usize = 1000;
 
@@ -561,4 +575,288 @@ mod testnet2 {
             );
         }
     }
+
+    #[test]
+    fn test_private_key_mnemonic() {
+        let private_key = PrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap();
+        let mnemonic = private_key.to_mnemonic().unwrap();
+        assert_eq!(ALEO_TESTNET2_MNEMONIC, mnemonic);
+        assert_eq!(24, mnemonic.split_whitespace().count());
+
+        // Ensure the mnemonic restores the private key with an empty passphrase.
+        let candidate = PrivateKey::<Testnet2>::from_mnemonic(ALEO_TESTNET2_MNEMONIC, "").unwrap();
+        assert_eq!(ALEO_TESTNET2_PRIVATE_KEY, candidate.to_string());
+
+        // Ensure a passphrase derives a distinct, deterministic private key.
+        let with_passphrase = PrivateKey::<Testnet2>::from_mnemonic(ALEO_TESTNET2_MNEMONIC, "passphrase").unwrap();
+        assert_ne!(private_key, with_passphrase);
+        assert_eq!(
+            with_passphrase,
+            PrivateKey::<Testnet2>::from_mnemonic(ALEO_TESTNET2_MNEMONIC, "passphrase").unwrap()
+        );
+
+        // Ensure random private keys round-trip through their mnemonic.
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<Testnet2>::new(&mut thread_rng());
+            let mnemonic = private_key.to_mnemonic().unwrap();
+            assert_eq!(private_key, PrivateKey::from_mnemonic(&mnemonic, "").unwrap());
+        }
+    }
+
+    #[test]
+    fn test_private_key_from_invalid_mnemonic() {
+        let words: Vec<&str> = ALEO_TESTNET2_MNEMONIC.split_whitespace().collect();
+
+        // Ensure an unknown word is rejected.
+        let mut invalid = words.clone();
+        invalid[3] = "aleo";
+        assert!(matches!(
+            PrivateKey::<Testnet2>::from_mnemonic(&invalid.join(" "), ""),
+            Err(AccountError::InvalidMnemonicWord(word)) if word == "aleo"
+        ));
+
+        // Ensure a bad checksum is rejected.
+        let mut invalid = words.clone();
+        invalid.swap(0, 1);
+        assert!(matches!(
+            PrivateKey::<Testnet2>::from_mnemonic(&invalid.join(" "), ""),
+            Err(AccountError::InvalidMnemonicChecksum)
+        ));
+
+        // Ensure a mnemonic with an incorrect number of words is rejected.
+        assert!(matches!(
+            PrivateKey::<Testnet2>::from_mnemonic(&words[..23].join(" "), ""),
+            Err(AccountError::InvalidMnemonicWordCount(23))
+        ));
+        let twelve_words =
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(matches!(
+            PrivateKey::<Testnet2>::from_mnemonic(twelve_words, ""),
+            Err(AccountError::InvalidMnemonicWordCount(12))
+        ));
+
+        // Ensure entropy that is not a valid account seed is rejected.
+        let zoo = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote";
+        assert!(matches!(
+            PrivateKey::<Testnet2>::from_mnemonic(zoo, ""),
+            Err(AccountError::InvalidMnemonicSeed)
+        ));
+    }
+
+    #[test]
+    fn test_account_keystore() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::from(PrivateKey::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap());
+
+        let keystore = account.to_keystore("password", rng).unwrap();
+        let candidate = Account::<Testnet2>::from_keystore(&keystore, "password").unwrap();
+        assert_eq!(ALEO_TESTNET2_PRIVATE_KEY, candidate.private_key().to_string());
+        assert_eq!(ALEO_TESTNET2_ADDRESS, candidate.address().to_string());
+
+        // Ensure the keystore records the network ID and address, and does not contain the private key.
+        let json: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+        assert_eq!(KEYSTORE_VERSION as u64, json["version"]);
+        assert_eq!(Testnet2::NETWORK_ID as u64, json["network_id"]);
+        assert_eq!(ALEO_TESTNET2_ADDRESS, json["address"]);
+        assert!(!keystore.contains(ALEO_TESTNET2_PRIVATE_KEY));
+
+        // Ensure an incorrect password is rejected.
+        assert!(matches!(
+            Account::<Testnet2>::from_keystore(&keystore, "incorrect"),
+            Err(AccountError::InvalidKeystorePassword)
+        ));
+
+        // Ensure a keystore from another network is rejected.
+        assert!(matches!(
+            Account::<crate::testnet1::Testnet1>::from_keystore(&keystore, "password"),
+            Err(AccountError::InvalidKeystoreNetwork(1, 2))
+        ));
+
+        // Ensure tampering with the authenticated fields is rejected.
+        let mut tampered = json.clone();
+        tampered["network_id"] = serde_json::json!(1);
+        assert!(Account::<crate::testnet1::Testnet1>::from_keystore(&tampered.to_string(), "password").is_err());
+        let mut tampered = json.clone();
+        tampered["version"] = serde_json::json!(2);
+        assert!(matches!(
+            Account::<Testnet2>::from_keystore(&tampered.to_string(), "password"),
+            Err(AccountError::InvalidKeystoreVersion(2))
+        ));
+
+        // Ensure the key derivation parameters are bounded.
+        for (parameter, value) in [("log_n", 64), ("r", u32::MAX), ("p", u32::MAX)] {
+            let mut tampered = json.clone();
+            tampered["kdf"][parameter] = serde_json::json!(value);
+            assert!(matches!(
+                Account::<Testnet2>::from_keystore(&tampered.to_string(), "password"),
+                Err(AccountError::InvalidKeystoreKdf(_))
+            ));
+        }
+
+        // Ensure the key derivation parameters may not be weakened below their minimums.
+        for (parameter, value) in [("log_n", 1), ("log_n", 13), ("r", 1), ("p", 0)] {
+            let mut tampered = json.clone();
+            tampered["kdf"][parameter] = serde_json::json!(value);
+            assert!(matches!(
+                Account::<Testnet2>::from_keystore(&tampered.to_string(), "password"),
+                Err(AccountError::InvalidKeystoreKdf(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_signed_message() {
+        let rng = &mut thread_rng();
+        let private_key = PrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap();
+        let message = b"Sign in to example.com";
+
+        let signed_message = SignedMessage::sign(&private_key, message, rng).unwrap();
+        assert_eq!(ALEO_TESTNET2_ADDRESS, signed_message.signer().to_string());
+        assert_eq!(&message[..], signed_message.message());
+        assert!(signed_message.verify().unwrap());
+
+        // Ensure the string encoding round trips.
+        let encoded = signed_message.to_string();
+        assert!(encoded.starts_with("aleomsg1"));
+        let candidate = SignedMessage::<Testnet2>::from_str(&encoded).unwrap();
+        assert_eq!(signed_message, candidate);
+        assert!(candidate.verify().unwrap());
+
+        // Ensure the serde encodings round trip.
+        let candidate: SignedMessage<Testnet2> =
+            serde_json::from_str(&serde_json::to_string(&signed_message).unwrap()).unwrap();
+        assert_eq!(signed_message, candidate);
+        let candidate: SignedMessage<Testnet2> =
+            bincode::deserialize(&bincode::serialize(&signed_message).unwrap()).unwrap();
+        assert_eq!(signed_message, candidate);
+
+        // Ensure a tampered message is rejected.
+        let mut bytes = signed_message.to_bytes_le().unwrap();
+        let message_index = bytes.len() - Testnet2::SIGNATURE_SIZE_IN_BYTES - 1;
+        bytes[message_index] ^= 1;
+        let tampered = SignedMessage::<Testnet2>::read_le(&bytes[..]).unwrap();
+        assert!(!tampered.verify().unwrap());
+
+        // Ensure the signature is domain separated from a signature on the raw message.
+        let address = private_key.to_address();
+        assert!(!address.verify_signature(message, signed_message.signature()).unwrap());
+
+        // Ensure a signed message from another network is rejected.
+        assert!(SignedMessage::<crate::testnet1::Testnet1>::from_str(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_vanity_pattern() {
+        let address = Address::<Testnet2>::from_str(ALEO_TESTNET2_ADDRESS).unwrap();
+
+        // Ensure patterns are matched against the data and checksum of the address.
+        assert!(VanityPattern::<Testnet2>::new("d5hg", "").unwrap().is_match(&address));
+        assert!(VanityPattern::<Testnet2>::new("", "ddah").unwrap().is_match(&address));
+        assert!(VanityPattern::<Testnet2>::new("D5H", "DAH").unwrap().is_match(&address));
+        assert!(!VanityPattern::<Testnet2>::new("ale", "").unwrap().is_match(&address));
+        assert!(!VanityPattern::<Testnet2>::new("d5hg", "dda")
+            .unwrap()
+            .is_match(&address));
+
+        // Ensure invalid patterns are rejected.
+        assert!(matches!(
+            VanityPattern::<Testnet2>::new("aleo1d5hg", ""),
+            Err(AccountError::InvalidVanityPattern(_))
+        ));
+        assert!(matches!(
+            VanityPattern::<Testnet2>::new("b", ""),
+            Err(AccountError::InvalidVanityPattern(_))
+        ));
+        assert!(matches!(
+            VanityPattern::<Testnet2>::new("", "1"),
+            Err(AccountError::InvalidVanityPattern(_))
+        ));
+        assert!(matches!(
+            VanityPattern::<Testnet2>::new("", "qqqqqqq"),
+            Err(AccountError::InvalidVanityPattern(_))
+        ));
+
+        // Ensure the difficulty estimate accounts for every character in the pattern.
+        let pattern = VanityPattern::<Testnet2>::new("aa", "z").unwrap();
+        assert_eq!(32768.0, pattern.difficulty());
+        assert_eq!(0.0, pattern.probability(0));
+        assert!((pattern.probability(32768) - 0.632).abs() < 0.001);
+
+        // Ensure the search finds a matching private key.
+        let pattern = VanityPattern::<Testnet2>::new("q", "").unwrap();
+        let private_key = pattern.search(&AtomicBool::new(false), |_| ()).unwrap();
+        assert!(private_key.to_address().to_string().starts_with("aleo1q"));
+
+        // Ensure the search stops when terminated.
+        let pattern = VanityPattern::<Testnet2>::new("qqqqqqqqqqqqqqqq", "").unwrap();
+        assert!(pattern.search(&AtomicBool::new(true), |_| ()).is_none());
+    }
+
+    #[test]
+    fn test_extended_private_key_derivation() {
+        const EXPECTED_VECTORS: [(&str, &str, &str); 4] = [
+            (
+                "m",
+                "AXPrivateKey13KzaBLxWsJCwBoZUtm1AXwW1r1dX8SSBbBkFu7ZPZaWEZCqR7547ZxNJE5XRCgMhztCfPmkWh12CAcUhBf4SiKgoW4AKGwk",
+                ALEO_TESTNET2_PRIVATE_KEY,
+            ),
+            (
+                "m/0",
+                "AXPrivateKey13La4ijNuLNmD2SMjSgA6Jj3PE12wbWJDuQtAABsH6VoK5PoLuFPkJhep5iFHMu7Hpsg4D3C9jFiPoMvzCuSjJcEPtDFCFrR",
+                "APrivateKey1zkp1tMiTR6YAFrN9Ags93fdTcQgk7nwaPxvF2K5vmNsBK69",
+            ),
+            (
+                "m/0/5",
+                "AXPrivateKey13MADCepk4RVSNPX4QtRi7EKuHt2W2uV3oPvkc3mnGHw2H5sJaGfheG9FsqcKojmHDf843qgoqghwfNB8DuB45fqHwQ8DpAm",
+                "APrivateKey1zkpCKHa2FCm4LnDnoJxqZzx8NEe8hfjoYGtwBFxtEMXuFu3",
+            ),
+            (
+                "m/1",
+                "AXPrivateKey13LaCJqao9HE5JRu1oy62EFVA13pJrUFYYhuh7aPqXJCj4v4ADARLJGVPQgeYbrhDaeQeCjM1tz7ZbVa3gY6AqkzJh9A7817",
+                "APrivateKey1zkpG6z9h9dopoAPx9kQDKh32MHBBNs5pVahiewevd8EtEoT",
+            ),
+        ];
+
+        let mut rng = ChaChaRng::seed_from_u64(1231275789u64);
+        let master_seed: <Testnet2 as Network>::AccountSeed = UniformRand::rand(&mut rng);
+        let master = ExtendedPrivateKey::<Testnet2>::new(&master_seed).unwrap();
+
+        for (path, expected_extended_private_key, expected_private_key) in EXPECTED_VECTORS.iter() {
+            let path = DerivationPath::from_str(path).unwrap();
+            let extended_private_key = master.derive_path(&path).unwrap();
+            assert_eq!(path.indices().len(), extended_private_key.depth() as usize);
+            assert_eq!(*expected_extended_private_key, extended_private_key.to_string());
+            assert_eq!(*expected_private_key, extended_private_key.to_private_key().to_string());
+
+            // Ensure the extended private key string round-trips.
+            let candidate = ExtendedPrivateKey::<Testnet2>::from_str(expected_extended_private_key).unwrap();
+            assert_eq!(extended_private_key, candidate);
+        }
+
+        // Ensure deriving children one at a time matches deriving by path.
+        let child = master.derive_child(0).unwrap().derive_child(5).unwrap();
+        assert_eq!(EXPECTED_VECTORS[2].1, child.to_string());
+        assert_eq!(5, child.child_index());
+
+        // Ensure a path may only be derived from a master key.
+        assert!(child.derive_path(&DerivationPath::from(vec![0])).is_err());
+    }
+
+    #[test]
+    fn test_derivation_path_from_str() {
+        let path = DerivationPath::from_str("m/0/5").unwrap();
+        assert_eq!(&[0, 5], path.indices());
+        assert_eq!("m/0/5", path.to_string());
+        assert_eq!("m", DerivationPath::from_str("m").unwrap().to_string());
+
+        for path in ["", "0/5", "M/0", "m/", "m//5", "m/-1", "m/+1", "m/0'", "m/4294967296"] {
+            assert!(DerivationPath::from_str(path).is_err(), "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_extended_private_key_from_invalid_str() {
+        assert!(ExtendedPrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).is_err());
+        assert!(ExtendedPrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_VIEW_KEY).is_err());
+    }
 }
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account_format, AccountError, Address, Network, PrivateKey};
+use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
+
+use rand::thread_rng;
+use rayon::iter::{repeat, ParallelIterator};
+use std::{
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+};
+
+/// The bech32 character set, in order of value.
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// A pattern for the bech32m string of an account address, used to search for vanity addresses.
+///
+/// The prefix is matched against the characters after the `aleo1` human-readable part, and the
+/// suffix is matched against the last characters of the address, which are the checksum.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct VanityPattern<N: Network> {
+    prefix: String,
+    suffix: String,
+    #[derivative(Debug = "ignore")]
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> VanityPattern<N> {
+    /// The maximum number of characters in a prefix pattern.
+    pub const MAX_PREFIX_LENGTH: usize = 16;
+    /// The maximum number of characters in a suffix pattern.
+    /// Longer suffixes would overlap the last characters of the address data, which are not uniformly distributed.
+    pub const MAX_SUFFIX_LENGTH: usize = 6;
+    /// The number of attempts between each progress report during a search.
+    pub const PROGRESS_INTERVAL: u64 = 1000;
+
+    /// Returns a new vanity pattern for the given prefix and suffix, either of which may be empty.
+    pub fn new(prefix: &str, suffix: &str) -> Result<Self, AccountError> {
+        // Bech32 strings are case-insensitive, and are encoded in lowercase.
+        let prefix = prefix.to_lowercase();
+        let suffix = suffix.to_lowercase();
+
+        // Ensure the prefix does not repeat the human-readable part of the address.
+        if prefix.starts_with(&format!("{}1", account_format::ADDRESS_PREFIX)) {
+            return Err(AccountError::InvalidVanityPattern(format!(
+                "the prefix must not include the leading \"{}1\"",
+                account_format::ADDRESS_PREFIX
+            )));
+        }
+        if prefix.len() > Self::MAX_PREFIX_LENGTH {
+            return Err(AccountError::InvalidVanityPattern(format!(
+                "the prefix must be at most {} characters",
+                Self::MAX_PREFIX_LENGTH
+            )));
+        }
+        if suffix.len() > Self::MAX_SUFFIX_LENGTH {
+            return Err(AccountError::InvalidVanityPattern(format!(
+                "the suffix must be at most {} characters",
+                Self::MAX_SUFFIX_LENGTH
+            )));
+        }
+
+        // Ensure the pattern only contains bech32 characters.
+        if let Some(character) = prefix
+            .chars()
+            .chain(suffix.chars())
+            .find(|c| !BECH32_CHARSET.contains(*c))
+        {
+            return Err(AccountError::InvalidVanityPattern(format!(
+                "{:?} is not a bech32 character, expected one of \"{}\"",
+                character, BECH32_CHARSET
+            )));
+        }
+
+        Ok(Self {
+            prefix,
+            suffix,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the prefix of the pattern.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the suffix of the pattern.
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Returns `true` if the given address matches the pattern.
+    pub fn is_match(&self, address: &Address<N>) -> bool {
+        let address = address.to_string();
+        // The data of the address starts after the human-readable part and the separator.
+        let data = &address[account_format::ADDRESS_PREFIX.len() + 1..];
+        data.starts_with(&self.prefix) && data.ends_with(&self.suffix)
+    }
+
+    /// Returns the expected number of attempts to find an address matching the pattern.
+    pub fn difficulty(&self) -> f64 {
+        (BECH32_CHARSET.len() as f64).powi((self.prefix.len() + self.suffix.len()) as i32)
+    }
+
+    /// Returns the probability of finding an address matching the pattern within the given number of attempts.
+    pub fn probability(&self, attempts: u64) -> f64 {
+        // Computes 1 - (1 - 1/difficulty)^attempts, without losing precision for large difficulties.
+        -(attempts as f64 * (-1.0 / self.difficulty()).ln_1p()).exp_m1()
+    }
+
+    ///
+    /// Searches for a private key whose address matches the pattern, on all threads of the current rayon thread pool.
+    ///
+    /// The given callback is invoked with the total number of attempts, every `PROGRESS_INTERVAL` attempts.
+    /// Returns `None` if the search is stopped by the terminator.
+    ///
+    pub fn search<F: Fn(u64) + Sync>(&self, terminator: &AtomicBool, on_progress: F) -> Option<PrivateKey<N>> {
+        let attempts = AtomicU64::new(0);
+
+        // The account seed of a matching private key is returned as bytes, to be sent across threads.
+        let seed = repeat(())
+            .map_init(thread_rng, |rng, _| {
+                if terminator.load(Ordering::SeqCst) {
+                    return Some(None);
+                }
+
+                let seed = N::AccountSeed::rand(rng);
+                let private_key = PrivateKey::<N>::from(&seed);
+
+                let num_attempts = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                if num_attempts % Self::PROGRESS_INTERVAL == 0 {
+                    on_progress(num_attempts);
+                }
+
+                match self.is_match(&private_key.to_address()) {
+                    true => Some(Some(
+                        seed.to_bytes_le().expect("Failed to convert an account seed to bytes"),
+                    )),
+                    false => None,
+                }
+            })
+            .find_map_any(|seed| seed)
+            .flatten()?;
+
+        let seed = N::AccountSeed::read_le(&seed[..]).expect("Failed to read an account seed from bytes");
+        Some(PrivateKey::from(&seed))
+    }
+}
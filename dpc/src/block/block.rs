@@ -179,7 +179,16 @@ impl<N: Network> Block<N> {
         };
 
         // Ensure the coinbase reward is equal to or greater than the expected block reward.
-        let coinbase_reward = AleoAmount::ZERO.sub(coinbase_transaction.value_balance()); // Make it a positive number.
+        let coinbase_reward = match coinbase_transaction
+            .value_balance()
+            .and_then(|balance| Ok(balance.checked_neg()?))
+        {
+            Ok(coinbase_reward) => coinbase_reward, // Make it a positive number.
+            Err(error) => {
+                eprintln!("Invalid coinbase reward: {}", error);
+                return false;
+            }
+        };
         let block_reward = Self::block_reward(self.height());
         if coinbase_reward < block_reward {
             eprintln!("Coinbase reward must be >= {}, found {}", block_reward, coinbase_reward);
@@ -187,7 +196,17 @@ impl<N: Network> Block<N> {
         }
 
         // Ensure the coinbase reward less transaction fees is less than or equal to the block reward.
-        let candidate_block_reward = AleoAmount::ZERO.sub(self.transactions.net_value_balance()); // Make it a positive number.
+        let candidate_block_reward = match self
+            .transactions
+            .net_value_balance()
+            .and_then(|balance| Ok(balance.checked_neg()?))
+        {
+            Ok(candidate_block_reward) => candidate_block_reward, // Make it a positive number.
+            Err(error) => {
+                eprintln!("Invalid net value balance: {}", error);
+                return false;
+            }
+        };
         if candidate_block_reward > block_reward {
             eprintln!(
                 "Block reward must be <= {}, found {}",
@@ -535,7 +554,7 @@ mod tests {
         // Serialize
         let expected_bytes = expected_block.to_bytes_le().unwrap();
         let candidate_bytes = bincode::serialize(&expected_block).unwrap();
-        assert_eq!(2090, expected_bytes.len(), "Update me if serialization has changed");
+        assert_eq!(2092, expected_bytes.len(), "Update me if serialization has changed");
         // TODO (howardwu): Serialization - Handle the inconsistency between ToBytes and Serialize (off by a length encoding).
         assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
 
@@ -502,7 +502,7 @@ mod tests {
 
         // Construct a PoSW proof.
         let mut block_header = Testnet2::posw()
-            .mine(&block_template, &AtomicBool::new(false), &mut thread_rng())
+            .mine(&block_template, &AtomicBool::new(false), &mut thread_rng(), -1)
             .unwrap();
 
         // Check that the difficulty target is satisfied.
@@ -321,7 +321,7 @@ mod tests {
         // Serialize
         let expected_bytes = expected_template.to_bytes_le().unwrap();
         let candidate_bytes = bincode::serialize(&expected_template).unwrap();
-        assert_eq!(1503, expected_bytes.len(), "Update me if serialization has changed");
+        assert_eq!(1505, expected_bytes.len(), "Update me if serialization has changed");
         // TODO (howardwu): Serialization - Handle the inconsistency between ToBytes and Serialize (off by a length encoding).
         assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
 
@@ -108,7 +108,7 @@ impl<N: Network> Transactions<N> {
         let num_coinbase = self
             .transactions
             .iter()
-            .filter(|t| t.value_balance().is_negative())
+            .filter(|t| matches!(t.value_balance(), Ok(value_balance) if value_balance.is_negative()))
             .count();
         if num_coinbase != 1 {
             eprintln!("Block must have exactly 1 coinbase transaction, found {}", num_coinbase);
@@ -144,29 +144,31 @@ impl<N: Network> Transactions<N> {
     }
 
     /// Returns the net value balance, by summing the value balance from all transactions.
-    pub fn net_value_balance(&self) -> AleoAmount {
+    pub fn net_value_balance(&self) -> Result<AleoAmount> {
         self.transactions
             .iter()
-            .map(Transaction::value_balance)
-            .fold(AleoAmount::ZERO, |a, b| a.add(b))
+            .try_fold(AleoAmount::ZERO, |a, b| Ok(a.checked_add(b.value_balance()?)?))
     }
 
     /// Returns the total transaction fees, by summing the value balance from all positive transactions.
     /// Note - this amount does *not* include the block reward.
-    pub fn transaction_fees(&self) -> AleoAmount {
-        self.transactions
-            .iter()
-            .filter_map(|t| match t.value_balance().is_negative() {
-                true => None,
-                false => Some(t.value_balance()),
-            })
-            .fold(AleoAmount::ZERO, |a, b| a.add(b))
+    pub fn transaction_fees(&self) -> Result<AleoAmount> {
+        self.transactions.iter().try_fold(AleoAmount::ZERO, |a, t| {
+            let value_balance = t.value_balance()?;
+            match value_balance.is_negative() {
+                true => Ok(a),
+                false => Ok(a.checked_add(value_balance)?),
+            }
+        })
     }
 
     /// Returns the coinbase transaction for the block.
     pub fn to_coinbase_transaction(&self) -> Result<Transaction<N>> {
         // Filter out all transactions with a positive value balance.
-        let coinbase_transaction: Vec<_> = self.iter().filter(|t| t.value_balance().is_negative()).collect();
+        let coinbase_transaction: Vec<_> = self
+            .iter()
+            .filter(|t| matches!(t.value_balance(), Ok(value_balance) if value_balance.is_negative()))
+            .collect();
 
         // Ensure there is exactly 1 coinbase transaction.
         let num_coinbase = coinbase_transaction.len();
@@ -348,7 +350,7 @@ mod tests {
         // Serialize
         let expected_bytes = expected_transactions.to_bytes_le().unwrap();
         let candidate_bytes = bincode::serialize(&expected_transactions).unwrap();
-        assert_eq!(1123, expected_bytes.len(), "Update me if serialization has changed");
+        assert_eq!(1125, expected_bytes.len(), "Update me if serialization has changed");
         // TODO (howardwu): Serialization - Handle the inconsistency between ToBytes and Serialize (off by a length encoding).
         assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
 
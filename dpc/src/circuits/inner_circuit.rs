@@ -46,9 +46,14 @@ pub struct InnerCircuit<N: Network> {
 
 impl<N: Network> InnerCircuit<N> {
     pub fn blank() -> Self {
+        Self::blank_with_input_records(N::NUM_INPUT_RECORDS)
+    }
+
+    /// Returns a blank inner circuit for transitions with the given (padded) number of input records.
+    pub fn blank_with_input_records(num_input_records: usize) -> Self {
         Self {
             public: InnerPublicVariables::blank(),
-            private: InnerPrivateVariables::blank(),
+            private: InnerPrivateVariables::blank(num_input_records),
         }
     }
 
@@ -179,11 +184,13 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
         /* ///////////////////////////// INPUT RECORDS //////////////////////////////// */
         /* //////////////////////////////////////////////////////////////////////////// */
 
-        let mut input_serial_numbers_bytes = Vec::with_capacity(N::NUM_INPUT_RECORDS);
-        let mut input_commitments_bytes = Vec::with_capacity(N::NUM_INPUT_RECORDS * 32);
-        let mut input_owners = Vec::with_capacity(N::NUM_INPUT_RECORDS);
-        let mut input_values = Vec::with_capacity(N::NUM_INPUT_RECORDS);
-        let mut input_program_ids = Vec::with_capacity(N::NUM_INPUT_RECORDS);
+        let num_input_records = private.input_records.len();
+
+        let mut input_serial_numbers_bytes = Vec::with_capacity(num_input_records);
+        let mut input_commitments_bytes = Vec::with_capacity(num_input_records * 32);
+        let mut input_owners = Vec::with_capacity(num_input_records);
+        let mut input_values = Vec::with_capacity(num_input_records);
+        let mut input_program_ids = Vec::with_capacity(num_input_records);
 
         for (i, (record, ledger_proof)) in private
             .input_records
@@ -491,7 +498,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
 
             // Enforce that the input owners are the same address.
             let mut current_owner = &input_owners[0];
-            for (i, next_owner) in input_owners.iter().take(N::NUM_INPUT_RECORDS).skip(1).enumerate() {
+            for (i, next_owner) in input_owners.iter().skip(1).enumerate() {
                 // Enforce the owners are equal.
                 current_owner.enforce_equal(signature_cs.ns(|| format!("check_owners_match_{}", i)), next_owner)?;
                 // Update the current owner.
@@ -705,7 +712,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                     .function_type
                     .input_count()])?[0];
             {
-                let number_of_input_records = UInt8::constant(N::NUM_INPUT_RECORDS as u8);
+                let number_of_input_records = UInt8::constant(num_input_records as u8);
                 let is_inputs_size_correct = number_of_inputs.less_than_or_equal(
                     &mut program_cs.ns(|| "Check number of inputs is less than or equal to input records size"),
                     &number_of_input_records,
@@ -733,8 +740,7 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                 )?;
             }
 
-            for (i, input_program_id_field_elements) in input_program_ids.iter().take(N::NUM_INPUT_RECORDS).enumerate()
-            {
+            for (i, input_program_id_field_elements) in input_program_ids.iter().enumerate() {
                 let input_cs = &mut program_cs.ns(|| format!("Check input record {} on executable", i));
 
                 let input_index = UInt8::constant(i as u8);
@@ -852,7 +858,8 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
 
             // Sanity check that the correct number of leaves are allocated.
             // Note: This is *not* enforced in the circuit.
-            assert_eq!(usize::pow(2, N::TRANSITION_TREE_DEPTH as u32), transition_leaves.len());
+            assert!(input_serial_numbers_bytes.len().is_power_of_two());
+            assert!(output_commitments_bytes.len().is_power_of_two());
 
             // Allocate the hashed leaves.
             let hashed_transition_leaves = transition_leaves
@@ -865,12 +872,27 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for InnerCircuit<N>
                     )
                 })
                 .collect::<Result<Vec<_>, _>>()?;
+            let (hashed_serial_number_leaves, hashed_commitment_leaves) =
+                hashed_transition_leaves.split_at(input_serial_numbers_bytes.len());
 
+            // Compute the transition ID from the roots of the serial numbers and commitments subtrees.
+            let serial_numbers_root =
+                compute_root::<<N::TransitionIDParameters as MerkleParameters>::H, N::TransitionIDCRHGadget, _, _>(
+                    &mut cs.ns(|| "Compute the serial numbers root"),
+                    &transition_id_crh,
+                    hashed_serial_number_leaves,
+                )?;
+            let commitments_root =
+                compute_root::<<N::TransitionIDParameters as MerkleParameters>::H, N::TransitionIDCRHGadget, _, _>(
+                    &mut cs.ns(|| "Compute the commitments root"),
+                    &transition_id_crh,
+                    hashed_commitment_leaves,
+                )?;
             let candidate_transition_id =
                 compute_root::<<N::TransitionIDParameters as MerkleParameters>::H, N::TransitionIDCRHGadget, _, _>(
                     &mut cs.ns(|| "Compute the transition ID"),
                     &transition_id_crh,
-                    &hashed_transition_leaves,
+                    &[serial_numbers_root, commitments_root],
                 )?;
 
             let given_transition_id = <N::TransitionIDCRHGadget as CRHGadget<
@@ -34,10 +34,10 @@ pub struct InnerPrivateVariables<N: Network> {
 }
 
 impl<N: Network> InnerPrivateVariables<N> {
-    pub(crate) fn blank() -> Self {
+    pub(crate) fn blank(num_input_records: usize) -> Self {
         Self {
-            input_records: vec![Record::default(); N::NUM_INPUT_RECORDS],
-            ledger_proofs: vec![Default::default(); N::NUM_INPUT_RECORDS],
+            input_records: vec![Record::default(); num_input_records],
+            ledger_proofs: vec![Default::default(); num_input_records],
             signature: <N::AccountSignatureScheme as SignatureScheme>::Signature::default().into(),
             function_type: FunctionType::Noop,
             output_records: vec![Record::default(); N::NUM_OUTPUT_RECORDS],
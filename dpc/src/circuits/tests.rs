@@ -17,7 +17,7 @@
 use crate::{circuits::*, prelude::*};
 use snarkvm_algorithms::prelude::*;
 use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
-use snarkvm_utilities::{ToBytes, ToMinimalBits};
+use snarkvm_utilities::{FromBytes, ToBytes, ToMinimalBits};
 
 use rand::thread_rng;
 
@@ -45,7 +45,7 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
 
     // Compute the value balance.
     let mut value_balance = AleoAmount::ZERO;
-    for record in request.records().iter().take(N::NUM_INPUT_RECORDS) {
+    for record in request.records() {
         value_balance = value_balance.add(record.value());
     }
     for record in response.records().iter().take(N::NUM_OUTPUT_RECORDS) {
@@ -117,7 +117,7 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
 
     // Generate inner circuit parameters and proof for verification in the outer circuit.
     let (inner_proving_key, inner_verifying_key) =
-        <N as Network>::InnerSNARK::setup(&InnerCircuit::<N>::blank(), &mut SRS::CircuitSpecific(rng)).unwrap();
+        <N as Network>::InnerSNARK::setup(&InnerCircuit::<N>::blank(), &mut SRS::CircuitSpecific(rng), -1).unwrap();
 
     // NOTE: Do not change this to `N::inner_circuit_id()` as that will load the *saved* inner circuit VK.
     let inner_circuit_id = <N as Network>::inner_circuit_id_crh()
@@ -125,7 +125,7 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
         .unwrap()
         .into();
 
-    let inner_proof = <N as Network>::InnerSNARK::prove(&inner_proving_key, &inner_circuit, rng).unwrap();
+    let inner_proof = <N as Network>::InnerSNARK::prove(&inner_proving_key, &inner_circuit, rng, -1).unwrap();
     assert_eq!(N::INNER_PROOF_SIZE_IN_BYTES, inner_proof.to_bytes_le().unwrap().len());
 
     // Verify that the inner circuit proof passes.
@@ -164,7 +164,7 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
 
     // Generate outer circuit parameters and proof.
     let (outer_proving_key, outer_verifying_key) =
-        <N as Network>::OuterSNARK::setup(&outer_circuit, &mut SRS::CircuitSpecific(rng)).unwrap();
+        <N as Network>::OuterSNARK::setup(&outer_circuit, &mut SRS::CircuitSpecific(rng), -1).unwrap();
 
     // // NOTE: Do not change this to `N::inner_circuit_id()` as that will load the *saved* inner circuit VK.
     // let inner_circuit_id = <N as Network>::inner_circuit_id_crh()
@@ -172,13 +172,137 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
     //     .unwrap()
     //     .into();
 
-    let outer_proof = <N as Network>::OuterSNARK::prove(&outer_proving_key, &outer_circuit, rng).unwrap();
+    let outer_proof = <N as Network>::OuterSNARK::prove(&outer_proving_key, &outer_circuit, rng, -1).unwrap();
     assert_eq!(N::OUTER_PROOF_SIZE_IN_BYTES, outer_proof.to_bytes_le().unwrap().len());
 
     // Verify that the outer circuit proof passes.
     assert!(<N as Network>::OuterSNARK::verify(&outer_verifying_key, &outer_public, &outer_proof).unwrap());
 }
 
+fn dpc_join_records_test<N: Network>(num_records: usize)
+where
+    N::OuterProof: Default,
+{
+    let rng = &mut thread_rng();
+
+    let account = Account::<N>::new(rng);
+    let amount = AleoAmount::from_i64(10);
+
+    // Produce the records to join in prior transitions of the same transaction.
+    let mut transitions = Transitions::<N>::new().unwrap();
+    let mut records = Vec::with_capacity(num_records);
+    while records.len() < num_records {
+        let request = Request::new_coinbase(account.address(), amount, false, rng).unwrap();
+        let response = ResponseBuilder::new()
+            .add_request(request.clone())
+            .add_output(Output::new(account.address(), amount, Default::default(), None).unwrap())
+            .build(rng)
+            .unwrap();
+        let transition = Transition::<N>::from(
+            response.transition_id(),
+            request.to_serial_numbers().unwrap(),
+            response.ciphertexts(),
+            response.value_balance(),
+            response.events().clone(),
+            Default::default(),
+        )
+        .unwrap();
+        transitions.add(&transition).unwrap();
+        records.push(response.records()[0].clone());
+    }
+
+    // Prove the records are in the local transitions.
+    let ledger_proofs = records
+        .iter()
+        .map(|record| LedgerProof::new_dummy(transitions.to_local_proof(record.commitment()).unwrap()).unwrap())
+        .collect();
+
+    // Join the records into one record.
+    let total = AleoAmount::from_i64(10 * num_records as i64);
+    let function_type = FunctionType::new(num_records as u8, 1);
+    let operation = Operation::Evaluate(
+        *N::noop_function_id(),
+        function_type,
+        FunctionInputs::new(&account.address(), &account.address(), total, Default::default()),
+    );
+    let request = Request::new(
+        account.private_key(),
+        records,
+        ledger_proofs,
+        operation,
+        AleoAmount::ZERO,
+        false,
+        rng,
+    )
+    .unwrap();
+    assert_eq!(function_type.num_input_records::<N>(), request.records().len());
+
+    let response = ResponseBuilder::new()
+        .add_request(request.clone())
+        .add_output(Output::new(account.address(), total, Default::default(), None).unwrap())
+        .build(rng)
+        .unwrap();
+    assert_eq!(total, response.records()[0].value());
+    assert_eq!(AleoAmount::ZERO, response.value_balance());
+
+    // Ensure the transition encodes every serial number, and its commitments are provable at the standard depth.
+    let serial_numbers = request.to_serial_numbers().unwrap();
+    let transition = Transition::<N>::from(
+        response.transition_id(),
+        serial_numbers.clone(),
+        response.ciphertexts(),
+        response.value_balance(),
+        response.events().clone(),
+        Default::default(),
+    )
+    .unwrap();
+    assert_eq!(num_records, transition.serial_numbers().count());
+    assert_eq!(
+        transition,
+        Transition::read_le(&transition.to_bytes_le().unwrap()[..]).unwrap()
+    );
+    for commitment in response.commitments() {
+        let inclusion_proof = transition.to_transition_inclusion_proof(commitment).unwrap();
+        assert_eq!(N::TRANSITION_TREE_DEPTH, inclusion_proof.path.len());
+        assert!(inclusion_proof
+            .verify(&transition.transition_id(), &commitment)
+            .unwrap());
+    }
+    for serial_number in &serial_numbers {
+        let inclusion_proof = transition.to_transition_inclusion_proof(serial_number).unwrap();
+        assert!(inclusion_proof
+            .verify(&transition.transition_id(), serial_number)
+            .unwrap());
+    }
+
+    // Check that the inner circuit is satisfied by the joined records.
+    let inner_public = InnerPublicVariables::new(
+        response.transition_id(),
+        response.value_balance(),
+        LedgerTree::<N>::new().unwrap().root(),
+        transitions.root(),
+        Some(request.to_program_id().unwrap()),
+    );
+    let inner_private = InnerPrivateVariables::new(&request, &response).unwrap();
+
+    let mut inner_cs = TestConstraintSystem::<N::InnerScalarField>::new();
+    InnerCircuit::new(inner_public, inner_private)
+        .generate_constraints(&mut inner_cs.ns(|| "Inner circuit"))
+        .unwrap();
+
+    if !inner_cs.is_satisfied() {
+        println!("Unsatisfied constraints:\n{}", inner_cs.which_is_unsatisfied().unwrap());
+    }
+    assert!(inner_cs.is_satisfied());
+
+    // Ensure the blank inner circuit for this number of input records has the same shape.
+    let mut blank_cs = TestConstraintSystem::<N::InnerScalarField>::new();
+    InnerCircuit::<N>::blank_with_input_records(num_records)
+        .generate_constraints(&mut blank_cs.ns(|| "Inner circuit"))
+        .unwrap();
+    assert_eq!(inner_cs.num_constraints(), blank_cs.num_constraints());
+}
+
 mod testnet1 {
     use super::*;
     use crate::testnet1::*;
@@ -197,4 +321,9 @@ mod testnet2 {
     fn test_dpc_execute_circuits() {
         dpc_execute_circuits_test::<Testnet2>(253822, 242379);
     }
+
+    #[test]
+    fn test_dpc_join_records() {
+        dpc_join_records_test::<Testnet2>(8);
+    }
 }
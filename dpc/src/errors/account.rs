@@ -36,12 +36,45 @@ pub enum AccountError {
     #[error("invalid character length: {}", _0)]
     InvalidCharacterLength(usize),
 
+    #[error("invalid derivation depth: {}", _0)]
+    InvalidDerivationDepth(u8),
+
+    #[error("invalid derivation path: {:?}", _0)]
+    InvalidDerivationPath(String),
+
+    #[error("invalid keystore KDF parameters: {}", _0)]
+    InvalidKeystoreKdf(String),
+
+    #[error("invalid keystore network: expected {}, found {}", _0, _1)]
+    InvalidKeystoreNetwork(u16, u16),
+
+    #[error("invalid keystore password")]
+    InvalidKeystorePassword,
+
+    #[error("invalid keystore version: {}", _0)]
+    InvalidKeystoreVersion(u8),
+
+    #[error("invalid mnemonic checksum")]
+    InvalidMnemonicChecksum,
+
+    #[error("invalid mnemonic seed")]
+    InvalidMnemonicSeed,
+
+    #[error("invalid mnemonic word: {:?}", _0)]
+    InvalidMnemonicWord(String),
+
+    #[error("invalid mnemonic word count: {}", _0)]
+    InvalidMnemonicWordCount(usize),
+
     #[error("invalid prefix: {:?}", _0)]
     InvalidPrefix(String),
 
     #[error("invalid prefix bytes: {:?}", _0)]
     InvalidPrefixBytes(Vec<u8>),
 
+    #[error("invalid vanity pattern: {:?}", _0)]
+    InvalidVanityPattern(String),
+
     #[error("invalid variant")]
     InvalidVariant,
 
@@ -14,33 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-/**
-Add,
-    Div,
-    Double,
-    Inv,
-    IsEq,
-    IsNeq,
-    Mul,
-    Neg,
-    One,
-    Square,
-    Sub,
-    Ternary,
-    ToBitsLE,
-    ToBitsBE,
-    Zero,
+#[derive(Debug, Error)]
+pub enum AmountError {
+    #[error("Amount overflowed")]
+    Overflow,
 
- */
+    #[error("Invalid amount denomination: {}", _0)]
+    InvalidDenomination(String),
 
-pub enum BooleanOp {
-    And,
-    IsEq,
-    IsNeq,
-    Nand,
-    Nor,
-    Not,
-    Or,
-    Ternary,
-    Xor,
+    #[error("Invalid amount: {}", _0)]
+    InvalidAmount(String),
 }
@@ -45,6 +45,12 @@ pub enum RecordError {
     #[error("Invalid commitment. Expected {}, found {}", _0, _1)]
     InvalidCommitment(String, String),
 
+    #[error("Invalid payload value. Expected {}, found {}", _0, _1)]
+    InvalidPayloadValue(String, String),
+
+    #[error("Payload of {} bytes exceeds the maximum of {} bytes", _0, _1)]
+    PayloadTooLarge(usize, usize),
+
     #[error("{}", _0)]
     PRFError(#[from] PRFError),
 
@@ -0,0 +1,301 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+/// A record owned by the scanned account, along with its location in the ledger.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct OwnedRecord<N: Network> {
+    /// The height of the block containing the record.
+    pub block_height: u32,
+    /// The commitment of the record.
+    pub commitment: N::Commitment,
+    /// The decrypted record.
+    pub record: Record<N>,
+    /// The height of the block spending the record, if it is known to be spent.
+    pub spent_height: Option<u32>,
+}
+
+impl<N: Network> OwnedRecord<N> {
+    /// Returns `true` if the record is known to be spent.
+    /// Note: This is only determined if the scanner was given a compute key.
+    pub fn is_spent(&self) -> bool {
+        self.spent_height.is_some()
+    }
+}
+
+/// A read-only scanner over the ledger, which finds the records owned by an account view key.
+///
+/// If a compute key is given, the scanner also determines which of the owned records are spent,
+/// by looking up their serial numbers in the ledger, up to the scanned height.
+///
+/// The scanner may also be advanced one block at a time with `scan_block`, and rolled back with `rollback_to`.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct AccountScanner<N: Network> {
+    /// The decryption key of the account.
+    decryption_key: DecryptionKey<N>,
+    /// The compute key of the account, if spent records are tracked.
+    compute_key: Option<ComputeKey<N>>,
+    /// The height of the next block to scan.
+    checkpoint: u32,
+    /// The records owned by the account, in order of block height.
+    records: Vec<OwnedRecord<N>>,
+    /// A map of serial numbers to the index of their unspent record.
+    serial_numbers: HashMap<N::SerialNumber, usize>,
+}
+
+impl<N: Network> AccountScanner<N> {
+    /// Returns a new account scanner, starting from the genesis block.
+    pub fn new(view_key: &ViewKey<N>, compute_key: Option<ComputeKey<N>>) -> Self {
+        Self {
+            decryption_key: view_key.into(),
+            compute_key,
+            checkpoint: 0,
+            records: Vec::new(),
+            serial_numbers: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Returns a new account scanner, resuming from the given checkpoint height
+    /// with the owned records found by a previous scan before the checkpoint.
+    ///
+    /// If a compute key is given, the unspent records are checked for spends on the next scan,
+    /// including spends in blocks prior to the checkpoint.
+    ///
+    pub fn from_checkpoint(
+        view_key: &ViewKey<N>,
+        compute_key: Option<ComputeKey<N>>,
+        checkpoint: u32,
+        records: Vec<OwnedRecord<N>>,
+    ) -> Result<Self> {
+        let address = Address::from(view_key);
+
+        let mut records = records;
+        records.sort_by_key(|record| record.block_height);
+
+        let mut scanner = Self::new(view_key, compute_key);
+        scanner.checkpoint = checkpoint;
+        for record in records {
+            // Ensure the record belongs to the account, and was found before the checkpoint.
+            if record.record.owner() != address {
+                return Err(anyhow!("Record {} is not owned by {}", record.commitment, address));
+            }
+            if record.block_height >= checkpoint {
+                return Err(anyhow!(
+                    "Record {} at block height {} is not before the checkpoint {}",
+                    record.commitment,
+                    record.block_height,
+                    checkpoint
+                ));
+            }
+            scanner.add_record(record)?;
+        }
+        Ok(scanner)
+    }
+
+    /// Returns the height of the next block to scan.
+    pub fn checkpoint(&self) -> u32 {
+        self.checkpoint
+    }
+
+    /// Returns the owned records found so far, in order of block height.
+    pub fn records(&self) -> &[OwnedRecord<N>] {
+        &self.records
+    }
+
+    /// Returns the owned records found so far that are not known to be spent.
+    pub fn unspent_records(&self) -> impl Iterator<Item = &OwnedRecord<N>> + '_ {
+        self.records.iter().filter(|record| !record.is_spent())
+    }
+
+    /// Scans the blocks from the checkpoint height up to and including the given end height,
+    /// and returns the newly-found owned records. On success, the checkpoint advances past the end height.
+    pub fn scan(&mut self, blocks: &Blocks<N>, end_height: u32) -> Result<Vec<OwnedRecord<N>>> {
+        if end_height > blocks.latest_block_height() {
+            return Err(anyhow!(
+                "Invalid block range: end height {} exceeds the latest block height {}",
+                end_height,
+                blocks.latest_block_height()
+            ));
+        }
+        if self.checkpoint > end_height {
+            return Err(anyhow!(
+                "Invalid block range: checkpoint {} exceeds end height {}",
+                self.checkpoint,
+                end_height
+            ));
+        }
+
+        // Scan on a copy of the scanner, so that a failure does not leave it partially advanced.
+        let mut scanner = self.clone();
+        let num_records = scanner.records.len();
+        for block_height in self.checkpoint..=end_height {
+            for transaction in blocks.get_block_transactions(block_height)?.iter() {
+                for record in transaction.to_decrypted_records(&self.decryption_key) {
+                    scanner.add_record(OwnedRecord {
+                        block_height,
+                        commitment: record.commitment(),
+                        record,
+                        spent_height: None,
+                    })?;
+                }
+            }
+        }
+        scanner.mark_spent(blocks, end_height)?;
+        scanner.checkpoint = end_height + 1;
+
+        let new_records = scanner.records[num_records..].to_vec();
+        *self = scanner;
+        Ok(new_records)
+    }
+
+    /// Scans the given block, which must be at the checkpoint height, and returns the newly-found owned records.
+    /// On success, the checkpoint advances past the block.
+    ///
+    /// Unlike `scan`, only the records spent in the given block are marked as spent.
+    pub fn scan_block(&mut self, block: &Block<N>) -> Result<Vec<OwnedRecord<N>>> {
+        if block.height() != self.checkpoint {
+            return Err(anyhow!(
+                "Expected a block at height {}, found a block at height {}",
+                self.checkpoint,
+                block.height()
+            ));
+        }
+
+        // Decrypt the owned records and compute their serial numbers, before modifying the scanner.
+        let mut new_records = Vec::with_capacity(block.transactions().len());
+        for transaction in block.transactions().iter() {
+            let mut records = Vec::new();
+            for record in transaction.to_decrypted_records(&self.decryption_key) {
+                let serial_number = match &self.compute_key {
+                    Some(compute_key) => Some(record.to_serial_number(compute_key)?),
+                    None => None,
+                };
+                records.push((record, serial_number));
+            }
+            new_records.push((transaction, records));
+        }
+
+        // Mark the spent records, then add the owned records, in order of transactions.
+        let num_records = self.records.len();
+        for (transaction, records) in new_records {
+            for serial_number in transaction.serial_numbers() {
+                if let Some(index) = self.serial_numbers.remove(serial_number) {
+                    self.records[index].spent_height = Some(block.height());
+                }
+            }
+
+            for (record, serial_number) in records {
+                if let Some(serial_number) = serial_number {
+                    self.serial_numbers.insert(serial_number, self.records.len());
+                }
+                self.records.push(OwnedRecord {
+                    block_height: block.height(),
+                    commitment: record.commitment(),
+                    record,
+                    spent_height: None,
+                });
+            }
+        }
+        self.checkpoint += 1;
+
+        Ok(self.records[num_records..].to_vec())
+    }
+
+    /// Rolls back the scanner to the given block height, removing the records
+    /// and spends from all blocks after it. On success, the checkpoint is the next block height.
+    pub fn rollback_to(&mut self, block_height: u32) -> Result<()> {
+        if block_height >= self.checkpoint {
+            return Err(anyhow!(
+                "Cannot roll back the scanner to block height {}, at or after its checkpoint {}",
+                block_height,
+                self.checkpoint
+            ));
+        }
+
+        // Roll back on a copy of the scanner, so that a failure does not leave it partially rolled back.
+        let mut scanner = self.clone();
+
+        // Remove the records found after the given height.
+        let num_records = scanner
+            .records
+            .partition_point(|record| record.block_height <= block_height);
+        scanner.records.truncate(num_records);
+        scanner.serial_numbers.retain(|_, index| *index < num_records);
+
+        // Restore the records spent after the given height.
+        for index in 0..num_records {
+            if matches!(scanner.records[index].spent_height, Some(spent_height) if spent_height > block_height) {
+                scanner.records[index].spent_height = None;
+                if let Some(compute_key) = &scanner.compute_key {
+                    let serial_number = scanner.records[index].record.to_serial_number(compute_key)?;
+                    scanner.serial_numbers.insert(serial_number, index);
+                }
+            }
+        }
+        scanner.checkpoint = block_height + 1;
+
+        *self = scanner;
+        Ok(())
+    }
+
+    /// Marks the owned records whose serial numbers are in the ledger, up to and including the given end height.
+    fn mark_spent(&mut self, blocks: &Blocks<N>, end_height: u32) -> Result<()> {
+        let spent = self
+            .serial_numbers
+            .iter()
+            .filter(|(serial_number, _)| blocks.contains_serial_number(serial_number))
+            .map(|(serial_number, index)| (*serial_number, *index))
+            .collect::<Vec<_>>();
+
+        for (serial_number, index) in spent {
+            // Find the block spending the record, which is not before the block containing the record.
+            for block_height in self.records[index].block_height..=end_height {
+                if blocks
+                    .get_block_transactions(block_height)?
+                    .serial_numbers()
+                    .any(|s| *s == serial_number)
+                {
+                    self.records[index].spent_height = Some(block_height);
+                    self.serial_numbers.remove(&serial_number);
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the given owned record, and tracks its serial number if it is unspent.
+    fn add_record(&mut self, record: OwnedRecord<N>) -> Result<()> {
+        if let (Some(compute_key), false) = (&self.compute_key, record.is_spent()) {
+            let serial_number = record.record.to_serial_number(compute_key)?;
+            self.serial_numbers.insert(serial_number, self.records.len());
+        }
+        self.records.push(record);
+        Ok(())
+    }
+}
@@ -36,6 +36,10 @@ pub struct Blocks<N: Network> {
     headers: HashMap<u32, BlockHeader<N>>,
     /// The chain of block transactions.
     transactions: HashMap<u32, Transactions<N>>,
+    /// The program events in the chain, in block order.
+    program_events: Vec<IndexedEvent<N>>,
+    /// The positions of the program events of each program ID, in block order.
+    program_event_positions: HashMap<N::ProgramID, Vec<usize>>,
 }
 
 impl<N: Network> Blocks<N> {
@@ -51,6 +55,8 @@ impl<N: Network> Blocks<N> {
             previous_hashes: Default::default(),
             headers: Default::default(),
             transactions: Default::default(),
+            program_events: Default::default(),
+            program_event_positions: Default::default(),
         };
 
         blocks.ledger_tree.add(&genesis_block.hash())?;
@@ -59,6 +65,7 @@ impl<N: Network> Blocks<N> {
             .insert(height, genesis_block.previous_block_hash());
         blocks.headers.insert(height, genesis_block.header().clone());
         blocks.transactions.insert(height, genesis_block.transactions().clone());
+        blocks.index_program_events(height, genesis_block.transactions());
 
         Ok(blocks)
     }
@@ -309,6 +316,7 @@ impl<N: Network> Blocks<N> {
             blocks.previous_hashes.insert(height, block.previous_block_hash());
             blocks.headers.insert(height, block.header().clone());
             blocks.transactions.insert(height, block.transactions().clone());
+            blocks.index_program_events(height, block.transactions());
 
             *self = blocks;
         }
@@ -316,6 +324,60 @@ impl<N: Network> Blocks<N> {
         Ok(())
     }
 
+    /// Returns the program events matching the given filter, from the given start height
+    /// up to and including the given end height, in the order they appear in the chain.
+    pub fn get_program_events(
+        &self,
+        filter: &EventFilter<N>,
+        start_height: u32,
+        end_height: u32,
+    ) -> Result<Vec<IndexedEvent<N>>> {
+        if start_height > end_height {
+            return Err(anyhow!(
+                "Invalid block range: start height {} exceeds end height {}",
+                start_height,
+                end_height
+            ));
+        }
+
+        let in_range =
+            |event: &&IndexedEvent<N>| event.block_height >= start_height && event.block_height <= end_height;
+
+        let events: Box<dyn Iterator<Item = &IndexedEvent<N>>> = match filter.to_program_id() {
+            Some(program_id) => Box::new(
+                self.program_event_positions
+                    .get(&program_id)
+                    .into_iter()
+                    .flatten()
+                    .map(|position| &self.program_events[*position]),
+            ),
+            None => Box::new(self.program_events.iter()),
+        };
+
+        Ok(events
+            .filter(in_range)
+            .filter(|event| filter.matches(&event.event))
+            .cloned()
+            .collect())
+    }
+
+    /// Adds the program events in the given block transactions to the program events index.
+    fn index_program_events(&mut self, block_height: u32, transactions: &Transactions<N>) {
+        for transition in transactions.iter().flat_map(Transaction::transitions) {
+            for event in transition.events().filter_map(Event::to_program_event) {
+                self.program_event_positions
+                    .entry(event.program_id())
+                    .or_default()
+                    .push(self.program_events.len());
+                self.program_events.push(IndexedEvent {
+                    block_height,
+                    transition_id: transition.transition_id(),
+                    event: event.clone(),
+                });
+            }
+        }
+    }
+
     /// Returns the ledger tree.
     pub fn to_ledger_tree(&self) -> &LedgerTree<N> {
         &self.ledger_tree
@@ -561,6 +623,7 @@ impl<N: Network> Blocks<N> {
 mod tests {
     use super::*;
     use crate::testnet2::Testnet2;
+    use snarkvm_utilities::UniformRand;
 
     use rand::{thread_rng, Rng};
 
@@ -800,4 +863,77 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_get_program_events() {
+        let rng = &mut thread_rng();
+
+        let mut blocks = Blocks::<Testnet2>::new().unwrap();
+
+        // The genesis block does not contain program events.
+        assert!(blocks.get_program_events(&EventFilter::new(), 0, 0).unwrap().is_empty());
+
+        // An inverted block range is rejected.
+        assert!(blocks.get_program_events(&EventFilter::new(), 1, 0).is_err());
+
+        // Returns the genesis transactions, with the given program events emitted by the coinbase transition.
+        let genesis_transaction = &Testnet2::genesis_block().transactions()[0];
+        let coinbase_transition = &genesis_transaction.transitions()[0];
+        let with_events = |events: &[ProgramEvent<Testnet2>]| {
+            let transition = Transition::from(
+                coinbase_transition.transition_id(),
+                coinbase_transition.serial_numbers().cloned().collect(),
+                coinbase_transition.ciphertexts().cloned().collect(),
+                *coinbase_transition.value_balance(),
+                events.iter().cloned().map(Event::Program).collect(),
+                coinbase_transition.proof().clone(),
+            )
+            .unwrap();
+            let transaction = Transaction::from(
+                genesis_transaction.inner_circuit_id(),
+                genesis_transaction.ledger_root(),
+                vec![transition],
+            )
+            .unwrap();
+            Transactions::from(&[transaction]).unwrap()
+        };
+
+        let (first_program, second_program) = (UniformRand::rand(rng), UniformRand::rand(rng));
+        let event = |program_id, topic: u8| ProgramEvent::new(program_id, vec![[topic; 32]], vec![topic]).unwrap();
+
+        let first_block = [event(first_program, 1), event(second_program, 2)];
+        let second_block = [event(second_program, 3), event(first_program, 4)];
+        blocks.index_program_events(1, &with_events(&first_block));
+        blocks.index_program_events(2, &with_events(&second_block));
+
+        let to_events = |events: Vec<IndexedEvent<Testnet2>>| events.into_iter().map(|e| e.event).collect::<Vec<_>>();
+
+        // Unfiltered events are returned in the order they appear in the chain.
+        let events = blocks.get_program_events(&EventFilter::new(), 0, 2).unwrap();
+        assert_eq!(
+            vec![1, 1, 2, 2],
+            events.iter().map(|event| event.block_height).collect::<Vec<_>>()
+        );
+        assert!(events
+            .iter()
+            .all(|event| event.transition_id == coinbase_transition.transition_id()));
+        assert_eq!([&first_block[..], &second_block[..]].concat(), to_events(events));
+
+        // Events are filtered by block range.
+        let events = blocks.get_program_events(&EventFilter::new(), 2, 2).unwrap();
+        assert_eq!(second_block.to_vec(), to_events(events));
+
+        // Events are filtered by program ID.
+        let filter = EventFilter::new().program_id(first_program);
+        let events = blocks.get_program_events(&filter, 0, 2).unwrap();
+        assert_eq!(vec![first_block[0].clone(), second_block[1].clone()], to_events(events));
+
+        // Events are filtered by topic.
+        let filter = EventFilter::new().topic(0, [3u8; 32]);
+        let events = blocks.get_program_events(&filter, 0, 2).unwrap();
+        assert_eq!(vec![second_block[0].clone()], to_events(events));
+
+        let filter = EventFilter::new().program_id(first_program).topic(0, [3u8; 32]);
+        assert!(blocks.get_program_events(&filter, 0, 2).unwrap().is_empty());
+    }
 }
@@ -96,6 +96,23 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.contains_transaction(transaction)
     }
 
+    /// Returns the program events on the canon chain matching the given filter,
+    /// from the given start height up to and including the given end height.
+    pub fn get_program_events(
+        &self,
+        filter: &EventFilter<N>,
+        start_height: u32,
+        end_height: u32,
+    ) -> Result<Vec<IndexedEvent<N>>> {
+        self.canon_blocks.get_program_events(filter, start_height, end_height)
+    }
+
+    /// Scans the canon chain from the checkpoint of the given scanner up to and including
+    /// the given end height, and returns the newly-found records owned by the scanned account.
+    pub fn scan_account(&self, scanner: &mut AccountScanner<N>, end_height: u32) -> Result<Vec<OwnedRecord<N>>> {
+        scanner.scan(&self.canon_blocks, end_height)
+    }
+
     /// Adds the given canon block, if it is well-formed and does not already exist.
     /// Note: This method requires blocks to be added in order of canon block height.
     pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
@@ -222,14 +239,23 @@ impl<N: Network> Ledger<N> {
     pub fn to_ledger_proof(&self, commitment: N::Commitment) -> Result<LedgerProof<N>> {
         self.canon_blocks.to_ledger_proof(commitment)
     }
+
+    ///
+    /// Returns a payment receipt for the given record with the current ledger root.
+    ///
+    pub fn to_payment_receipt(&self, record: &Record<N>) -> Result<PaymentReceipt<N>> {
+        PaymentReceipt::new(record, self.to_ledger_proof(record.commitment())?)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{testnet1::Testnet1, testnet2::Testnet2};
+    use snarkvm_utilities::{FromBytes, ToBytes};
 
     use rand::thread_rng;
+    use std::str::FromStr;
 
     #[test]
     fn test_new() {
@@ -240,6 +266,329 @@ mod tests {
         assert_eq!(0, ledger.latest_block_height());
     }
 
+    #[test]
+    fn test_get_program_events() {
+        let ledger = Ledger::<Testnet2>::new().unwrap();
+
+        // The genesis block does not contain program events.
+        let events = ledger.get_program_events(&EventFilter::new(), 0, 0).unwrap();
+        assert!(events.is_empty());
+
+        // An inverted block range is rejected.
+        assert!(ledger.get_program_events(&EventFilter::new(), 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_scan_account() {
+        let rng = &mut thread_rng();
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let account = Account::<Testnet2>::new(rng);
+        let compute_key = account.private_key().to_compute_key();
+
+        // The genesis block does not contain records for a new account.
+        let mut scanner = AccountScanner::new(account.view_key(), Some(compute_key.clone()));
+        assert!(ledger.scan_account(&mut scanner, 0).unwrap().is_empty());
+        assert_eq!(1, scanner.checkpoint());
+
+        // Scanning beyond the latest block height is rejected, and does not advance the checkpoint.
+        assert!(ledger.scan_account(&mut scanner, 1).is_err());
+        assert_eq!(1, scanner.checkpoint());
+
+        ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng, -1)
+            .unwrap();
+
+        // Resume scanning from the checkpoint, and find the coinbase record.
+        let records = ledger.scan_account(&mut scanner, 1).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!(1, records[0].block_height);
+        assert_eq!(account.address(), records[0].record.owner());
+        assert_eq!(records[0].record.commitment(), records[0].commitment);
+        assert!(!records[0].is_spent());
+        assert_eq!(1, scanner.unspent_records().count());
+
+        // A scanner starting from a later checkpoint does not find the record.
+        let mut scanner = AccountScanner::from_checkpoint(account.view_key(), Some(compute_key), 2, vec![]).unwrap();
+        assert!(ledger.scan_account(&mut scanner, 1).is_err());
+        assert!(scanner.records().is_empty());
+    }
+
+    #[test]
+    fn test_scan_account_spent_records() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let account = Account::<Testnet2>::new(rng);
+        let compute_key = account.private_key().to_compute_key();
+        let record = ledger
+            .mine_next_block(account.address(), true, &terminator, rng, -1)
+            .unwrap();
+
+        // Scan the coinbase record, and keep it to resume scanning from checkpoints.
+        let mut scanner = AccountScanner::new(account.view_key(), Some(compute_key.clone()));
+        let records = ledger.scan_account(&mut scanner, 1).unwrap();
+        assert_eq!(1, records.len());
+        let mut checkpoint_scanner =
+            AccountScanner::from_checkpoint(account.view_key(), Some(compute_key.clone()), 2, records.clone()).unwrap();
+
+        // Ensure a checkpoint may not include records at or after it, or records of another account.
+        assert!(AccountScanner::from_checkpoint(account.view_key(), None, 1, records.clone()).is_err());
+        let other = Account::<Testnet2>::new(rng);
+        assert!(AccountScanner::from_checkpoint(other.view_key(), None, 2, records.clone()).is_err());
+
+        // Spend the coinbase record in the next block.
+        let ledger_proof = ledger.to_ledger_proof(record.commitment()).unwrap();
+        let request = Request::new_transfer(
+            account.private_key(),
+            vec![record],
+            vec![ledger_proof, LedgerProof::default()],
+            other.address(),
+            AleoAmount::from_gates(1),
+            AleoAmount::ZERO,
+            false,
+            rng,
+        )
+        .unwrap();
+        let (vm, _) = VirtualMachine::<Testnet2>::new(ledger.latest_ledger_root())
+            .unwrap()
+            .execute(&request, rng)
+            .unwrap();
+        ledger.add_unconfirmed_transaction(&vm.finalize().unwrap()).unwrap();
+        ledger
+            .mine_next_block(account.address(), true, &terminator, rng, -1)
+            .unwrap();
+
+        // Ensure the scanner reports the record as spent, and finds the coinbase record and the change.
+        let new_records = ledger.scan_account(&mut scanner, 2).unwrap();
+        assert_eq!(2, new_records.len());
+        assert_eq!(Some(2), scanner.records()[0].spent_height);
+        assert_eq!(2, scanner.unspent_records().count());
+
+        // Ensure the scanner resumed from the checkpoint reports the record as spent.
+        assert_eq!(2, ledger.scan_account(&mut checkpoint_scanner, 2).unwrap().len());
+        assert_eq!(Some(2), checkpoint_scanner.records()[0].spent_height);
+        assert_eq!(scanner.records(), checkpoint_scanner.records());
+
+        // Ensure a scanner resumed from a checkpoint after the spend reports the record as spent.
+        ledger
+            .mine_next_block(account.address(), true, &terminator, rng, -1)
+            .unwrap();
+        let mut checkpoint_scanner =
+            AccountScanner::from_checkpoint(account.view_key(), Some(compute_key), 3, records).unwrap();
+        assert_eq!(1, ledger.scan_account(&mut checkpoint_scanner, 3).unwrap().len());
+        assert_eq!(Some(2), checkpoint_scanner.records()[0].spent_height);
+        assert_eq!(1, checkpoint_scanner.unspent_records().count());
+
+        // Ensure a scanner without a compute key does not track spent records.
+        let mut scanner = AccountScanner::new(account.view_key(), None);
+        assert_eq!(4, ledger.scan_account(&mut scanner, 3).unwrap().len());
+        assert_eq!(4, scanner.unspent_records().count());
+    }
+
+    #[test]
+    fn test_payment_receipt() {
+        let rng = &mut thread_rng();
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let account = Account::<Testnet2>::new(rng);
+        ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng, -1)
+            .unwrap();
+
+        let mut scanner = AccountScanner::new(account.view_key(), None);
+        let records = ledger.scan_account(&mut scanner, 1).unwrap();
+        let record = &records[0].record;
+
+        // Issue a receipt for the coinbase record, and verify it with the ledger root.
+        let ledger_root = ledger.latest_ledger_root();
+        let receipt = ledger.to_payment_receipt(record).unwrap();
+        assert_eq!(record, &receipt.verify(&ledger_root).unwrap());
+        assert!(receipt.is_valid(&ledger_root, &account.address(), record.value()));
+        assert!(!receipt.is_valid(&ledger_root, &Account::<Testnet2>::new(rng).address(), record.value()));
+        assert!(!receipt.is_valid(&ledger_root, &account.address(), AleoAmount::from_gates(1)));
+
+        // Ensure the receipt is rejected for a different ledger root.
+        assert!(receipt
+            .verify(&Ledger::<Testnet2>::new().unwrap().latest_ledger_root())
+            .is_err());
+
+        // Serialize and deserialize the receipt.
+        let candidate = PaymentReceipt::<Testnet2>::from_str(&receipt.to_string()).unwrap();
+        assert_eq!(record, &candidate.verify(&ledger_root).unwrap());
+        let candidate = PaymentReceipt::<Testnet2>::read_le(&receipt.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(record, &candidate.verify(&ledger_root).unwrap());
+
+        // Ensure a receipt with a tampered ledger proof fails to decode.
+        // The commitment is the last field of the receipt, and flipping its lowest bit keeps it canonical.
+        let receipt_bytes = receipt.to_bytes_le().unwrap();
+        let mut tampered = receipt_bytes.clone();
+        let commitment_index = tampered.len() - 32;
+        tampered[commitment_index] ^= 1;
+        assert!(PaymentReceipt::<Testnet2>::read_le(&tampered[..]).is_err());
+
+        let mut json: serde_json::Value = serde_json::from_str(&receipt.to_string()).unwrap();
+        let ledger_proof = receipt.ledger_proof().to_bytes_le().unwrap();
+        let mut tampered_proof = ledger_proof.clone();
+        tampered_proof[ledger_proof.len() - 32] ^= 1;
+        json["ledger_proof"] = serde_json::json!(hex::encode(tampered_proof));
+        assert!(PaymentReceipt::<Testnet2>::from_str(&json.to_string()).is_err());
+
+        // Ensure a receipt with a tampered record view key is not valid.
+        // The record view key follows the ciphertext, and flipping its lowest bit keeps it canonical.
+        let mut tampered = receipt_bytes;
+        tampered[receipt.ciphertext().to_bytes_le().unwrap().len()] ^= 1;
+        let candidate = PaymentReceipt::<Testnet2>::read_le(&tampered[..]).unwrap();
+        assert!(candidate.verify(&ledger_root).is_err());
+        assert!(!candidate.is_valid(&ledger_root, &account.address(), record.value()));
+
+        // Ensure a receipt cannot be built from a mismatching ledger proof.
+        let genesis_block = ledger.canon_blocks.get_block(0).unwrap();
+        let genesis_commitment = *genesis_block
+            .to_coinbase_transaction()
+            .unwrap()
+            .commitments()
+            .next()
+            .unwrap();
+        let genesis_ledger_proof = ledger.to_ledger_proof(genesis_commitment).unwrap();
+        assert!(PaymentReceipt::new(record, genesis_ledger_proof).is_err());
+    }
+
+    #[test]
+    fn test_watch_wallet_file() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+        let compute_key = account.private_key().to_compute_key();
+
+        // The genesis block does not contain records for a new account.
+        let mut wallet = WatchWallet::new(account.view_key(), Some(compute_key.clone()));
+        assert_eq!(None, wallet.latest_block_height());
+        assert!(wallet.add_next_block(Testnet2::genesis_block()).unwrap().is_empty());
+        assert_eq!(Some(0), wallet.latest_block_height());
+        assert_eq!(Some(Testnet2::genesis_block().hash()), wallet.latest_block_hash());
+        assert_eq!(AleoAmount::ZERO, wallet.balance().unwrap());
+
+        // A block that does not extend the latest block is rejected.
+        assert!(wallet.add_next_block(Testnet2::genesis_block()).is_err());
+
+        // The wallet cannot be rolled back to a future block height.
+        assert!(wallet.rollback_to(1).is_err());
+        wallet.rollback_to(0).unwrap();
+        assert_eq!(Some(0), wallet.latest_block_height());
+
+        // Ensure the wallet state round trips.
+        let bytes = wallet.to_bytes_le().unwrap();
+        let candidate = WatchWallet::from_bytes_le(&bytes, account.view_key(), Some(compute_key)).unwrap();
+        assert_eq!(wallet.latest_block_height(), candidate.latest_block_height());
+        assert_eq!(wallet.latest_block_hash(), candidate.latest_block_hash());
+        assert_eq!(wallet.records(), candidate.records());
+
+        // Ensure the wallet state is rejected for another account, network, or version.
+        let other_account = Account::<Testnet2>::new(rng);
+        assert!(WatchWallet::from_bytes_le(&bytes, other_account.view_key(), None).is_err());
+        let mut tampered = bytes.clone();
+        tampered[1] = 1;
+        assert!(WatchWallet::from_bytes_le(&tampered, account.view_key(), None).is_err());
+        let mut tampered = bytes;
+        tampered[0] = WATCH_WALLET_VERSION + 1;
+        assert!(WatchWallet::from_bytes_le(&tampered, account.view_key(), None).is_err());
+    }
+
+    #[test]
+    fn test_watch_wallet() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let account = Account::<Testnet2>::new(rng);
+        let compute_key = account.private_key().to_compute_key();
+
+        let mut wallet = WatchWallet::new(account.view_key(), Some(compute_key.clone()));
+        assert!(wallet
+            .add_next_block(&ledger.latest_block().unwrap())
+            .unwrap()
+            .is_empty());
+
+        // Mine a block to the account, and find the coinbase record.
+        let coinbase_record = ledger
+            .mine_next_block(account.address(), true, &terminator, rng, -1)
+            .unwrap();
+        let records = wallet.add_next_block(&ledger.latest_block().unwrap()).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!(coinbase_record, records[0].record);
+        assert_eq!(coinbase_record.value(), wallet.balance().unwrap());
+
+        // Ensure the wallet survives a restart.
+        let path = std::env::temp_dir().join(format!("watch_wallet_{}", account.address()));
+        wallet.save(&path).unwrap();
+        let loaded = WatchWallet::load(&path, account.view_key(), Some(compute_key)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(wallet.latest_block_hash(), loaded.latest_block_hash());
+        assert_eq!(wallet.records(), loaded.records());
+        assert_eq!(wallet.balance().unwrap(), loaded.balance().unwrap());
+
+        // Spend the coinbase record in a block mined to another account.
+        let other_account = Account::<Testnet2>::new(rng);
+        let ledger_proof = ledger.to_ledger_proof(coinbase_record.commitment()).unwrap();
+        let request = Request::new_transfer(
+            account.private_key(),
+            vec![coinbase_record.clone()],
+            vec![ledger_proof, LedgerProof::default()],
+            other_account.address(),
+            AleoAmount::from_gates(1),
+            AleoAmount::ZERO,
+            false,
+            rng,
+        )
+        .unwrap();
+        let (vm, _) = VirtualMachine::<Testnet2>::new(ledger.latest_ledger_root())
+            .unwrap()
+            .execute(&request, rng)
+            .unwrap();
+        ledger.add_unconfirmed_transaction(&vm.finalize().unwrap()).unwrap();
+        ledger
+            .mine_next_block(other_account.address(), true, &terminator, rng, -1)
+            .unwrap();
+
+        // Ensure the spent record is marked, and the balance drops to the change.
+        let records = wallet.add_next_block(&ledger.latest_block().unwrap()).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!(Some(2), wallet.records()[0].spent_height);
+        assert_eq!(1, wallet.unspent_records().count());
+        let change = coinbase_record.value().sub(AleoAmount::from_gates(1));
+        assert_eq!(change, wallet.balance().unwrap());
+
+        // Ensure rolling back before the spend restores the record and the balance.
+        wallet.rollback_to(1).unwrap();
+        assert_eq!(Some(1), wallet.latest_block_height());
+        assert_eq!(loaded.records(), wallet.records());
+        assert!(!wallet.records()[0].is_spent());
+        assert_eq!(coinbase_record.value(), wallet.balance().unwrap());
+
+        // Ensure the spend is marked again when the block is added back.
+        wallet.add_next_block(&ledger.latest_block().unwrap()).unwrap();
+        assert_eq!(change, wallet.balance().unwrap());
+
+        // Mine a competing chain, whose blocks do not extend the latest block of the wallet.
+        let mut fork = Ledger::<Testnet2>::new().unwrap();
+        for _ in 0..3 {
+            fork.mine_next_block(other_account.address(), true, &terminator, rng, -1)
+                .unwrap();
+        }
+        assert!(wallet.add_next_block(&fork.latest_block().unwrap()).is_err());
+
+        // Roll back to the common ancestor, and switch to the competing chain.
+        wallet.rollback_to(0).unwrap();
+        assert!(wallet.records().is_empty());
+        for block_height in 1..=3 {
+            let block = fork.canon_blocks.get_block(block_height).unwrap();
+            assert!(wallet.add_next_block(&block).unwrap().is_empty());
+        }
+        assert_eq!(Some(3), wallet.latest_block_height());
+        assert_eq!(AleoAmount::ZERO, wallet.balance().unwrap());
+    }
+
     #[test]
     fn test_mine_next_block() {
         let rng = &mut thread_rng();
@@ -249,7 +598,7 @@ mod tests {
 
             assert_eq!(0, ledger.latest_block_height());
             ledger
-                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng, -1)
                 .unwrap();
             assert_eq!(1, ledger.latest_block_height());
         }
@@ -259,7 +608,7 @@ mod tests {
 
             assert_eq!(0, ledger.latest_block_height());
             ledger
-                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng)
+                .mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng, -1)
                 .unwrap();
             assert_eq!(1, ledger.latest_block_height());
         }
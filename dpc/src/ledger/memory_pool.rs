@@ -61,7 +61,7 @@ impl<N: Network> MemoryPool<N> {
         }
 
         // Ensure the transaction does not attempt to mint new value.
-        if transaction.value_balance().is_negative() {
+        if transaction.value_balance()?.is_negative() {
             return Err(anyhow!("The unconfirmed transaction is attempting to mint new value"));
         }
 
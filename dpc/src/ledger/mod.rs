@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod account_scanner;
+pub use account_scanner::*;
+
 pub mod blocks;
 pub use blocks::*;
 
@@ -28,3 +31,6 @@ pub use ledger_tree::*;
 
 pub mod memory_pool;
 pub use memory_pool::*;
+
+pub mod watch_wallet;
+pub use watch_wallet::*;
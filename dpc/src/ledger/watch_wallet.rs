@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::{collections::VecDeque, fs, path::Path};
+
+/// The version of the watch wallet file format.
+pub const WATCH_WALLET_VERSION: u8 = 1;
+
+/// A watch-only wallet, which tracks the records and balance of an account from its view key.
+///
+/// The wallet consumes blocks one at a time with an account scanner, and can be rolled back to a previous
+/// height on a reorg, up to the maximum fork depth of the network. If a compute key is given, the wallet also
+/// tracks which of the owned records are spent; otherwise, all owned records are considered unspent.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct WatchWallet<N: Network> {
+    /// The address of the account.
+    address: Address<N>,
+    /// The scanner of the account, whose checkpoint is the height of the next block to add.
+    scanner: AccountScanner<N>,
+    /// The hashes of the most recent blocks, up to the maximum fork depth, ending with the latest block.
+    block_hashes: VecDeque<N::BlockHash>,
+}
+
+impl<N: Network> WatchWallet<N> {
+    /// Returns a new watch wallet, starting from the genesis block.
+    pub fn new(view_key: &ViewKey<N>, compute_key: Option<ComputeKey<N>>) -> Self {
+        Self {
+            address: view_key.into(),
+            scanner: AccountScanner::new(view_key, compute_key),
+            block_hashes: VecDeque::new(),
+        }
+    }
+
+    /// Returns the address of the account.
+    pub fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the height of the latest block added to the wallet, or `None` if no blocks have been added.
+    pub fn latest_block_height(&self) -> Option<u32> {
+        self.scanner.checkpoint().checked_sub(1)
+    }
+
+    /// Returns the hash of the latest block added to the wallet, or `None` if no blocks have been added.
+    pub fn latest_block_hash(&self) -> Option<N::BlockHash> {
+        self.block_hashes.back().copied()
+    }
+
+    /// Returns the owned records, in order of block height.
+    pub fn records(&self) -> &[OwnedRecord<N>] {
+        self.scanner.records()
+    }
+
+    /// Returns the owned records that are not known to be spent.
+    pub fn unspent_records(&self) -> impl Iterator<Item = &OwnedRecord<N>> + '_ {
+        self.scanner.unspent_records()
+    }
+
+    /// Returns the sum of the values of the unspent records.
+    pub fn balance(&self) -> Result<AleoAmount> {
+        Ok(self
+            .unspent_records()
+            .map(|record| record.record.value())
+            .sum::<Result<AleoAmount, _>>()?)
+    }
+
+    /// Adds the given block, which must extend the latest block, and returns the newly-found owned records.
+    pub fn add_next_block(&mut self, block: &Block<N>) -> Result<Vec<OwnedRecord<N>>> {
+        // Ensure the block extends the latest block of the wallet.
+        if let Some(latest_block_hash) = self.latest_block_hash() {
+            if block.previous_block_hash() != latest_block_hash {
+                return Err(anyhow!(
+                    "Block {} does not extend the latest block {}, the wallet must be rolled back",
+                    block.hash(),
+                    latest_block_hash
+                ));
+            }
+        }
+
+        // Scan the block, which ensures it is at the next block height.
+        let new_records = self.scanner.scan_block(block)?;
+
+        // Advance the latest block, only keeping the block hashes within the maximum fork depth.
+        self.block_hashes.push_back(block.hash());
+        if self.block_hashes.len() > N::ALEO_MAXIMUM_FORK_DEPTH as usize + 1 {
+            self.block_hashes.pop_front();
+        }
+
+        Ok(new_records)
+    }
+
+    /// Rolls back the wallet to the given block height, removing the records
+    /// and spends from all blocks after it, up to the maximum fork depth.
+    pub fn rollback_to(&mut self, block_height: u32) -> Result<()> {
+        let latest_block_height = match self.latest_block_height() {
+            Some(latest_block_height) if block_height <= latest_block_height => latest_block_height,
+            _ => {
+                return Err(anyhow!(
+                    "Cannot roll back the wallet to future block height {}",
+                    block_height
+                ))
+            }
+        };
+
+        let num_blocks = (latest_block_height - block_height) as usize;
+        if num_blocks >= self.block_hashes.len() {
+            return Err(anyhow!(
+                "Cannot roll back the wallet by {} blocks, exceeding the maximum fork depth",
+                num_blocks
+            ));
+        }
+
+        // Remove the records and spends after the given height, then the blocks after it.
+        self.scanner.rollback_to(block_height)?;
+        self.block_hashes.truncate(self.block_hashes.len() - num_blocks);
+
+        Ok(())
+    }
+
+    /// Writes the state of the wallet to the given file, replacing its previous state.
+    /// Note: The file contains the decrypted records of the account, but not its keys.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        // Write to a temporary file first, so that a failure does not corrupt the previous state.
+        let temporary_path = path.with_extension("tmp");
+        fs::write(&temporary_path, self.to_bytes_le()?)?;
+        fs::rename(&temporary_path, path)?;
+        Ok(())
+    }
+
+    /// Loads the state of the wallet for the given view key from the given file.
+    /// If a compute key is given, the serial numbers of the records are recomputed to track spent records.
+    pub fn load(path: &Path, view_key: &ViewKey<N>, compute_key: Option<ComputeKey<N>>) -> Result<Self> {
+        Self::from_bytes_le(&fs::read(path)?, view_key, compute_key)
+    }
+
+    /// Returns the state of the wallet for the given view key, from the given bytes.
+    pub fn from_bytes_le(bytes: &[u8], view_key: &ViewKey<N>, compute_key: Option<ComputeKey<N>>) -> Result<Self> {
+        let mut reader = bytes;
+
+        let version: u8 = FromBytes::read_le(&mut reader)?;
+        if version != WATCH_WALLET_VERSION {
+            return Err(anyhow!("Unsupported watch wallet version {}", version));
+        }
+        let network_id: u16 = FromBytes::read_le(&mut reader)?;
+        if network_id != N::NETWORK_ID {
+            return Err(anyhow!(
+                "Watch wallet is for network {}, expected network {}",
+                network_id,
+                N::NETWORK_ID
+            ));
+        }
+
+        let expected_address = Address::from(view_key);
+        let address: Address<N> = FromBytes::read_le(&mut reader)?;
+        if address != expected_address {
+            return Err(anyhow!(
+                "Watch wallet is for address {}, not for {}",
+                address,
+                expected_address
+            ));
+        }
+
+        let next_block_height: u32 = FromBytes::read_le(&mut reader)?;
+        let num_block_hashes: u32 = FromBytes::read_le(&mut reader)?;
+        if num_block_hashes > next_block_height
+            || num_block_hashes > N::ALEO_MAXIMUM_FORK_DEPTH + 1
+            || (num_block_hashes == 0 && next_block_height > 0)
+        {
+            return Err(anyhow!(
+                "Invalid number of block hashes in watch wallet: {}",
+                num_block_hashes
+            ));
+        }
+        let mut block_hashes = VecDeque::with_capacity(num_block_hashes as usize);
+        for _ in 0..num_block_hashes {
+            block_hashes.push_back(FromBytes::read_le(&mut reader)?);
+        }
+
+        let num_records: u32 = FromBytes::read_le(&mut reader)?;
+        let mut records = Vec::with_capacity(num_records as usize);
+        for _ in 0..num_records {
+            let block_height = FromBytes::read_le(&mut reader)?;
+            let record: Record<N> = FromBytes::read_le(&mut reader)?;
+            let is_spent: bool = FromBytes::read_le(&mut reader)?;
+            let spent_height = match is_spent {
+                true => Some(FromBytes::read_le(&mut reader)?),
+                false => None,
+            };
+            records.push(OwnedRecord {
+                block_height,
+                commitment: record.commitment(),
+                record,
+                spent_height,
+            });
+        }
+        if !reader.is_empty() {
+            return Err(anyhow!("Watch wallet contains {} trailing bytes", reader.len()));
+        }
+
+        // Resume the scanner from the next block height, which ensures the records belong to the account.
+        Ok(Self {
+            address,
+            scanner: AccountScanner::from_checkpoint(view_key, compute_key, next_block_height, records)?,
+            block_hashes,
+        })
+    }
+
+    /// Returns the bytes of the state of the wallet.
+    pub fn to_bytes_le(&self) -> Result<Vec<u8>> {
+        let mut writer = Vec::new();
+        WATCH_WALLET_VERSION.write_le(&mut writer)?;
+        N::NETWORK_ID.write_le(&mut writer)?;
+        self.address.write_le(&mut writer)?;
+
+        self.scanner.checkpoint().write_le(&mut writer)?;
+        (self.block_hashes.len() as u32).write_le(&mut writer)?;
+        for block_hash in &self.block_hashes {
+            block_hash.write_le(&mut writer)?;
+        }
+
+        (self.records().len() as u32).write_le(&mut writer)?;
+        for record in self.records() {
+            record.block_height.write_le(&mut writer)?;
+            record.record.write_le(&mut writer)?;
+            record.spent_height.is_some().write_le(&mut writer)?;
+            if let Some(spent_height) = record.spent_height {
+                spent_height.write_le(&mut writer)?;
+            }
+        }
+        Ok(writer)
+    }
+}
@@ -268,7 +268,7 @@ mod tests {
 
         // Construct a block header.
         let block_header = Testnet2::posw()
-            .mine(&block_template, &AtomicBool::new(false), &mut thread_rng())
+            .mine(&block_template, &AtomicBool::new(false), &mut thread_rng(), -1)
             .unwrap();
 
         assert_eq!(
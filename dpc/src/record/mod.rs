@@ -23,6 +23,12 @@ pub use decryption_key::*;
 pub mod payload;
 pub use payload::*;
 
+pub mod payload_schema;
+pub use payload_schema::*;
+
+pub mod payment_receipt;
+pub use payment_receipt::*;
+
 pub mod record;
 pub use record::*;
 
@@ -29,14 +29,20 @@ use std::{
 pub struct Payload<N: Network>(Vec<u8>, PhantomData<N>);
 
 impl<N: Network> Payload<N> {
-    pub fn from(bytes: &[u8]) -> Self {
-        assert!(bytes.len() <= N::RECORD_PAYLOAD_SIZE_IN_BYTES);
+    /// Returns a new payload from the given bytes, padded with zeros up to the payload size.
+    pub fn from(bytes: &[u8]) -> Result<Self, RecordError> {
+        if bytes.len() > N::RECORD_PAYLOAD_SIZE_IN_BYTES {
+            return Err(RecordError::PayloadTooLarge(
+                bytes.len(),
+                N::RECORD_PAYLOAD_SIZE_IN_BYTES,
+            ));
+        }
 
         // Pad the bytes up to PAYLOAD_SIZE.
         let mut buffer = bytes.to_vec();
         buffer.resize(N::RECORD_PAYLOAD_SIZE_IN_BYTES, 0u8);
 
-        Self(buffer, PhantomData)
+        Ok(Self(buffer, PhantomData))
     }
 
     pub fn is_empty(&self) -> bool {
@@ -59,7 +65,7 @@ impl<N: Network> FromBytes for Payload<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let mut buffer = vec![0u8; N::RECORD_PAYLOAD_SIZE_IN_BYTES];
         reader.read_exact(&mut buffer)?;
-        Ok(Self::from(&buffer))
+        Ok(Self(buffer, PhantomData))
     }
 }
 
@@ -107,7 +113,7 @@ impl<'de, N: Network> Deserialize<'de> for Payload<N> {
 
 impl<N: Network> Default for Payload<N> {
     fn default() -> Self {
-        Self::from(&[])
+        Self(vec![0u8; N::RECORD_PAYLOAD_SIZE_IN_BYTES], PhantomData)
     }
 }
 
@@ -126,13 +132,20 @@ mod tests {
         // Create a random byte array, construct a payload from it, and check its byte array matches.
         for i in 0..Testnet2::RECORD_PAYLOAD_SIZE_IN_BYTES {
             let expected_payload = (0..i).map(|_| u8::rand(rng)).collect::<Vec<u8>>();
-            let candidate_payload = Payload::<Testnet2>::from(&expected_payload).to_bytes_le().unwrap();
+            let candidate_payload = Payload::<Testnet2>::from(&expected_payload)
+                .unwrap()
+                .to_bytes_le()
+                .unwrap();
             assert_eq!(expected_payload, candidate_payload[0..i]);
             assert_eq!(
                 vec![0u8; Testnet2::RECORD_PAYLOAD_SIZE_IN_BYTES - i],
                 candidate_payload[i..]
             );
         }
+
+        // Check that an oversized byte array is rejected.
+        let oversized_payload = vec![0u8; Testnet2::RECORD_PAYLOAD_SIZE_IN_BYTES + 1];
+        assert!(Payload::<Testnet2>::from(&oversized_payload).is_err());
     }
 
     #[test]
@@ -143,7 +156,8 @@ mod tests {
             &(0..Testnet2::RECORD_PAYLOAD_SIZE_IN_BYTES)
                 .map(|_| u8::rand(rng))
                 .collect::<Vec<u8>>(),
-        );
+        )
+        .unwrap();
 
         // Serialize
         let expected_string = &expected_payload.to_string();
@@ -169,7 +183,8 @@ mod tests {
             &(0..Testnet2::RECORD_PAYLOAD_SIZE_IN_BYTES)
                 .map(|_| u8::rand(rng))
                 .collect::<Vec<u8>>(),
-        );
+        )
+        .unwrap();
 
         // Serialize
         let expected_bytes = expected_payload.to_bytes_le().unwrap();
@@ -0,0 +1,481 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Address, Network, Payload, RecordError};
+use snarkvm_fields::{Field, One, PrimeField};
+use snarkvm_gadgets::{
+    bits::Boolean,
+    fields::FpGadget,
+    integers::{
+        int::{Int16, Int32, Int64, Int8},
+        uint::{UInt16, UInt32, UInt64, UInt8},
+    },
+    traits::{eq::EqGadget, fields::FieldGadget, integers::integer::Integer},
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+use snarkvm_utilities::{math::div_ceil, FromBytes, ToBytes};
+
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// The type of a single field in a record payload.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PayloadType {
+    Field,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    Address,
+    Boolean,
+}
+
+impl PayloadType {
+    /// Returns the number of bytes used to encode this type in a payload.
+    pub fn size_in_bytes<N: Network>(&self) -> usize {
+        match self {
+            Self::Field => div_ceil(N::InnerScalarField::size_in_bits(), 8),
+            Self::U8 | Self::I8 | Self::Boolean => 1,
+            Self::U16 | Self::I16 => 2,
+            Self::U32 | Self::I32 => 4,
+            Self::U64 | Self::I64 => 8,
+            Self::Address => div_ceil(N::ProgramBaseField::size_in_bits(), 8),
+        }
+    }
+}
+
+/// A typed value stored in a record payload.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "N: Network"),
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub enum PayloadValue<N: Network> {
+    Field(N::InnerScalarField),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    Address(Address<N>),
+    Boolean(bool),
+}
+
+impl<N: Network> PayloadValue<N> {
+    /// Returns the type of the payload value.
+    pub fn payload_type(&self) -> PayloadType {
+        match self {
+            Self::Field(..) => PayloadType::Field,
+            Self::U8(..) => PayloadType::U8,
+            Self::U16(..) => PayloadType::U16,
+            Self::U32(..) => PayloadType::U32,
+            Self::U64(..) => PayloadType::U64,
+            Self::I8(..) => PayloadType::I8,
+            Self::I16(..) => PayloadType::I16,
+            Self::I32(..) => PayloadType::I32,
+            Self::I64(..) => PayloadType::I64,
+            Self::Address(..) => PayloadType::Address,
+            Self::Boolean(..) => PayloadType::Boolean,
+        }
+    }
+
+    /// Returns the little-endian byte encoding of the payload value.
+    fn to_bytes_le(self) -> Result<Vec<u8>, RecordError> {
+        Ok(match self {
+            Self::Field(value) => value.to_bytes_le()?,
+            Self::U8(value) => value.to_le_bytes().to_vec(),
+            Self::U16(value) => value.to_le_bytes().to_vec(),
+            Self::U32(value) => value.to_le_bytes().to_vec(),
+            Self::U64(value) => value.to_le_bytes().to_vec(),
+            Self::I8(value) => value.to_le_bytes().to_vec(),
+            Self::I16(value) => value.to_le_bytes().to_vec(),
+            Self::I32(value) => value.to_le_bytes().to_vec(),
+            Self::I64(value) => value.to_le_bytes().to_vec(),
+            Self::Address(value) => value.to_bytes_le()?,
+            Self::Boolean(value) => vec![value as u8],
+        })
+    }
+
+    /// Returns the payload value of the given type from its little-endian byte encoding.
+    fn from_bytes_le(payload_type: PayloadType, bytes: &[u8]) -> Result<Self, RecordError> {
+        Ok(match payload_type {
+            PayloadType::Field => Self::Field(FromBytes::read_le(bytes)?),
+            PayloadType::U8 => Self::U8(FromBytes::read_le(bytes)?),
+            PayloadType::U16 => Self::U16(FromBytes::read_le(bytes)?),
+            PayloadType::U32 => Self::U32(FromBytes::read_le(bytes)?),
+            PayloadType::U64 => Self::U64(FromBytes::read_le(bytes)?),
+            PayloadType::I8 => Self::I8(u8::read_le(bytes)? as i8),
+            PayloadType::I16 => Self::I16(u16::read_le(bytes)? as i16),
+            PayloadType::I32 => Self::I32(u32::read_le(bytes)? as i32),
+            PayloadType::I64 => Self::I64(u64::read_le(bytes)? as i64),
+            PayloadType::Address => Self::Address(FromBytes::read_le(bytes)?),
+            PayloadType::Boolean => match bytes[0] {
+                0 => Self::Boolean(false),
+                1 => Self::Boolean(true),
+                byte => return Err(RecordError::InvalidPayloadValue("boolean".into(), byte.to_string())),
+            },
+        })
+    }
+}
+
+/// A typed value decoded from a record payload inside a circuit.
+#[derive(Clone, Debug)]
+pub enum PayloadValueGadget<F: PrimeField> {
+    Field(FpGadget<F>),
+    U8(UInt8),
+    U16(UInt16),
+    U32(UInt32),
+    U64(UInt64),
+    I8(Int8),
+    I16(Int16),
+    I32(Int32),
+    I64(Int64),
+    /// The little-endian bytes of the address x-coordinate.
+    Address(Vec<UInt8>),
+    Boolean(Boolean),
+}
+
+/// An ordered list of types describing the layout of a record payload.
+///
+/// Values are encoded back-to-back in little-endian order, and the remainder
+/// of the payload is padded with zeros.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct PayloadSchema<N: Network> {
+    types: Vec<PayloadType>,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> PayloadSchema<N> {
+    /// Returns a new payload schema, if the given types fit in a record payload.
+    pub fn new(types: Vec<PayloadType>) -> Result<Self, RecordError> {
+        let size_in_bytes = types.iter().map(PayloadType::size_in_bytes::<N>).sum::<usize>();
+        if size_in_bytes > N::RECORD_PAYLOAD_SIZE_IN_BYTES {
+            return Err(RecordError::PayloadTooLarge(
+                size_in_bytes,
+                N::RECORD_PAYLOAD_SIZE_IN_BYTES,
+            ));
+        }
+
+        Ok(Self {
+            types,
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the types in the schema.
+    pub fn types(&self) -> &[PayloadType] {
+        &self.types
+    }
+
+    /// Returns the number of payload bytes used by the schema.
+    pub fn size_in_bytes(&self) -> usize {
+        self.types.iter().map(PayloadType::size_in_bytes::<N>).sum()
+    }
+
+    /// Encodes the given values into a payload.
+    pub fn encode(&self, values: &[PayloadValue<N>]) -> Result<Payload<N>, RecordError> {
+        if values.len() != self.types.len() {
+            return Err(RecordError::InvalidPayloadValue(
+                format!("{} values", self.types.len()),
+                format!("{} values", values.len()),
+            ));
+        }
+
+        let mut bytes = Vec::with_capacity(self.size_in_bytes());
+        for (payload_type, value) in self.types.iter().zip(values) {
+            if *payload_type != value.payload_type() {
+                return Err(RecordError::InvalidPayloadValue(
+                    format!("{:?}", payload_type),
+                    format!("{:?}", value.payload_type()),
+                ));
+            }
+            bytes.extend_from_slice(&value.to_bytes_le()?);
+        }
+
+        Payload::from(&bytes)
+    }
+
+    /// Decodes the values in the given payload.
+    pub fn decode(&self, payload: &Payload<N>) -> Result<Vec<PayloadValue<N>>, RecordError> {
+        let bytes = payload.to_bytes_le()?;
+
+        let mut values = Vec::with_capacity(self.types.len());
+        let mut offset = 0;
+        for payload_type in &self.types {
+            let size_in_bytes = payload_type.size_in_bytes::<N>();
+            values.push(PayloadValue::from_bytes_le(
+                *payload_type,
+                &bytes[offset..offset + size_in_bytes],
+            )?);
+            offset += size_in_bytes;
+        }
+
+        // Ensure the remainder of the payload is padding.
+        if bytes[offset..].iter().any(|byte| *byte != 0u8) {
+            return Err(RecordError::InvalidPayloadValue(
+                "zero padding".into(),
+                "non-zero bytes".into(),
+            ));
+        }
+
+        Ok(values)
+    }
+
+    /// Decodes the values in the given payload bytes inside a circuit.
+    ///
+    /// As in `decode`, field elements and address x-coordinates are enforced to be in canonical form,
+    /// boolean bytes are enforced to be zero or one, and the remainder of the payload is enforced to be zero.
+    /// Note that address x-coordinates are not enforced to be on the curve.
+    ///
+    /// Program functions may use this to enforce the payloads they are given, as in `PayloadFunction`.
+    pub fn decode_gadget<CS: ConstraintSystem<N::InnerScalarField>>(
+        &self,
+        mut cs: CS,
+        payload: &[UInt8],
+    ) -> Result<Vec<PayloadValueGadget<N::InnerScalarField>>, SynthesisError> {
+        if payload.len() != N::RECORD_PAYLOAD_SIZE_IN_BYTES {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut values = Vec::with_capacity(self.types.len());
+        let mut offset = 0;
+        for (i, payload_type) in self.types.iter().enumerate() {
+            let size_in_bytes = payload_type.size_in_bytes::<N>();
+            let bytes = &payload[offset..offset + size_in_bytes];
+            let bits = bytes.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+            offset += size_in_bytes;
+
+            values.push(match payload_type {
+                PayloadType::Field => {
+                    let field_cs = &mut cs.ns(|| format!("Decode field element {}", i));
+
+                    // Enforce the field element is less than the modulus.
+                    Boolean::enforce_in_field_le(field_cs.ns(|| "Enforce canonical"), &bits)?;
+
+                    let modulus_bits = N::InnerScalarField::size_in_bits();
+
+                    let mut value = FpGadget::zero(field_cs.ns(|| "zero"))?;
+                    let mut power = N::InnerScalarField::one();
+                    for (j, bit) in bits.iter().take(modulus_bits).enumerate() {
+                        let fp_bit =
+                            FpGadget::from_boolean(field_cs.ns(|| format!("convert from boolean {}", j)), *bit)?;
+                        let fp_bit_times_power =
+                            fp_bit.mul_by_constant(field_cs.ns(|| format!("multiply by power {}", j)), &power)?;
+                        value.add_in_place(field_cs.ns(|| format!("sum {}", j)), &fp_bit_times_power)?;
+                        power.double_in_place();
+                    }
+                    PayloadValueGadget::Field(value)
+                }
+                PayloadType::U8 => PayloadValueGadget::U8(bytes[0].clone()),
+                PayloadType::U16 => PayloadValueGadget::U16(UInt16::from_bits_le(&bits)),
+                PayloadType::U32 => PayloadValueGadget::U32(UInt32::from_bits_le(&bits)),
+                PayloadType::U64 => PayloadValueGadget::U64(UInt64::from_bits_le(&bits)),
+                PayloadType::I8 => PayloadValueGadget::I8(Int8::from_bits_le(&bits)),
+                PayloadType::I16 => PayloadValueGadget::I16(Int16::from_bits_le(&bits)),
+                PayloadType::I32 => PayloadValueGadget::I32(Int32::from_bits_le(&bits)),
+                PayloadType::I64 => PayloadValueGadget::I64(Int64::from_bits_le(&bits)),
+                PayloadType::Address => {
+                    // Enforce the x-coordinate is less than the modulus.
+                    let mut modulus_minus_one = N::ProgramBaseField::characteristic().to_vec();
+                    modulus_minus_one[0] -= 1;
+                    Boolean::enforce_smaller_or_equal_than_le(
+                        cs.ns(|| format!("Enforce canonical address {}", i)),
+                        &bits,
+                        modulus_minus_one,
+                    )?;
+                    PayloadValueGadget::Address(bytes.to_vec())
+                }
+                PayloadType::Boolean => {
+                    // Enforce the upper bits of the boolean byte are zero.
+                    for (j, bit) in bits.iter().enumerate().skip(1) {
+                        bit.enforce_equal(
+                            cs.ns(|| format!("Enforce boolean {} bit {} is zero", i, j)),
+                            &Boolean::constant(false),
+                        )?;
+                    }
+                    PayloadValueGadget::Boolean(bits[0])
+                }
+            });
+        }
+
+        // Ensure the remainder of the payload is padding.
+        for (i, byte) in payload[offset..].iter().enumerate() {
+            for (j, bit) in byte.to_bits_le().iter().enumerate() {
+                bit.enforce_equal(
+                    cs.ns(|| format!("Enforce padding byte {} bit {} is zero", i, j)),
+                    &Boolean::constant(false),
+                )?;
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, PrivateKey};
+    use snarkvm_r1cs::TestConstraintSystem;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type F = <Testnet2 as Network>::InnerScalarField;
+
+    #[test]
+    fn test_encode_decode() {
+        let rng = &mut thread_rng();
+
+        let schema = PayloadSchema::<Testnet2>::new(vec![
+            PayloadType::Field,
+            PayloadType::U64,
+            PayloadType::I16,
+            PayloadType::Address,
+            PayloadType::Boolean,
+        ])
+        .unwrap();
+        assert_eq!(32 + 8 + 2 + 32 + 1, schema.size_in_bytes());
+
+        let expected_values = vec![
+            PayloadValue::Field(F::rand(rng)),
+            PayloadValue::U64(u64::rand(rng)),
+            PayloadValue::I16(-12345),
+            PayloadValue::Address(PrivateKey::<Testnet2>::new(rng).to_address()),
+            PayloadValue::Boolean(true),
+        ];
+
+        let payload = schema.encode(&expected_values).unwrap();
+        assert_eq!(expected_values, schema.decode(&payload).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        // A schema that exceeds the payload size is rejected.
+        let types = vec![PayloadType::Field; Testnet2::RECORD_PAYLOAD_SIZE_IN_BYTES / 32 + 1];
+        assert!(PayloadSchema::<Testnet2>::new(types).is_err());
+
+        let schema = PayloadSchema::<Testnet2>::new(vec![PayloadType::U8, PayloadType::Boolean]).unwrap();
+
+        // Mismatched values are rejected.
+        assert!(schema.encode(&[PayloadValue::U8(1)]).is_err());
+        assert!(schema.encode(&[PayloadValue::U8(1), PayloadValue::U8(1)]).is_err());
+
+        // Malformed payloads are rejected.
+        assert!(schema.decode(&Payload::from(&[1, 2]).unwrap()).is_err());
+        assert!(schema.decode(&Payload::from(&[1, 1, 1]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_decode_gadget() {
+        let rng = &mut thread_rng();
+
+        let schema = PayloadSchema::<Testnet2>::new(vec![
+            PayloadType::Field,
+            PayloadType::U32,
+            PayloadType::I64,
+            PayloadType::Boolean,
+        ])
+        .unwrap();
+
+        let field = F::rand(rng);
+        let payload = schema
+            .encode(&[
+                PayloadValue::Field(field),
+                PayloadValue::U32(123456),
+                PayloadValue::I64(-42),
+                PayloadValue::Boolean(true),
+            ])
+            .unwrap();
+
+        let mut cs = TestConstraintSystem::<F>::new();
+        let payload_bytes = UInt8::alloc_vec(cs.ns(|| "payload"), &payload.to_bytes_le().unwrap()).unwrap();
+        let values = schema.decode_gadget(cs.ns(|| "decode"), &payload_bytes).unwrap();
+        assert!(cs.is_satisfied());
+
+        match &values[0] {
+            PayloadValueGadget::Field(value) => assert_eq!(Some(field), value.get_value()),
+            _ => panic!("Expected a field element"),
+        }
+        match &values[1] {
+            PayloadValueGadget::U32(value) => assert_eq!(Some(123456), value.value),
+            _ => panic!("Expected a u32"),
+        }
+        match &values[2] {
+            PayloadValueGadget::I64(value) => assert_eq!(Some(-42), value.value),
+            _ => panic!("Expected an i64"),
+        }
+        match &values[3] {
+            PayloadValueGadget::Boolean(value) => assert_eq!(Some(true), value.get_value()),
+            _ => panic!("Expected a boolean"),
+        }
+    }
+
+    #[test]
+    fn test_decode_gadget_rejects_non_canonical_payloads() {
+        let schema = PayloadSchema::<Testnet2>::new(vec![PayloadType::Field, PayloadType::Address]).unwrap();
+
+        let is_satisfied = |bytes: &[u8]| {
+            let mut cs = TestConstraintSystem::<F>::new();
+            let payload_bytes = UInt8::alloc_vec(cs.ns(|| "payload"), bytes).unwrap();
+            schema.decode_gadget(cs.ns(|| "decode"), &payload_bytes).unwrap();
+            cs.is_satisfied()
+        };
+
+        let modulus_bytes = |limbs: &[u64]| limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect::<Vec<_>>();
+        let address = PrivateKey::<Testnet2>::new(&mut thread_rng()).to_address();
+        let payload = schema
+            .encode(&[PayloadValue::Field(F::one()), PayloadValue::Address(address)])
+            .unwrap()
+            .to_bytes_le()
+            .unwrap();
+        assert!(is_satisfied(&payload));
+
+        // A field element equal to the modulus is rejected.
+        let mut bytes = payload.clone();
+        bytes[..32].copy_from_slice(&modulus_bytes(F::characteristic()));
+        assert!(schema.decode(&Payload::from(&bytes).unwrap()).is_err());
+        assert!(!is_satisfied(&bytes));
+
+        // An address x-coordinate equal to the modulus is rejected.
+        let mut bytes = payload.clone();
+        bytes[32..64].copy_from_slice(&modulus_bytes(<Testnet2 as Network>::ProgramBaseField::characteristic()));
+        assert!(schema.decode(&Payload::from(&bytes).unwrap()).is_err());
+        assert!(!is_satisfied(&bytes));
+
+        // Non-zero padding is rejected.
+        let mut bytes = payload;
+        bytes[64] = 1;
+        assert!(schema.decode(&Payload::from(&bytes).unwrap()).is_err());
+        assert!(!is_satisfied(&bytes));
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+
+use anyhow::{anyhow, Result};
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt,
+    io::{Read, Result as IoResult, Write},
+    str::FromStr,
+};
+
+/// A receipt proving that a record commitment in the ledger pays a given owner a given value.
+///
+/// The receipt discloses the record view key of a single record, which decrypts only that
+/// record ciphertext, and does not reveal the account view key of the sender or the owner.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct PaymentReceipt<N: Network> {
+    /// The ciphertext of the record.
+    ciphertext: N::RecordCiphertext,
+    /// The record view key, used to decrypt the record ciphertext.
+    record_view_key: N::RecordViewKey,
+    /// The proof of inclusion of the record commitment in the ledger.
+    ledger_proof: LedgerProof<N>,
+}
+
+impl<N: Network> PaymentReceipt<N> {
+    /// Returns a new payment receipt for the given record and its ledger proof.
+    pub fn new(record: &Record<N>, ledger_proof: LedgerProof<N>) -> Result<Self> {
+        // Ensure the ledger proof is for the given record.
+        if ledger_proof.commitment() != record.commitment() {
+            return Err(anyhow!(
+                "Ledger proof is for commitment {}, not for record commitment {}",
+                ledger_proof.commitment(),
+                record.commitment()
+            ));
+        }
+
+        Ok(Self {
+            ciphertext: record.ciphertext().clone(),
+            record_view_key: record.record_view_key().clone(),
+            ledger_proof,
+        })
+    }
+
+    ///
+    /// Returns the record paid by this receipt, if the receipt is valid for the given ledger root.
+    ///
+    /// The caller is responsible for checking that the ledger root is in the canonical chain,
+    /// and for checking the owner and value of the returned record.
+    ///
+    pub fn verify(&self, ledger_root: &N::LedgerRoot) -> Result<Record<N>> {
+        // Ensure the ledger proof is for the given ledger root.
+        if self.ledger_proof.ledger_root() != *ledger_root {
+            return Err(anyhow!(
+                "Payment receipt is for ledger root {}, not for ledger root {}",
+                self.ledger_proof.ledger_root(),
+                ledger_root
+            ));
+        }
+
+        // Ensure the ledger proof is not a dummy proof, which is not verifiable.
+        if self.ledger_proof.block_hash() == Default::default() {
+            return Err(anyhow!("Payment receipt contains a dummy ledger proof"));
+        }
+
+        // Ensure the record ciphertext is the one proven to be in the ledger.
+        if self.ciphertext.commitment() != self.ledger_proof.commitment() {
+            return Err(anyhow!(
+                "Payment receipt ciphertext does not match commitment {}",
+                self.ledger_proof.commitment()
+            ));
+        }
+
+        // Decrypt the record ciphertext with the record view key.
+        let decryption_key = DecryptionKey::from_record_view_key(&self.record_view_key);
+        Ok(Record::decrypt(&decryption_key, &self.ciphertext)?)
+    }
+
+    /// Returns `true` if the receipt proves a payment of the given value to the given owner, for the given ledger root.
+    pub fn is_valid(&self, ledger_root: &N::LedgerRoot, owner: &Address<N>, value: AleoAmount) -> bool {
+        match self.verify(ledger_root) {
+            Ok(record) => record.owner() == *owner && record.value() == value,
+            Err(error) => {
+                eprintln!("Failed to verify payment receipt: {}", error);
+                false
+            }
+        }
+    }
+
+    /// Returns the record commitment.
+    pub fn commitment(&self) -> N::Commitment {
+        self.ledger_proof.commitment()
+    }
+
+    /// Returns the ledger root of the ledger proof.
+    pub fn ledger_root(&self) -> N::LedgerRoot {
+        self.ledger_proof.ledger_root()
+    }
+
+    /// Returns a reference to the record ciphertext.
+    pub fn ciphertext(&self) -> &N::RecordCiphertext {
+        &self.ciphertext
+    }
+
+    /// Returns a reference to the ledger proof.
+    pub fn ledger_proof(&self) -> &LedgerProof<N> {
+        &self.ledger_proof
+    }
+}
+
+impl<N: Network> FromBytes for PaymentReceipt<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let ciphertext = FromBytes::read_le(&mut reader)?;
+        let record_view_key = FromBytes::read_le(&mut reader)?;
+        let ledger_proof = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self {
+            ciphertext,
+            record_view_key,
+            ledger_proof,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for PaymentReceipt<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.ciphertext.write_le(&mut writer)?;
+        self.record_view_key.write_le(&mut writer)?;
+        self.ledger_proof.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromStr for PaymentReceipt<N> {
+    type Err = anyhow::Error;
+
+    fn from_str(receipt: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(receipt)?)
+    }
+}
+
+impl<N: Network> fmt::Display for PaymentReceipt<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err::<fmt::Error, _>(serde::ser::Error::custom)?
+        )
+    }
+}
+
+impl<N: Network> Serialize for PaymentReceipt<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let ledger_proof = self.ledger_proof.to_bytes_le().map_err(serde::ser::Error::custom)?;
+
+                let mut receipt = serializer.serialize_struct("PaymentReceipt", 4)?;
+                receipt.serialize_field("commitment", &self.commitment())?;
+                receipt.serialize_field("ciphertext", &self.ciphertext)?;
+                receipt.serialize_field("record_view_key", &self.record_view_key)?;
+                receipt.serialize_field("ledger_proof", &hex::encode(ledger_proof))?;
+                receipt.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for PaymentReceipt<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let receipt = serde_json::Value::deserialize(deserializer)?;
+                let commitment: N::Commitment =
+                    serde_json::from_value(receipt["commitment"].clone()).map_err(de::Error::custom)?;
+
+                // Recover the ledger proof.
+                let ledger_proof: String =
+                    serde_json::from_value(receipt["ledger_proof"].clone()).map_err(de::Error::custom)?;
+                let ledger_proof = hex::decode(ledger_proof).map_err(de::Error::custom)?;
+
+                let receipt = Self {
+                    ciphertext: serde_json::from_value(receipt["ciphertext"].clone()).map_err(de::Error::custom)?,
+                    record_view_key: serde_json::from_value(receipt["record_view_key"].clone())
+                        .map_err(de::Error::custom)?,
+                    ledger_proof: FromBytes::read_le(&ledger_proof[..]).map_err(de::Error::custom)?,
+                };
+
+                // Ensure the commitment matches.
+                match commitment == receipt.commitment() {
+                    true => Ok(receipt),
+                    false => Err(de::Error::custom(format!(
+                        "Invalid commitment. Expected {}, found {}",
+                        commitment,
+                        receipt.commitment()
+                    ))),
+                }
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "payment receipt"),
+        }
+    }
+}
@@ -100,7 +100,11 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     const NETWORK_ID: u16;
     const NETWORK_NAME: &'static str;
 
+    /// The number of input records in a standard transition, which requests with fewer inputs are padded to.
     const NUM_INPUT_RECORDS: usize;
+    /// The maximum number of input records consumed by a transition, as a power of two.
+    const MAX_INPUT_RECORDS: usize;
+    /// The number of output records produced by a transition.
     const NUM_OUTPUT_RECORDS: usize;
     const NUM_TOTAL_RECORDS: usize = Self::NUM_INPUT_RECORDS + Self::NUM_OUTPUT_RECORDS;
     const NUM_TRANSITIONS: u8;
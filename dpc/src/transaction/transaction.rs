@@ -127,7 +127,11 @@ impl<N: Network> Transaction<N> {
         }
 
         // Returns `false` if the number of serial numbers in the transaction is incorrect.
-        if self.serial_numbers().count() != num_transitions * N::NUM_INPUT_RECORDS {
+        if self
+            .transitions
+            .iter()
+            .any(|transition| !Transition::<N>::is_valid_num_serial_numbers(transition.serial_numbers().count()))
+        {
             eprintln!("Transaction contains incorrect number of serial numbers");
             return false;
         }
@@ -173,6 +177,12 @@ impl<N: Network> Transaction<N> {
             return false;
         }
 
+        // Returns `false` if the value balance of the transaction overflows.
+        if let Err(error) = self.value_balance() {
+            eprintln!("Transaction contains an invalid value balance: {}", error);
+            return false;
+        }
+
         // Initialize a local transitions tree.
         let mut transitions = match Transitions::<N>::new() {
             Ok(transitions) => transitions,
@@ -276,11 +286,11 @@ impl<N: Network> Transaction<N> {
 
     /// Returns the value balance.
     #[inline]
-    pub fn value_balance(&self) -> AleoAmount {
-        self.transitions
+    pub fn value_balance(&self) -> Result<AleoAmount> {
+        Ok(self
+            .transitions
             .iter()
-            .map(Transition::value_balance)
-            .fold(AleoAmount::ZERO, |a, b| a.add(*b))
+            .try_fold(AleoAmount::ZERO, |a, b| a.checked_add(*b.value_balance()))?)
     }
 
     /// Returns the events.
@@ -514,7 +524,7 @@ mod tests {
         // Serialize
         let expected_bytes = expected_transaction.to_bytes_le().unwrap();
         let candidate_bytes = bincode::serialize(&expected_transaction).unwrap();
-        assert_eq!(1121, expected_bytes.len(), "Update me if serialization has changed");
+        assert_eq!(1123, expected_bytes.len(), "Update me if serialization has changed");
         // TODO (howardwu): Serialization - Handle the inconsistency between ToBytes and Serialize (off by a length encoding).
         assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
 
@@ -16,7 +16,7 @@
 
 use crate::{Address, AleoAmount, ComputeKey, FunctionType, LedgerProof, Network, Operation, PrivateKey, Record};
 use snarkvm_algorithms::SignatureScheme;
-use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
+use snarkvm_utilities::{error, to_bytes_le, FromBytes, ToBytes};
 
 use anyhow::{anyhow, Result};
 use once_cell::sync::OnceCell;
@@ -53,16 +53,8 @@ impl<N: Network> Request<N> {
     ) -> Result<Self> {
         let burner = PrivateKey::new(rng);
         let operation = Operation::Coinbase(recipient, amount);
-        let fee = AleoAmount::ZERO.sub(amount);
-        Self::new(
-            &burner,
-            vec![],
-            vec![LedgerProof::default(); N::NUM_INPUT_RECORDS],
-            operation,
-            fee,
-            is_public,
-            rng,
-        )
+        let fee = amount.checked_neg()?;
+        Self::new(&burner, vec![], vec![], operation, fee, is_public, rng)
     }
 
     /// Initializes a new transfer request.
@@ -84,17 +76,11 @@ impl<N: Network> Request<N> {
     pub fn new_noop<R: Rng + CryptoRng>(ledger_proofs: Vec<LedgerProof<N>>, rng: &mut R) -> Result<Self> {
         // Sample a burner noop private key.
         let noop_private_key = PrivateKey::new(rng);
-        let noop_address = Address::from_private_key(&noop_private_key);
-
-        // Construct the noop records.
-        let mut records = Vec::with_capacity(N::NUM_INPUT_RECORDS);
-        for _ in 0..N::NUM_INPUT_RECORDS {
-            records.push(Record::new_noop(noop_address, rng)?);
-        }
 
+        // The noop records are padded in by the request.
         Self::new(
             &noop_private_key,
-            records,
+            vec![],
             ledger_proofs,
             Operation::Noop,
             AleoAmount::ZERO,
@@ -115,14 +101,40 @@ impl<N: Network> Request<N> {
     ) -> Result<Self> {
         let caller_address = Address::from_private_key(caller);
 
+        // Ensure the function type is supported by the network.
+        let function_type = operation.function_type();
+        if !function_type.is_supported::<N>() {
+            return Err(anyhow!(
+                "Function type {:?} exceeds the network record limits",
+                function_type
+            ));
+        }
+
+        // Ensure the number of given records does not exceed the function type.
+        if records.len() > function_type.input_count() as usize {
+            return Err(anyhow!(
+                "Function type {:?} consumes at most {} records, found {}",
+                function_type,
+                function_type.input_count(),
+                records.len()
+            ));
+        }
+
         // Pad the records with noops if there is less than required.
+        let num_input_records = function_type.num_input_records::<N>();
         let mut records = records;
-        while records.len() < N::NUM_INPUT_RECORDS {
+        while records.len() < num_input_records {
             records.push(Record::new_noop(caller_address, rng)?);
         }
 
-        let mut commitments = Vec::with_capacity(N::NUM_INPUT_RECORDS);
-        for record in records.iter().take(N::NUM_INPUT_RECORDS) {
+        // Pad the ledger proofs of the noop records.
+        let mut ledger_proofs = ledger_proofs;
+        while ledger_proofs.len() < num_input_records {
+            ledger_proofs.push(LedgerProof::default());
+        }
+
+        let mut commitments = Vec::with_capacity(num_input_records);
+        for record in &records {
             // Ensure the caller and record owner match.
             if caller_address != record.owner() {
                 return Err(anyhow!("Address from caller private key does not match record owner"));
@@ -162,26 +174,45 @@ impl<N: Network> Request<N> {
 
     /// Returns `true` if the request signature is valid.
     pub fn is_valid(&self) -> bool {
+        // Ensure the function type is supported by the network.
+        let function_type = self.function_type();
+        if !function_type.is_supported::<N>() {
+            eprintln!("Function type {:?} exceeds the network record limits", function_type);
+            return false;
+        }
+
         // Ensure the number of records is correct.
-        if self.records.len() != N::NUM_INPUT_RECORDS {
+        let num_input_records = function_type.num_input_records::<N>();
+        if self.records.len() != num_input_records {
             eprintln!(
                 "Incorrect number of request records. Expected {}, found {}",
-                N::NUM_INPUT_RECORDS,
+                num_input_records,
                 self.records.len()
             );
             return false;
         }
 
         // Ensure the number of ledger proofs is correct.
-        if self.ledger_proofs.len() != N::NUM_INPUT_RECORDS {
+        if self.ledger_proofs.len() != num_input_records {
             eprintln!(
                 "Incorrect number of request ledger proofs. Expected {}, found {}",
-                N::NUM_INPUT_RECORDS,
+                num_input_records,
                 self.ledger_proofs.len()
             );
             return false;
         }
 
+        // Ensure the records beyond the input count of the function type are dummy records.
+        if self
+            .records
+            .iter()
+            .skip(function_type.input_count() as usize)
+            .any(|record| !record.is_dummy())
+        {
+            eprintln!("Request contains more non-dummy records than its function type consumes");
+            return false;
+        }
+
         // Ensure the records contain the same owner, and retrieve the owner as the caller.
         let caller = {
             let owners: HashSet<Address<N>> = self.records.iter().map(|record| record.owner()).collect();
@@ -195,7 +226,13 @@ impl<N: Network> Request<N> {
 
         // Ensure the records contains a total value that is at least the fee amount.
         if !self.operation.is_coinbase() {
-            let balance: AleoAmount = self.records.iter().map(|record| record.value()).sum();
+            let balance = match self.to_balance() {
+                Ok(balance) => balance,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    return false;
+                }
+            };
             if balance < self.fee {
                 eprintln!("Request records do not contain sufficient value for fee");
                 return false;
@@ -309,8 +346,8 @@ impl<N: Network> Request<N> {
     }
 
     /// Returns the balance of the caller.
-    pub fn to_balance(&self) -> AleoAmount {
-        self.records.iter().map(|record| record.value()).sum()
+    pub fn to_balance(&self) -> Result<AleoAmount> {
+        Ok(self.records.iter().map(|record| record.value()).sum::<Result<_, _>>()?)
     }
 
     /// Returns the program ID.
@@ -341,13 +378,18 @@ impl<N: Network> Request<N> {
 impl<N: Network> FromBytes for Request<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let mut records = Vec::with_capacity(N::NUM_INPUT_RECORDS);
-        for _ in 0..N::NUM_INPUT_RECORDS {
+        let num_records: u16 = FromBytes::read_le(&mut reader)?;
+        if num_records as usize > N::MAX_INPUT_RECORDS {
+            return Err(error("Request exceeds the maximum number of input records"));
+        }
+
+        let mut records = Vec::with_capacity(num_records as usize);
+        for _ in 0..num_records {
             records.push(FromBytes::read_le(&mut reader)?);
         }
 
-        let mut ledger_proofs = Vec::with_capacity(N::NUM_INPUT_RECORDS);
-        for _ in 0..N::NUM_INPUT_RECORDS {
+        let mut ledger_proofs = Vec::with_capacity(num_records as usize);
+        for _ in 0..num_records {
             ledger_proofs.push(FromBytes::read_le(&mut reader)?);
         }
 
@@ -356,14 +398,15 @@ impl<N: Network> FromBytes for Request<N> {
         let signature = FromBytes::read_le(&mut reader)?;
         let is_public = FromBytes::read_le(&mut reader)?;
 
-        Ok(Self::from(records, ledger_proofs, operation, fee, signature, is_public)
-            .expect("Failed to deserialize a request"))
+        Self::from(records, ledger_proofs, operation, fee, signature, is_public)
+            .map_err(|_| error("Failed to deserialize a request"))
     }
 }
 
 impl<N: Network> ToBytes for Request<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.records.len() as u16).write_le(&mut writer)?;
         self.records.write_le(&mut writer)?;
         self.ledger_proofs.write_le(&mut writer)?;
         self.operation.write_le(&mut writer)?;
@@ -114,13 +114,13 @@ impl<N: Network> FromBytes for Response<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let transition_id = FromBytes::read_le(&mut reader)?;
 
-        let mut records = Vec::with_capacity(N::NUM_INPUT_RECORDS);
-        for _ in 0..N::NUM_INPUT_RECORDS {
+        let mut records = Vec::with_capacity(N::NUM_OUTPUT_RECORDS);
+        for _ in 0..N::NUM_OUTPUT_RECORDS {
             records.push(FromBytes::read_le(&mut reader)?);
         }
 
-        let mut encryption_randomness = Vec::with_capacity(N::NUM_INPUT_RECORDS);
-        for _ in 0..N::NUM_INPUT_RECORDS {
+        let mut encryption_randomness = Vec::with_capacity(N::NUM_OUTPUT_RECORDS);
+        for _ in 0..N::NUM_OUTPUT_RECORDS {
             encryption_randomness.push(FromBytes::read_le(&mut reader)?);
         }
 
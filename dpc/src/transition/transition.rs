@@ -16,10 +16,10 @@
 
 use crate::{circuits::*, prelude::*};
 use snarkvm_algorithms::{
-    merkle_tree::{MerklePath, MerkleTree},
-    traits::SNARK,
+    merkle_tree::{MerklePath, MerkleTreeDigest},
+    traits::{MerkleParameters, SNARK},
 };
-use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::{anyhow, Result};
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
@@ -235,68 +235,130 @@ impl<N: Network> Transition<N> {
         // Convert the leaf into bytes.
         let leaf = leaf.to_bytes_le()?;
 
-        // Retrieve the transition leaves.
-        let leaves = Self::compute_transition_leaves(&self.serial_numbers, &self.commitments)?;
-
-        // Find the index of the given leaf.
-        for (index, candidate_leaf) in leaves.iter().enumerate() {
-            if *candidate_leaf == leaf {
-                let tree = MerkleTree::<N::TransitionIDParameters>::new(
-                    Arc::new(N::transition_id_parameters().clone()),
-                    &leaves,
-                )?;
-                return Ok(tree.generate_proof(index, &leaf)?);
+        // Retrieve the transition leaves and subtrees.
+        let serial_number_leaves = Self::compute_leaves(&self.serial_numbers)?;
+        let commitment_leaves = Self::compute_leaves(&self.commitments)?;
+        let serial_numbers_tree = Self::compute_subtree(&serial_number_leaves)?;
+        let commitments_tree = Self::compute_subtree(&commitment_leaves)?;
+
+        // Find the subtree and index of the given leaf.
+        let (tree, sibling_tree, index, leaf_index) = match (
+            serial_number_leaves
+                .iter()
+                .position(|candidate_leaf| *candidate_leaf == leaf),
+            commitment_leaves
+                .iter()
+                .position(|candidate_leaf| *candidate_leaf == leaf),
+        ) {
+            (Some(index), _) => (&serial_numbers_tree, &commitments_tree, index, index),
+            (None, Some(index)) => {
+                // The commitments subtree is the right child of the transition tree root.
+                (
+                    &commitments_tree,
+                    &serial_numbers_tree,
+                    index,
+                    index + (1 << (commitments_tree.len() - 1)),
+                )
             }
+            (None, None) => return Err(anyhow!("Failed to find the given element in the transition")),
+        };
+
+        // Collect the siblings from the leaf up to the subtree root, followed by the root of the sibling subtree.
+        let mut path = Vec::with_capacity(tree.len());
+        for (level, nodes) in tree.iter().take(tree.len() - 1).enumerate() {
+            path.push(nodes[(index >> level) ^ 1]);
         }
+        path.push(sibling_tree[sibling_tree.len() - 1][0]);
 
-        Err(anyhow!("Failed to find the given element in the transition"))
+        Ok(MerklePath {
+            parameters: Arc::new(N::transition_id_parameters().clone()),
+            path,
+            leaf_index: leaf_index as u64,
+        })
     }
 
     ///
     /// Returns the transition ID, which is the root of transition tree.
     ///
+    /// Transition Tree := Hash(MerkleTree(serial numbers) || MerkleTree(commitments))
+    ///
+    /// For a standard transition, this is the Merkle tree of depth `N::TRANSITION_TREE_DEPTH`
+    /// over the serial numbers and commitments. Joining more input records only deepens the
+    /// serial numbers subtree, so the inclusion proof of a commitment is always of the same depth.
+    ///
     #[inline]
     pub(crate) fn compute_transition_id(
         serial_numbers: &[N::SerialNumber],
         commitments: &[N::Commitment],
     ) -> Result<N::TransitionID> {
-        let leaves = Self::compute_transition_leaves(serial_numbers, commitments)?;
-        let tree =
-            MerkleTree::<N::TransitionIDParameters>::new(Arc::new(N::transition_id_parameters().clone()), &leaves)?;
-        Ok((*tree.root()).into())
+        let (serial_numbers_tree, commitments_tree) = Self::compute_transition_subtrees(serial_numbers, commitments)?;
+        let root = N::transition_id_parameters().hash_inner_node(
+            &serial_numbers_tree[serial_numbers_tree.len() - 1][0],
+            &commitments_tree[commitments_tree.len() - 1][0],
+        )?;
+        Ok(root.into())
     }
 
     ///
-    /// Returns an instance of the transition tree.
-    ///
-    /// Transition Tree := MerkleTree(serial numbers || commitments)
+    /// Returns the levels of the serial numbers subtree and the commitments subtree,
+    /// each from its hashed leaves up to its root.
     ///
     #[inline]
-    pub(crate) fn compute_transition_leaves(
+    #[allow(clippy::type_complexity)]
+    fn compute_transition_subtrees(
         serial_numbers: &[N::SerialNumber],
         commitments: &[N::Commitment],
-    ) -> Result<Vec<Vec<u8>>> {
-        // Construct the leaves of the transition tree.
-        let leaves: Vec<Vec<u8>> = vec![
-            // Leaf 0, 1 := serial numbers
-            serial_numbers
-                .iter()
-                .take(N::NUM_INPUT_RECORDS)
-                .map(ToBytes::to_bytes_le)
-                .collect::<Result<Vec<_>>>()?,
-            // Leaf 2, 3 := commitments
-            commitments
-                .iter()
-                .take(N::NUM_OUTPUT_RECORDS)
-                .map(ToBytes::to_bytes_le)
-                .collect::<Result<Vec<_>>>()?,
-        ]
-        .concat();
+    ) -> Result<(
+        Vec<Vec<MerkleTreeDigest<N::TransitionIDParameters>>>,
+        Vec<Vec<MerkleTreeDigest<N::TransitionIDParameters>>>,
+    )> {
+        // Ensure the number of serial numbers is a padded number of input records.
+        if !Self::is_valid_num_serial_numbers(serial_numbers.len()) {
+            return Err(anyhow!("Transition contains an invalid number of serial numbers"));
+        }
 
-        // Ensure the correct number of leaves are allocated.
-        assert_eq!(usize::pow(2, N::TRANSITION_TREE_DEPTH as u32), leaves.len());
+        // Ensure the number of commitments is correct.
+        if commitments.len() != N::NUM_OUTPUT_RECORDS {
+            return Err(anyhow!("Transition contains an invalid number of commitments"));
+        }
 
-        Ok(leaves)
+        Ok((
+            Self::compute_subtree(&Self::compute_leaves(serial_numbers)?)?,
+            Self::compute_subtree(&Self::compute_leaves(commitments)?)?,
+        ))
+    }
+
+    /// Returns `true` if the given number of serial numbers is a padded number of input records.
+    #[inline]
+    pub(crate) fn is_valid_num_serial_numbers(num_serial_numbers: usize) -> bool {
+        num_serial_numbers >= N::NUM_INPUT_RECORDS
+            && num_serial_numbers <= N::MAX_INPUT_RECORDS
+            && num_serial_numbers.is_power_of_two()
+    }
+
+    /// Returns the leaves of a transition subtree.
+    #[inline]
+    fn compute_leaves<L: ToBytes>(elements: &[L]) -> Result<Vec<Vec<u8>>> {
+        elements.iter().map(ToBytes::to_bytes_le).collect()
+    }
+
+    /// Returns the levels of a transition subtree, from the hashed leaves up to the root.
+    #[inline]
+    fn compute_subtree(leaves: &[Vec<u8>]) -> Result<Vec<Vec<MerkleTreeDigest<N::TransitionIDParameters>>>> {
+        let parameters = N::transition_id_parameters();
+
+        let mut levels = vec![leaves
+            .iter()
+            .map(|leaf| parameters.hash_leaf(leaf))
+            .collect::<Result<Vec<_>, _>>()?];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| parameters.hash_inner_node(&pair[0], &pair[1]))
+                .collect::<Result<Vec<_>, _>>()?;
+            levels.push(level);
+        }
+        Ok(levels)
     }
 }
 
@@ -305,8 +367,13 @@ impl<N: Network> FromBytes for Transition<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let transition_id: N::TransitionID = FromBytes::read_le(&mut reader)?;
 
-        let mut serial_numbers = Vec::<N::SerialNumber>::with_capacity(N::NUM_INPUT_RECORDS);
-        for _ in 0..N::NUM_INPUT_RECORDS {
+        let num_serial_numbers: u16 = FromBytes::read_le(&mut reader)?;
+        if num_serial_numbers as usize > N::MAX_INPUT_RECORDS {
+            return Err(error("Transition exceeds the maximum number of serial numbers"));
+        }
+
+        let mut serial_numbers = Vec::<N::SerialNumber>::with_capacity(num_serial_numbers as usize);
+        for _ in 0..num_serial_numbers {
             serial_numbers.push(FromBytes::read_le(&mut reader)?);
         }
 
@@ -325,10 +392,8 @@ impl<N: Network> FromBytes for Transition<N> {
 
         let proof: N::OuterProof = FromBytes::read_le(&mut reader)?;
 
-        Ok(
-            Self::from(transition_id, serial_numbers, ciphertexts, value_balance, events, proof)
-                .expect("Failed to deserialize a transition from bytes"),
-        )
+        Self::from(transition_id, serial_numbers, ciphertexts, value_balance, events, proof)
+            .map_err(|_| error("Failed to deserialize a transition"))
     }
 }
 
@@ -336,6 +401,7 @@ impl<N: Network> ToBytes for Transition<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.transition_id.write_le(&mut writer)?;
+        (self.serial_numbers.len() as u16).write_le(&mut writer)?;
         self.serial_numbers.write_le(&mut writer)?;
         self.ciphertexts.write_le(&mut writer)?;
         self.value_balance.write_le(&mut writer)?;
@@ -422,13 +488,13 @@ mod tests {
             let transaction = Testnet1::genesis_block().to_coinbase_transaction().unwrap();
             let transition = transaction.transitions().first().unwrap().clone();
             let transition_bytes = transition.to_bytes_le().unwrap();
-            assert_eq!(1039, transition_bytes.len(),);
+            assert_eq!(1041, transition_bytes.len(),);
         }
         {
             let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
             let transition = transaction.transitions().first().unwrap().clone();
             let transition_bytes = transition.to_bytes_le().unwrap();
-            assert_eq!(1039, transition_bytes.len(),);
+            assert_eq!(1041, transition_bytes.len(),);
         }
     }
 
@@ -456,7 +522,7 @@ mod tests {
         // Serialize
         let expected_bytes = expected_transition.to_bytes_le().unwrap();
         let candidate_bytes = bincode::serialize(&expected_transition).unwrap();
-        assert_eq!(1039, expected_bytes.len(), "Update me if serialization has changed");
+        assert_eq!(1041, expected_bytes.len(), "Update me if serialization has changed");
         // TODO (howardwu): Serialization - Handle the inconsistency between ToBytes and Serialize (off by a length encoding).
         assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
 
@@ -464,4 +530,55 @@ mod tests {
         assert_eq!(expected_transition, Transition::read_le(&expected_bytes[..]).unwrap());
         assert_eq!(expected_transition, bincode::deserialize(&candidate_bytes[..]).unwrap());
     }
+
+    #[test]
+    fn test_standard_transition_tree() {
+        let rng = &mut rand::thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        let request = Request::new_coinbase(account.address(), AleoAmount::from_i64(10), false, rng).unwrap();
+        let response = ResponseBuilder::new()
+            .add_request(request.clone())
+            .add_output(Output::new(account.address(), AleoAmount::from_i64(10), Default::default(), None).unwrap())
+            .build(rng)
+            .unwrap();
+        let serial_numbers = request.to_serial_numbers().unwrap();
+        let commitments = response.commitments();
+
+        // Ensure a standard transition ID is the root of the transition tree of depth `TRANSITION_TREE_DEPTH`.
+        let leaves = [
+            Transition::<Testnet2>::compute_leaves(&serial_numbers).unwrap(),
+            Transition::<Testnet2>::compute_leaves(&commitments).unwrap(),
+        ]
+        .concat();
+        let tree = snarkvm_algorithms::merkle_tree::MerkleTree::<<Testnet2 as Network>::TransitionIDParameters>::new(
+            Arc::new(Testnet2::transition_id_parameters().clone()),
+            &leaves,
+        )
+        .unwrap();
+        let transition_id = Transition::<Testnet2>::compute_transition_id(&serial_numbers, &commitments).unwrap();
+        assert_eq!(*tree.root(), *transition_id);
+
+        // Ensure the inclusion proofs match the transition tree.
+        let transition = Transition::<Testnet2>::from(
+            transition_id,
+            serial_numbers,
+            response.ciphertexts(),
+            response.value_balance(),
+            vec![],
+            Default::default(),
+        )
+        .unwrap();
+        for (index, leaf) in leaves.iter().enumerate() {
+            let expected = tree.generate_proof(index, leaf).unwrap();
+            let candidate = transition.to_transition_inclusion_proof(leaf).unwrap();
+            assert_eq!(expected.path, candidate.path);
+            assert_eq!(expected.leaf_index, candidate.leaf_index);
+        }
+
+        // Ensure a transition with an unpadded number of serial numbers is rejected.
+        let serial_numbers = request.to_serial_numbers().unwrap();
+        assert!(Transition::<Testnet2>::compute_transition_id(&serial_numbers[..1], &commitments).is_err());
+        assert!(Transition::<Testnet2>::compute_transition_id(&serial_numbers.repeat(3), &commitments).is_err());
+    }
 }
@@ -14,13 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::AmountError;
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use serde::{Deserialize, Serialize};
 use std::{
+    convert::TryFrom,
     fmt,
     io::{Read, Result as IoResult, Write},
     iter::Sum,
+    str::FromStr,
 };
 
 /// Represents the amount of ALEOs.
@@ -47,6 +50,19 @@ impl Denomination {
     }
 }
 
+impl FromStr for Denomination {
+    type Err = AmountError;
+
+    fn from_str(denomination: &str) -> Result<Self, Self::Err> {
+        match denomination {
+            "AB" => Ok(Denomination::BYTE),
+            "AG" => Ok(Denomination::GATE),
+            "ALEO" => Ok(Denomination::CREDIT),
+            _ => Err(AmountError::InvalidDenomination(denomination.to_string())),
+        }
+    }
+}
+
 impl fmt::Display for Denomination {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", match self {
@@ -73,25 +89,70 @@ impl AleoAmount {
     }
 
     /// Create an `AleoAmount` given a number of gates.
+    /// Panics if the amount overflows; use `try_from_gates` to handle overflows.
     pub fn from_gates(gate_value: i64) -> Self {
-        Self::from_i64(gate_value * 10_i64.pow(Denomination::GATE.precision()))
+        Self::try_from_gates(gate_value).expect("Gate amount overflowed")
     }
 
     /// Create an `AleoAmount` given a number of credits.
+    /// Panics if the amount overflows; use `try_from_aleo` to handle overflows.
     pub fn from_aleo(aleo_value: i64) -> Self {
-        Self::from_i64(aleo_value * 10_i64.pow(Denomination::CREDIT.precision()))
+        Self::try_from_aleo(aleo_value).expect("Credit amount overflowed")
+    }
+
+    /// Create an `AleoAmount` given a number of gates, returning an error on overflow.
+    pub fn try_from_gates(gate_value: i64) -> Result<Self, AmountError> {
+        Self::try_from_denomination(gate_value, Denomination::GATE)
     }
 
-    /// Add the values of two `AleoAmount`s
+    /// Create an `AleoAmount` given a number of credits, returning an error on overflow.
+    pub fn try_from_aleo(aleo_value: i64) -> Result<Self, AmountError> {
+        Self::try_from_denomination(aleo_value, Denomination::CREDIT)
+    }
+
+    /// Create an `AleoAmount` given a value in the given denomination, returning an error on overflow.
+    fn try_from_denomination(value: i64, denomination: Denomination) -> Result<Self, AmountError> {
+        value
+            .checked_mul(10_i64.pow(denomination.precision()))
+            .map(Self::from_i64)
+            .ok_or(AmountError::Overflow)
+    }
+
+    /// Add the values of two `AleoAmount`s.
+    /// Panics if the sum overflows; use `checked_add` to handle overflows.
     #[allow(clippy::should_implement_trait)]
     pub fn add(self, b: Self) -> Self {
-        Self::from_i64(self.0 + b.0)
+        self.checked_add(b).expect("Amount addition overflowed")
     }
 
-    /// Subtract the value of two `AleoAmounts`
+    /// Subtract the value of two `AleoAmounts`.
+    /// Panics if the difference overflows; use `checked_sub` to handle overflows.
     #[allow(clippy::should_implement_trait)]
     pub fn sub(self, b: AleoAmount) -> Self {
-        Self::from_i64(self.0 - b.0)
+        self.checked_sub(b).expect("Amount subtraction overflowed")
+    }
+
+    /// Add the values of two `AleoAmount`s, returning an error on overflow.
+    pub fn checked_add(self, b: Self) -> Result<Self, AmountError> {
+        self.0.checked_add(b.0).map(Self::from_i64).ok_or(AmountError::Overflow)
+    }
+
+    /// Subtract the value of two `AleoAmount`s, returning an error on overflow.
+    pub fn checked_sub(self, b: Self) -> Result<Self, AmountError> {
+        self.0.checked_sub(b.0).map(Self::from_i64).ok_or(AmountError::Overflow)
+    }
+
+    /// Multiply the amount by the given scalar, returning an error on overflow.
+    pub fn checked_mul(self, scalar: i64) -> Result<Self, AmountError> {
+        self.0
+            .checked_mul(scalar)
+            .map(Self::from_i64)
+            .ok_or(AmountError::Overflow)
+    }
+
+    /// Negate the amount, returning an error on overflow.
+    pub fn checked_neg(self) -> Result<Self, AmountError> {
+        self.0.checked_neg().map(Self::from_i64).ok_or(AmountError::Overflow)
     }
 
     /// Returns `true` the amount is positive and `false` if the amount is zero or
@@ -117,12 +178,19 @@ impl AleoAmount {
     }
 }
 
+/// Panics if the sum overflows; sum into a `Result<AleoAmount, AmountError>` to handle overflows.
 impl Sum for AleoAmount {
     fn sum<I: Iterator<Item = AleoAmount>>(iter: I) -> AleoAmount {
         iter.fold(AleoAmount::ZERO, |a, b| a.add(b))
     }
 }
 
+impl Sum<AleoAmount> for Result<AleoAmount, AmountError> {
+    fn sum<I: Iterator<Item = AleoAmount>>(mut iter: I) -> Result<AleoAmount, AmountError> {
+        iter.try_fold(AleoAmount::ZERO, AleoAmount::checked_add)
+    }
+}
+
 impl ToBytes for AleoAmount {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.0.write_le(&mut writer)
@@ -138,6 +206,58 @@ impl FromBytes for AleoAmount {
     }
 }
 
+impl FromStr for AleoAmount {
+    type Err = AmountError;
+
+    /// Parses an amount such as "1.5 ALEO", "300 AG", or "12 AB".
+    /// An amount without a denomination is interpreted in bytes (AB).
+    fn from_str(amount: &str) -> Result<Self, Self::Err> {
+        let mut parts = amount.split_whitespace();
+        let (value, denomination) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(value), Some(denomination), None) => (value, Denomination::from_str(denomination)?),
+            (Some(value), None, None) => (value, Denomination::BYTE),
+            _ => return Err(AmountError::InvalidAmount(amount.to_string())),
+        };
+
+        let invalid_amount = || AmountError::InvalidAmount(amount.to_string());
+
+        // Split the value into its integer and fractional parts.
+        let (integer, fraction) = match value.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (value, ""),
+        };
+        let precision = denomination.precision() as usize;
+        if fraction.len() > precision || (value.contains('.') && fraction.is_empty()) {
+            return Err(invalid_amount());
+        }
+
+        // Parse the sign separately, so that "-0.5" retains its sign.
+        let (is_negative, integer) = match integer.strip_prefix('-') {
+            Some(integer) => (true, integer),
+            None => (false, integer),
+        };
+        if integer.is_empty() || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+            return Err(invalid_amount());
+        }
+
+        // Right-pad the fractional part to the precision of the denomination, and compute the amount in bytes.
+        // The magnitude is computed in `i128`, so that `i64::MIN` may be parsed.
+        let fraction = format!("{:0<width$}", fraction, width = precision);
+        let integer: i128 = integer.parse::<u64>().map_err(|_| AmountError::Overflow)?.into();
+        let fraction: i128 = match fraction.is_empty() {
+            true => 0,
+            false => fraction.parse().map_err(|_| invalid_amount())?,
+        };
+        let bytes = integer * 10_i128.pow(precision as u32) + fraction;
+
+        let bytes = match is_negative {
+            true => i64::try_from(-bytes),
+            false => i64::try_from(bytes),
+        };
+        bytes.map(Self::from_i64).map_err(|_| AmountError::Overflow)
+    }
+}
+
 impl fmt::Display for AleoAmount {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -332,4 +452,137 @@ mod tests {
             }
         }
     }
+
+    mod checked_arithmetic {
+        use super::*;
+
+        #[test]
+        fn test_checked_overflow() {
+            let max = AleoAmount::from_i64(i64::MAX);
+            let min = AleoAmount::from_i64(i64::MIN);
+
+            assert!(max.checked_add(AleoAmount::from_i64(1)).is_err());
+            assert!(min.checked_sub(AleoAmount::from_i64(1)).is_err());
+            assert!(max.checked_mul(2).is_err());
+            assert!(min.checked_neg().is_err());
+            assert!(AleoAmount::try_from_aleo(i64::MAX).is_err());
+            assert!(AleoAmount::try_from_gates(i64::MAX).is_err());
+
+            assert_eq!(
+                max,
+                AleoAmount::from_i64(i64::MAX - 1)
+                    .checked_add(AleoAmount::from_i64(1))
+                    .unwrap()
+            );
+            assert_eq!(
+                AleoAmount::from_i64(-6),
+                AleoAmount::from_i64(3).checked_mul(-2).unwrap()
+            );
+        }
+
+        #[should_panic(expected = "Amount addition overflowed")]
+        #[test]
+        fn test_add_overflow_panics() {
+            AleoAmount::from_i64(i64::MAX).add(AleoAmount::from_i64(1));
+        }
+
+        #[test]
+        fn test_checked_sum() {
+            let amounts = vec![
+                AleoAmount::from_i64(1),
+                AleoAmount::from_i64(2),
+                AleoAmount::from_i64(3),
+            ];
+            let sum: Result<AleoAmount, AmountError> = amounts.into_iter().sum();
+            assert_eq!(AleoAmount::from_i64(6), sum.unwrap());
+
+            let amounts = vec![AleoAmount::from_i64(i64::MAX), AleoAmount::from_i64(1)];
+            let sum: Result<AleoAmount, AmountError> = amounts.into_iter().sum();
+            assert!(sum.is_err());
+
+            // Summing into an amount is still supported.
+            let amounts = vec![
+                AleoAmount::from_i64(1),
+                AleoAmount::from_i64(2),
+                AleoAmount::from_i64(3),
+            ];
+            assert_eq!(AleoAmount::from_i64(6), amounts.into_iter().sum());
+        }
+    }
+
+    mod parsing {
+        use super::*;
+
+        #[test]
+        fn test_from_str() {
+            assert_eq!(AleoAmount::from_aleo(1), AleoAmount::from_str("1 ALEO").unwrap());
+            assert_eq!(
+                AleoAmount::from_i64(1_500_000),
+                AleoAmount::from_str("1.5 ALEO").unwrap()
+            );
+            assert_eq!(AleoAmount::from_i64(1), AleoAmount::from_str("0.000001 ALEO").unwrap());
+            assert_eq!(AleoAmount::from_gates(300), AleoAmount::from_str("300 AG").unwrap());
+            assert_eq!(AleoAmount::from_i64(1_250), AleoAmount::from_str("1.25 AG").unwrap());
+            assert_eq!(AleoAmount::from_i64(12), AleoAmount::from_str("12 AB").unwrap());
+            assert_eq!(AleoAmount::from_i64(12), AleoAmount::from_str("12").unwrap());
+            assert_eq!(
+                AleoAmount::from_i64(-500_000),
+                AleoAmount::from_str("-0.5 ALEO").unwrap()
+            );
+            assert_eq!(
+                AleoAmount::from_i64(i64::MIN),
+                AleoAmount::from_str("-9223372036854775808").unwrap()
+            );
+            assert_eq!(
+                AleoAmount::from_i64(i64::MIN),
+                AleoAmount::from_str("-9223372036854.775808 ALEO").unwrap()
+            );
+        }
+
+        #[test]
+        fn test_display_round_trip() {
+            for amount in [0, 1, -1, 1_500_000, 123_456_789, i64::MAX, i64::MIN + 1, i64::MIN] {
+                let amount = AleoAmount::from_i64(amount);
+                assert_eq!(amount, AleoAmount::from_str(&amount.to_string()).unwrap());
+            }
+        }
+
+        #[test]
+        fn test_invalid_from_str() {
+            for amount in [
+                "",
+                "ALEO",
+                "1 ALE",
+                "1.5 AB",
+                "1.0001 AG",
+                "1.0000001 ALEO",
+                "1. ALEO",
+                ".5 ALEO",
+                "1 2 ALEO",
+                "+1 AB",
+                "1e3 AB",
+            ] {
+                assert!(
+                    matches!(
+                        AleoAmount::from_str(amount),
+                        Err(AmountError::InvalidAmount(_)) | Err(AmountError::InvalidDenomination(_))
+                    ),
+                    "{}",
+                    amount
+                );
+            }
+            assert!(matches!(
+                AleoAmount::from_str("9223372036854775808 AB"),
+                Err(AmountError::Overflow)
+            ));
+            assert!(matches!(
+                AleoAmount::from_str("-9223372036854775809 AB"),
+                Err(AmountError::Overflow)
+            ));
+            assert!(matches!(
+                AleoAmount::from_str("9223372036855 ALEO"),
+                Err(AmountError::Overflow)
+            ));
+        }
+    }
 }
@@ -120,10 +120,27 @@ impl<N: Network> ResponseBuilder<N> {
         let function_type = request.function_type();
         let program_id = request.to_program_id()?;
 
+        // Ensure the program events are emitted by the program of the request.
+        for event in events.iter().filter_map(Event::to_program_event) {
+            if event.program_id() != program_id {
+                return Err(anyhow!("Program event does not match the program ID of the request"));
+            }
+        }
+
         // Construct the inputs.
         let input_records = request.records();
         let serial_numbers = request.to_serial_numbers()?;
 
+        // Ensure the number of outputs does not exceed the function type.
+        if self.outputs.len() > function_type.output_count() as usize {
+            return Err(anyhow!(
+                "Function type {:?} produces at most {} records, found {}",
+                function_type,
+                function_type.output_count(),
+                self.outputs.len()
+            ));
+        }
+
         // Construct the outputs.
         let mut outputs = self.outputs.clone();
         // Pad the outputs with noop outputs if necessary.
@@ -178,11 +195,11 @@ impl<N: Network> ResponseBuilder<N> {
 
         // Compute the value balance.
         let mut value_balance = AleoAmount::ZERO;
-        for record in input_records.iter().take(N::NUM_INPUT_RECORDS) {
-            value_balance = value_balance.add(record.value());
+        for record in input_records {
+            value_balance = value_balance.checked_add(record.value())?;
         }
         for record in output_records.iter().take(N::NUM_OUTPUT_RECORDS) {
-            value_balance = value_balance.sub(record.value());
+            value_balance = value_balance.checked_sub(record.value())?;
         }
 
         // Ensure the value balance matches the fee from the request.
@@ -0,0 +1,369 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Function, FunctionType, Network, ProgramPrivateVariables, ProgramPublicVariables};
+use snarkvm_algorithms::{SNARK, SRS};
+use snarkvm_circuits::{
+    programs::{self, Literal, Type},
+    Circuit,
+    Environment,
+    Mode,
+};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use std::{
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+};
+
+/// The base field of the circuit environment, which is the inner scalar field of the network.
+type CircuitField = <Circuit as Environment>::BaseField;
+
+///
+/// A program function written in the instruction set of `snarkvm-circuits`, and proven with the program SNARK.
+///
+/// The public inputs of the function are the program public variables, as field elements, so an execution
+/// is verified on the same public variables as the noop function. The private inputs of the function are
+/// given as `CircuitFunctionPrivateVariables`. As the outputs of a function are public, and the program
+/// public variables do not include them, a function with outputs is rejected.
+///
+/// The registers of a circuit function may not be shared across threads, so the function is kept
+/// as bytecode, and decoded each time it is executed.
+///
+pub struct CircuitFunction<N: Network> {
+    bytecode: Vec<u8>,
+    function_type: FunctionType,
+    function_id: N::FunctionID,
+    proving_key: N::ProgramProvingKey,
+    verifying_key: N::ProgramVerifyingKey,
+}
+
+impl<N: Network<InnerScalarField = CircuitField>> CircuitFunction<N> {
+    ///
+    /// Sets up the given function with the given SRS of the program SNARK,
+    /// and returns it as a program function of the given function type.
+    ///
+    pub fn setup<R: Rng + CryptoRng>(
+        function: &programs::Function<Circuit>,
+        function_type: FunctionType,
+        srs: &mut SRS<R, <N::ProgramSNARK as SNARK>::UniversalSetupParameters>,
+    ) -> Result<Self> {
+        // Ensure the public inputs of the function are the program public variables.
+        let num_public_variables = ProgramPublicVariables::<N>::blank().to_field_elements()?.len();
+        let public_types = function
+            .input_types()?
+            .into_iter()
+            .filter(|(_, mode)| *mode == Mode::Public)
+            .map(|(type_, _)| type_)
+            .collect::<Vec<_>>();
+        if public_types != vec![Type::BaseField; num_public_variables] {
+            return Err(anyhow!(
+                "Function '{}' must have {} public field inputs, for the program public variables",
+                function.name(),
+                num_public_variables
+            ));
+        }
+
+        // Ensure the function does not have outputs, which would be additional public inputs.
+        if !function.output_types()?.is_empty() {
+            return Err(anyhow!(
+                "Function '{}' must not have outputs, as they are public",
+                function.name()
+            ));
+        }
+
+        let (proving_key, verifying_key) = function.setup::<N::ProgramSNARK, R>(srs)?;
+
+        Ok(Self {
+            bytecode: function.to_bytes_le()?,
+            function_type,
+            function_id: N::function_id(&verifying_key)?,
+            proving_key,
+            verifying_key,
+        })
+    }
+
+    /// Returns the function, decoded from its bytecode.
+    fn to_function(&self) -> Result<programs::Function<Circuit>> {
+        Ok(FromBytes::read_le(&self.bytecode[..])?)
+    }
+
+    /// Returns the inputs of the given function, where the public inputs are the program public variables.
+    fn to_inputs(
+        function: &programs::Function<Circuit>,
+        public: &ProgramPublicVariables<N>,
+        private: &[Literal<Circuit>],
+    ) -> Result<Vec<Literal<Circuit>>> {
+        let mut public = public.to_field_elements()?.into_iter();
+        let mut private = private.iter();
+
+        let inputs = function
+            .input_types()?
+            .into_iter()
+            .map(|(_, mode)| match mode {
+                Mode::Public => public.next().map(Literal::BaseField),
+                _ => private.next().copied(),
+            })
+            .collect::<Option<Vec<_>>>();
+
+        match inputs {
+            Some(inputs) if public.next().is_none() && private.next().is_none() => Ok(inputs),
+            _ => Err(anyhow!("Incorrect number of inputs for function '{}'", function.name())),
+        }
+    }
+}
+
+impl<N: Network<InnerScalarField = CircuitField>> Function<N> for CircuitFunction<N> {
+    /// Returns the function ID.
+    fn function_id(&self) -> N::FunctionID {
+        self.function_id
+    }
+
+    /// Returns the circuit type.
+    fn function_type(&self) -> FunctionType {
+        self.function_type
+    }
+
+    /// Executes the function, returning an proof.
+    fn execute(
+        &self,
+        public: ProgramPublicVariables<N>,
+        private: &dyn ProgramPrivateVariables<N>,
+    ) -> Result<N::ProgramProof> {
+        let private = match private.as_any().downcast_ref::<CircuitFunctionPrivateVariables<N>>() {
+            Some(private) => private,
+            None => return Err(anyhow!("Invalid private variables for a circuit function")),
+        };
+
+        let function = self.to_function()?;
+        let inputs = Self::to_inputs(&function, &public, &private.inputs)?;
+        let execution = function.prove::<N::ProgramSNARK, _>(&self.proving_key, &inputs, &mut rand::thread_rng())?;
+
+        let proof = execution.proof().clone().into();
+        match self.verify(&public, &proof) {
+            true => Ok(proof),
+            false => Err(anyhow!(
+                "Failed to verify the execution of function '{}'",
+                function.name()
+            )),
+        }
+    }
+
+    /// Returns true if the execution of the function is valid.
+    fn verify(&self, public: &ProgramPublicVariables<N>, proof: &N::ProgramProof) -> bool {
+        match <N::ProgramSNARK as SNARK>::verify(&self.verifying_key, public, proof) {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to verify circuit function proof: {}", error);
+                false
+            }
+        }
+    }
+
+    /// Synthesizes the circuit inside the given constraint system, with default private inputs.
+    fn synthesize<CS: ConstraintSystem<N::InnerScalarField>>(
+        &self,
+        cs: &mut CS,
+        public: &ProgramPublicVariables<N>,
+    ) -> Result<(), SynthesisError> {
+        let function = self.to_function()?;
+        let private = function
+            .input_types()?
+            .into_iter()
+            .filter(|(_, mode)| *mode != Mode::Public)
+            .map(|(type_, _)| programs::Function::<Circuit>::default_input(type_))
+            .collect::<Vec<_>>();
+
+        let inputs = Self::to_inputs(&function, public, &private)?;
+        function.synthesize(&inputs)?.generate_constraints(cs)
+    }
+}
+
+/// The private inputs of a circuit function, in the order they are declared.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct CircuitFunctionPrivateVariables<N: Network> {
+    inputs: Vec<Literal<Circuit>>,
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> CircuitFunctionPrivateVariables<N> {
+    /// Initializes the private variables of a circuit function with the given private inputs.
+    pub fn new(inputs: Vec<Literal<Circuit>>) -> Self {
+        Self {
+            inputs,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the private inputs.
+    pub fn inputs(&self) -> &[Literal<Circuit>] {
+        &self.inputs
+    }
+}
+
+impl<N: Network> ProgramPrivateVariables<N> for CircuitFunctionPrivateVariables<N> {
+    fn new_blank() -> Result<Self> {
+        Ok(Self::new(Vec::new()))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl<N: Network> FromBytes for CircuitFunctionPrivateVariables<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_inputs: u16 = FromBytes::read_le(&mut reader)?;
+        let inputs = (0..num_inputs)
+            .map(|_| FromBytes::read_le(&mut reader))
+            .collect::<IoResult<Vec<_>>>()?;
+        Ok(Self::new(inputs))
+    }
+}
+
+impl<N: Network> ToBytes for CircuitFunctionPrivateVariables<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.inputs.len() as u16).write_le(&mut writer)?;
+        self.inputs.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> ToConstraintField<N::InnerScalarField> for CircuitFunctionPrivateVariables<N> {
+    #[inline]
+    fn to_field_elements(&self) -> Result<Vec<N::InnerScalarField>, ConstraintFieldError> {
+        ToConstraintField::<N::InnerScalarField>::to_field_elements(&self.to_bytes_le()?[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet1::Testnet1, Program};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+    use std::sync::Arc;
+
+    type N = Testnet1;
+
+    const EXAMPLE: &str = r"function example:
+    input r0 as field.public;
+    input r1 as field.public;
+    input r2 as field.private;
+    add r1 r2 into r3;
+    inv r3 into r4;
+";
+
+    fn setup(function: &str) -> Result<CircuitFunction<N>> {
+        let function: programs::Function<Circuit> = function.parse()?;
+        CircuitFunction::<N>::setup(
+            &function,
+            FunctionType::Noop,
+            &mut SRS::CircuitSpecific(&mut thread_rng()),
+        )
+    }
+
+    fn public_variables() -> ProgramPublicVariables<N> {
+        let transition_id = CircuitField::rand(&mut thread_rng());
+        ProgramPublicVariables::new(transition_id.into())
+    }
+
+    #[test]
+    fn test_execute() {
+        let circuit_function = Arc::new(setup(EXAMPLE).unwrap());
+        assert_eq!(FunctionType::Noop, circuit_function.function_type());
+
+        // Retrieve the function from its program, as the virtual machine does.
+        let program = Program::<N>::new(vec![circuit_function.clone()]).unwrap();
+        let function_id = circuit_function.function_id();
+        let function = program.to_function(&function_id).unwrap();
+        let function_path = program.to_program_path(&function_id).unwrap();
+        assert!(function_path
+            .verify(&program.program_id(), &function.function_id())
+            .unwrap());
+
+        let public = public_variables();
+        let private = CircuitFunctionPrivateVariables::<N>::new(vec![Literal::BaseField(2u64.into())]);
+        let proof = function.execute(public, &private).unwrap();
+        assert!(function.verify(&public, &proof));
+
+        // Ensure the proof verifies on the program public variables alone, as in the outer circuit.
+        let verifying_key = &circuit_function.verifying_key;
+        assert!(<N as Network>::ProgramSNARK::verify(verifying_key, &public, &proof).unwrap());
+
+        // Ensure the proof does not verify for a different transition ID.
+        assert!(!function.verify(&public_variables(), &proof));
+
+        // Ensure the noop function does not verify the proof.
+        assert!(!N::noop_program()
+            .to_function(N::noop_function_id())
+            .unwrap()
+            .verify(&public, &proof));
+
+        // Ensure the private variables are checked.
+        assert!(function
+            .execute(public, &CircuitFunctionPrivateVariables::<N>::new(vec![]))
+            .is_err());
+        assert!(function
+            .execute(public, &crate::NoopPrivateVariables::<N>::new_blank().unwrap())
+            .is_err());
+
+        // Ensure a private input that halts the function is an error.
+        let transition_id = -CircuitField::from(2u64);
+        let halting_public = ProgramPublicVariables::new(transition_id.into());
+        assert!(function.execute(halting_public, &private).is_err());
+    }
+
+    #[test]
+    fn test_synthesize() {
+        let function = setup(EXAMPLE).unwrap();
+
+        let mut cs = snarkvm_r1cs::TestConstraintSystem::new();
+        function.synthesize(&mut cs, &public_variables()).unwrap();
+        assert!(cs.is_satisfied());
+
+        // The public inputs are the constant one, followed by the program public variables.
+        let num_public_variables = ProgramPublicVariables::<N>::blank().to_field_elements().unwrap().len();
+        assert_eq!(1 + num_public_variables, cs.num_public_variables());
+    }
+
+    #[test]
+    fn test_private_variables_bytes() {
+        let private =
+            CircuitFunctionPrivateVariables::<N>::new(vec![Literal::BaseField(2u64.into()), Literal::Boolean(true)]);
+        let expected = private.to_bytes_le().unwrap();
+        let candidate = CircuitFunctionPrivateVariables::<N>::read_le(&expected[..]).unwrap();
+        assert_eq!(private.inputs(), candidate.inputs());
+        assert_eq!(expected, candidate.to_bytes_le().unwrap());
+    }
+
+    #[test]
+    fn test_setup_rejects_other_signatures() {
+        // Ensure the public inputs must be the program public variables.
+        assert!(setup(&EXAMPLE.replace("r1 as field.public", "r1 as field.private")).is_err());
+        assert!(setup(&EXAMPLE.replace("r1 as field.public", "r1 as boolean.public")).is_err());
+
+        // Ensure the function may not have outputs.
+        assert!(setup(&format!("{}    output r4 as field;\n", EXAMPLE)).is_err());
+    }
+}
@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Network, Operation};
-use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+use crate::{Network, Operation, ProgramEvent};
+use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
@@ -32,6 +32,8 @@ pub enum Event<N: Network> {
     RecordViewKey(u8, N::RecordViewKey),
     /// Emits the operation performed in a transition.
     Operation(Operation<N>),
+    /// Emits an indexed event from a program.
+    Program(ProgramEvent<N>),
 }
 
 impl<N: Network> Event<N> {
@@ -42,6 +44,15 @@ impl<N: Network> Event<N> {
             Self::Custom(..) => 0,
             Self::RecordViewKey(..) => 1,
             Self::Operation(..) => 2,
+            Self::Program(..) => 3,
+        }
+    }
+
+    /// Returns the program event, if this is a program event.
+    pub fn to_program_event(&self) -> Option<&ProgramEvent<N>> {
+        match self {
+            Self::Program(event) => Some(event),
+            _ => None,
         }
     }
 }
@@ -63,7 +74,8 @@ impl<N: Network> FromBytes for Event<N> {
                 Ok(Self::RecordViewKey(index, record_view_key))
             }
             2 => Ok(Self::Operation(FromBytes::read_le(&mut reader)?)),
-            _ => unreachable!("Invalid event ID during deserialization"),
+            3 => Ok(Self::Program(FromBytes::read_le(&mut reader)?)),
+            _ => Err(error("Invalid event ID during deserialization")),
         }
     }
 }
@@ -82,6 +94,7 @@ impl<N: Network> ToBytes for Event<N> {
                 record_view_key.write_le(&mut writer)
             }
             Self::Operation(operation) => operation.write_le(&mut writer),
+            Self::Program(event) => event.write_le(&mut writer),
         }
     }
 }
@@ -127,6 +140,15 @@ impl<N: Network> Serialize for Event<N> {
                     event.serialize_field("operation", &operation)?;
                     event.end()
                 }
+                Self::Program(ref program_event) => {
+                    let topics: Vec<_> = program_event.topics().iter().map(hex::encode).collect();
+                    let mut event = serializer.serialize_struct("Event", 4)?;
+                    event.serialize_field("id", &self.id())?;
+                    event.serialize_field("program_id", &program_event.program_id())?;
+                    event.serialize_field("topics", &topics)?;
+                    event.serialize_field("data", &hex::encode(program_event.data()))?;
+                    event.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -153,7 +175,26 @@ impl<'de, N: Network> Deserialize<'de> for Event<N> {
                     2 => Ok(Self::Operation(
                         serde_json::from_value(event["operation"].clone()).map_err(de::Error::custom)?,
                     )),
-                    _ => unreachable!(format!("Invalid event id {}", event_id)),
+                    3 => {
+                        let program_id =
+                            serde_json::from_value(event["program_id"].clone()).map_err(de::Error::custom)?;
+                        let topics: Vec<String> =
+                            serde_json::from_value(event["topics"].clone()).map_err(de::Error::custom)?;
+                        let topics = topics
+                            .iter()
+                            .map(|topic| {
+                                let mut buffer = [0u8; 32];
+                                hex::decode_to_slice(topic, &mut buffer).map_err(de::Error::custom)?;
+                                Ok(buffer)
+                            })
+                            .collect::<Result<Vec<_>, D::Error>>()?;
+                        let data: String = serde_json::from_value(event["data"].clone()).map_err(de::Error::custom)?;
+                        let data = hex::decode(data).map_err(de::Error::custom)?;
+                        Ok(Self::Program(
+                            ProgramEvent::new(program_id, topics, data).map_err(de::Error::custom)?,
+                        ))
+                    }
+                    _ => Err(de::Error::custom(format!("Invalid event id {}", event_id))),
                 }
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "event"),
@@ -165,6 +206,9 @@ impl<'de, N: Network> Deserialize<'de> for Event<N> {
 mod tests {
     use super::*;
     use crate::testnet2::Testnet2;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
 
     #[test]
     fn test_event_serde_json() {
@@ -196,4 +240,35 @@ mod tests {
         assert_eq!(expected_event, Event::read_le(&expected_bytes[..]).unwrap());
         assert_eq!(expected_event, bincode::deserialize(&candidate_bytes[..]).unwrap());
     }
+
+    #[test]
+    fn test_program_event_serialization() {
+        let rng = &mut thread_rng();
+
+        let program_event =
+            ProgramEvent::<Testnet2>::new(UniformRand::rand(rng), vec![[7u8; 32]], vec![1, 2, 3]).unwrap();
+        let expected_event = Event::<Testnet2>::Program(program_event);
+
+        // Serialize and deserialize with serde_json.
+        let candidate_string = serde_json::to_string(&expected_event).unwrap();
+        assert_eq!(expected_event, Event::from_str(&candidate_string).unwrap());
+
+        // Serialize and deserialize with bincode.
+        let candidate_bytes = bincode::serialize(&expected_event).unwrap();
+        assert_eq!(expected_event, bincode::deserialize(&candidate_bytes[..]).unwrap());
+
+        // Serialize and deserialize with bytes.
+        let expected_bytes = expected_event.to_bytes_le().unwrap();
+        assert_eq!(expected_event, Event::read_le(&expected_bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_event_id() {
+        assert!(Event::<Testnet2>::read_le(&[4u8][..]).is_err());
+        assert!(Event::<Testnet2>::read_le(&[u8::MAX][..]).is_err());
+        assert!(Event::<Testnet2>::from_str(r#"{"id":4}"#).is_err());
+
+        // An event containing an invalid operation ID is rejected.
+        assert!(Event::<Testnet2>::read_le(&[2u8, 9, 0][..]).is_err());
+    }
 }
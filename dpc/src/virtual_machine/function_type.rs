@@ -14,11 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::Network;
 use snarkvm_utilities::{error, FromBytes, ToBytes};
 
 use serde::{Deserialize, Serialize};
 use std::io::{Read, Result as IoResult, Write};
 
+///
+/// The shape of a transition, as the number of input records it consumes and output records it produces.
+///
+/// Every shape of a standard transition (up to 2 inputs and 2 outputs) has a named variant.
+/// `Custom` describes the remaining shapes, such as joining 8 records, whose inputs are padded
+/// with noop records to the next power of two, up to the maximum number of input records of the network.
+///
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FunctionType {
     Noop,
@@ -30,12 +38,48 @@ pub enum FunctionType {
     Join,
     Split,
     Full,
+    /// A function that consumes `inputs` records and produces `outputs` records,
+    /// for shapes which do not have a named variant.
+    Custom {
+        inputs: u8,
+        outputs: u8,
+    },
 }
 
 impl FunctionType {
+    /// Returns the function type that consumes the given number of input records
+    /// and produces the given number of output records, which is a named variant whenever one exists.
+    pub fn new(input_count: u8, output_count: u8) -> Self {
+        [
+            Self::Noop,
+            Self::Insert,
+            Self::Update,
+            Self::Remove,
+            Self::DoubleInsert,
+            Self::DoubleRemove,
+            Self::Join,
+            Self::Split,
+            Self::Full,
+        ]
+        .iter()
+        .find(|function_type| {
+            function_type.input_count() == input_count && function_type.output_count() == output_count
+        })
+        .copied()
+        .unwrap_or(Self::Custom {
+            inputs: input_count,
+            outputs: output_count,
+        })
+    }
+
+    /// Returns the function type with the same shape, as a named variant whenever one exists.
+    pub fn normalize(&self) -> Self {
+        Self::new(self.input_count(), self.output_count())
+    }
+
     /// Return the id of the function type.
     pub fn id(&self) -> u8 {
-        match self {
+        match self.normalize() {
             Self::Noop => 0,
             Self::Insert => 1,
             Self::Update => 2,
@@ -45,10 +89,14 @@ impl FunctionType {
             Self::Join => 6,
             Self::Split => 7,
             Self::Full => 8,
+            Self::Custom { .. } => 9,
         }
     }
 
     /// Return a function type given an id.
+    ///
+    /// Custom function types are not recoverable from their id alone, as the id
+    /// does not encode the number of input and output records.
     pub fn from_id(id: u8) -> IoResult<Self> {
         Ok(match id {
             0 => Self::Noop,
@@ -60,6 +108,7 @@ impl FunctionType {
             6 => Self::Join,
             7 => Self::Split,
             8 => Self::Full,
+            9 => return Err(error("Custom function type requires record counts")),
             _ => return Err(error("Invalid function type id")),
         })
     }
@@ -76,6 +125,7 @@ impl FunctionType {
             Self::Join => 2,
             Self::Split => 1,
             Self::Full => 2,
+            Self::Custom { inputs, .. } => *inputs,
         }
     }
 
@@ -91,21 +141,116 @@ impl FunctionType {
             Self::Join => 1,
             Self::Split => 2,
             Self::Full => 2,
+            Self::Custom { outputs, .. } => *outputs,
         }
     }
+
+    /// Returns the number of input records in a transition of this function type, after padding with noop records.
+    pub fn num_input_records<N: Network>(&self) -> usize {
+        std::cmp::max(N::NUM_INPUT_RECORDS, (self.input_count() as usize).next_power_of_two())
+    }
+
+    /// Returns `true` if the input and output record counts fit within the limits of the network.
+    pub fn is_supported<N: Network>(&self) -> bool {
+        self.input_count() as usize <= N::MAX_INPUT_RECORDS && self.output_count() as usize <= N::NUM_OUTPUT_RECORDS
+    }
 }
 
 impl FromBytes for FunctionType {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let function_type_id: u8 = FromBytes::read_le(&mut reader)?;
-        Self::from_id(function_type_id)
+        match function_type_id {
+            9 => {
+                let inputs = FromBytes::read_le(&mut reader)?;
+                let outputs = FromBytes::read_le(&mut reader)?;
+                // Ensure a shape with a named variant is not encoded as a custom function type.
+                match Self::new(inputs, outputs) {
+                    Self::Custom { .. } => Ok(Self::Custom { inputs, outputs }),
+                    _ => Err(error("Non-canonical custom function type")),
+                }
+            }
+            _ => Self::from_id(function_type_id),
+        }
     }
 }
 
 impl ToBytes for FunctionType {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        self.id().write_le(&mut writer)
+        self.id().write_le(&mut writer)?;
+        match self.normalize() {
+            Self::Custom { inputs, outputs } => {
+                inputs.write_le(&mut writer)?;
+                outputs.write_le(&mut writer)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(FunctionType::Noop, FunctionType::new(0, 0));
+        assert_eq!(FunctionType::Join, FunctionType::new(2, 1));
+        assert_eq!(FunctionType::Full, FunctionType::new(2, 2));
+        assert_eq!(FunctionType::Custom { inputs: 8, outputs: 1 }, FunctionType::new(8, 1));
+    }
+
+    #[test]
+    fn test_bytes() {
+        for function_type in &[
+            FunctionType::Noop,
+            FunctionType::Split,
+            FunctionType::new(2, 0),
+            FunctionType::new(8, 1),
+        ] {
+            let bytes = function_type.to_bytes_le().unwrap();
+            assert_eq!(*function_type, FunctionType::read_le(&bytes[..]).unwrap());
+        }
+        assert!(FunctionType::from_id(9).is_err());
+        assert!(FunctionType::read_le(&[10u8][..]).is_err());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let custom = FunctionType::Custom { inputs: 1, outputs: 0 };
+        assert_ne!(FunctionType::Remove, custom);
+        assert_eq!(FunctionType::Remove, custom.normalize());
+        assert_eq!(FunctionType::Remove.id(), custom.id());
+
+        // Ensure a shape has a single encoding.
+        let bytes = custom.to_bytes_le().unwrap();
+        assert_eq!(FunctionType::Remove.to_bytes_le().unwrap(), bytes);
+        assert!(matches!(
+            FunctionType::read_le(&bytes[..]).unwrap(),
+            FunctionType::Remove
+        ));
+        assert!(FunctionType::read_le(&[9u8, 1, 0][..]).is_err());
+        assert!(FunctionType::read_le(&[9u8, 8, 1][..]).is_ok());
+    }
+
+    #[test]
+    fn test_is_supported() {
+        assert!(FunctionType::Full.is_supported::<Testnet2>());
+        assert!(FunctionType::new(1, 0).is_supported::<Testnet2>());
+        assert!(FunctionType::new(8, 1).is_supported::<Testnet2>());
+        assert!(FunctionType::new(16, 2).is_supported::<Testnet2>());
+        assert!(!FunctionType::new(17, 1).is_supported::<Testnet2>());
+        assert!(!FunctionType::new(0, 3).is_supported::<Testnet2>());
+    }
+
+    #[test]
+    fn test_num_input_records() {
+        assert_eq!(2, FunctionType::Noop.num_input_records::<Testnet2>());
+        assert_eq!(2, FunctionType::Full.num_input_records::<Testnet2>());
+        assert_eq!(4, FunctionType::new(3, 1).num_input_records::<Testnet2>());
+        assert_eq!(8, FunctionType::new(8, 1).num_input_records::<Testnet2>());
+        assert_eq!(16, FunctionType::new(9, 2).num_input_records::<Testnet2>());
     }
 }
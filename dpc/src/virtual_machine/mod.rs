@@ -20,6 +20,9 @@ pub use amount::*;
 pub mod builder;
 pub use builder::*;
 
+pub mod circuit_function;
+pub use circuit_function::*;
+
 pub mod event;
 pub use event::*;
 
@@ -41,11 +44,20 @@ pub use operation::*;
 pub mod output;
 pub use output::*;
 
+pub mod payload_function;
+pub use payload_function::*;
+
 pub mod program;
 pub use program::*;
 
+pub mod program_event;
+pub use program_event::*;
+
 pub mod program_public_variables;
 pub use program_public_variables::*;
 
+pub mod proving_job;
+pub use proving_job::*;
+
 pub mod virtual_machine;
 pub use virtual_machine::*;
@@ -16,7 +16,7 @@
 
 use crate::{Address, AleoAmount, FunctionInputs, FunctionType, Network};
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
-use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::{anyhow, Result};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -121,7 +121,7 @@ impl<N: Network> FromBytes for Operation<N> {
                 let function_inputs = FromBytes::read_le(&mut reader)?;
                 Ok(Self::Evaluate(function_id, function_type, function_inputs))
             }
-            _ => unreachable!("Invalid operation during deserialization"),
+            _ => Err(error("Invalid operation ID during deserialization")),
         }
     }
 }
@@ -176,7 +176,7 @@ impl<N: Network> FromStr for Operation<N> {
                 let function_inputs = serde_json::from_value(operation["function_inputs"].clone())?;
                 Ok(Self::Evaluate(function_id, function_type, function_inputs))
             }
-            _ => unreachable!(format!("Invalid operation id {}", operation_id)),
+            _ => Err(anyhow!("Invalid operation id {}", operation_id)),
         }
     }
 }
@@ -0,0 +1,379 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Function,
+    FunctionType,
+    Network,
+    Payload,
+    PayloadSchema,
+    ProgramPrivateVariables,
+    ProgramPublicVariables,
+    SynthesizedCircuit,
+};
+use snarkvm_algorithms::{SNARK, SRS};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_gadgets::integers::uint::UInt8;
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use std::io::{Read, Result as IoResult, Write};
+
+///
+/// A program function that enforces the record payloads given to it are well-formed under their schemas.
+///
+/// The public inputs of the function are the program public variables, as in the noop function.
+/// The private inputs of the function are the payloads, given as `PayloadPrivateVariables`,
+/// which are decoded inside the circuit with `PayloadSchema::decode_gadget`.
+///
+pub struct PayloadFunction<N: Network> {
+    schemas: Vec<PayloadSchema<N>>,
+    function_type: FunctionType,
+    function_id: N::FunctionID,
+    proving_key: N::ProgramProvingKey,
+    verifying_key: N::ProgramVerifyingKey,
+}
+
+impl<N: Network> PayloadFunction<N> {
+    ///
+    /// Sets up a function that decodes one payload for each of the given schemas,
+    /// with the given SRS of the program SNARK, and returns it as a program function of the given function type.
+    ///
+    pub fn setup<R: Rng + CryptoRng>(
+        schemas: Vec<PayloadSchema<N>>,
+        function_type: FunctionType,
+        srs: &mut SRS<R, <N::ProgramSNARK as SNARK>::UniversalSetupParameters>,
+    ) -> Result<Self> {
+        let (proving_key, verifying_key) = {
+            let public = ProgramPublicVariables::blank();
+            let payloads = vec![Payload::default(); schemas.len()];
+            let circuit = PayloadCircuit::new(&schemas, &public, &payloads);
+            <N::ProgramSNARK as SNARK>::setup(&circuit, srs, -1)?
+        };
+
+        Ok(Self {
+            schemas,
+            function_type,
+            function_id: N::function_id(&verifying_key)?,
+            proving_key,
+            verifying_key,
+        })
+    }
+
+    /// Returns the schemas of the payloads.
+    pub fn schemas(&self) -> &[PayloadSchema<N>] {
+        &self.schemas
+    }
+}
+
+impl<N: Network> Function<N> for PayloadFunction<N> {
+    /// Returns the function ID.
+    fn function_id(&self) -> N::FunctionID {
+        self.function_id
+    }
+
+    /// Returns the circuit type.
+    fn function_type(&self) -> FunctionType {
+        self.function_type
+    }
+
+    /// Executes the function, returning an proof.
+    fn execute(
+        &self,
+        public: ProgramPublicVariables<N>,
+        private: &dyn ProgramPrivateVariables<N>,
+    ) -> Result<N::ProgramProof> {
+        let private = match private.as_any().downcast_ref::<PayloadPrivateVariables<N>>() {
+            Some(private) => private,
+            None => return Err(anyhow!("Invalid private variables for a payload function")),
+        };
+
+        // Ensure each payload decodes with its schema, as the circuit is not satisfied otherwise.
+        if private.payloads.len() != self.schemas.len() {
+            return Err(anyhow!(
+                "Expected {} payloads for the payload function, found {}",
+                self.schemas.len(),
+                private.payloads.len()
+            ));
+        }
+        for (schema, payload) in self.schemas.iter().zip(&private.payloads) {
+            schema.decode(payload)?;
+        }
+
+        let circuit = PayloadCircuit::new(&self.schemas, &public, &private.payloads);
+        let proof = <N::ProgramSNARK as SNARK>::prove(&self.proving_key, &circuit, &mut rand::thread_rng(), -1)?.into();
+        match self.verify(&public, &proof) {
+            true => Ok(proof),
+            false => Err(anyhow!("Failed to verify the execution of the payload function")),
+        }
+    }
+
+    /// Returns true if the execution of the function is valid.
+    fn verify(&self, public: &ProgramPublicVariables<N>, proof: &N::ProgramProof) -> bool {
+        match <N::ProgramSNARK as SNARK>::verify(&self.verifying_key, public, proof) {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to verify payload function proof: {}", error);
+                false
+            }
+        }
+    }
+
+    /// Synthesizes the circuit inside the given constraint system, with empty payloads.
+    fn synthesize<CS: ConstraintSystem<N::InnerScalarField>>(
+        &self,
+        cs: &mut CS,
+        public: &ProgramPublicVariables<N>,
+    ) -> Result<(), SynthesisError> {
+        let payloads = vec![Payload::default(); self.schemas.len()];
+        PayloadCircuit::new(&self.schemas, public, &payloads).generate_constraints(cs)
+    }
+}
+
+/// The circuit of a payload function, which decodes each payload with its schema.
+struct PayloadCircuit<'a, N: Network> {
+    schemas: &'a [PayloadSchema<N>],
+    public: &'a ProgramPublicVariables<N>,
+    payloads: &'a [Payload<N>],
+}
+
+impl<'a, N: Network> PayloadCircuit<'a, N> {
+    fn new(schemas: &'a [PayloadSchema<N>], public: &'a ProgramPublicVariables<N>, payloads: &'a [Payload<N>]) -> Self {
+        Self {
+            schemas,
+            public,
+            payloads,
+        }
+    }
+}
+
+impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for PayloadCircuit<'_, N> {
+    fn generate_constraints<CS: ConstraintSystem<N::InnerScalarField>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), SynthesisError> {
+        if self.schemas.len() != self.payloads.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // Allocate the program public variables, as in the noop function.
+        SynthesizedCircuit::Noop(*self.public).generate_constraints(&mut cs.ns(|| "Program public variables"))?;
+
+        for (i, (schema, payload)) in self.schemas.iter().zip(self.payloads).enumerate() {
+            let payload_bytes = UInt8::alloc_vec(cs.ns(|| format!("Allocate payload {}", i)), &payload.to_bytes_le()?)?;
+            schema.decode_gadget(cs.ns(|| format!("Decode payload {}", i)), &payload_bytes)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The private inputs of a payload function, which are the payloads in the order of their schemas.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct PayloadPrivateVariables<N: Network> {
+    payloads: Vec<Payload<N>>,
+}
+
+impl<N: Network> PayloadPrivateVariables<N> {
+    /// Initializes the private variables of a payload function with the given payloads.
+    pub fn new(payloads: Vec<Payload<N>>) -> Self {
+        Self { payloads }
+    }
+
+    /// Returns the payloads.
+    pub fn payloads(&self) -> &[Payload<N>] {
+        &self.payloads
+    }
+}
+
+impl<N: Network> ProgramPrivateVariables<N> for PayloadPrivateVariables<N> {
+    fn new_blank() -> Result<Self> {
+        Ok(Self::new(Vec::new()))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl<N: Network> FromBytes for PayloadPrivateVariables<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_payloads: u16 = FromBytes::read_le(&mut reader)?;
+        let payloads = (0..num_payloads)
+            .map(|_| FromBytes::read_le(&mut reader))
+            .collect::<IoResult<Vec<_>>>()?;
+        Ok(Self::new(payloads))
+    }
+}
+
+impl<N: Network> ToBytes for PayloadPrivateVariables<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.payloads.len() as u16).write_le(&mut writer)?;
+        self.payloads.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> ToConstraintField<N::InnerScalarField> for PayloadPrivateVariables<N> {
+    #[inline]
+    fn to_field_elements(&self) -> Result<Vec<N::InnerScalarField>, ConstraintFieldError> {
+        ToConstraintField::<N::InnerScalarField>::to_field_elements(&self.to_bytes_le()?[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet1::Testnet1, PayloadType, PayloadValue, Program};
+    use snarkvm_r1cs::TestConstraintSystem;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+    use std::sync::Arc;
+
+    type N = Testnet1;
+
+    fn schemas() -> Vec<PayloadSchema<N>> {
+        vec![
+            PayloadSchema::new(vec![PayloadType::U64, PayloadType::Boolean]).unwrap(),
+            PayloadSchema::new(vec![PayloadType::Field]).unwrap(),
+        ]
+    }
+
+    fn payloads() -> Vec<Payload<N>> {
+        let schemas = schemas();
+        let field = <N as Network>::InnerScalarField::rand(&mut thread_rng());
+        vec![
+            schemas[0]
+                .encode(&[PayloadValue::U64(100), PayloadValue::Boolean(true)])
+                .unwrap(),
+            schemas[1].encode(&[PayloadValue::Field(field)]).unwrap(),
+        ]
+    }
+
+    fn public_variables() -> ProgramPublicVariables<N> {
+        let transition_id = <N as Network>::InnerScalarField::rand(&mut thread_rng());
+        ProgramPublicVariables::new(transition_id.into())
+    }
+
+    #[test]
+    fn test_execute() {
+        let payload_function = PayloadFunction::<N>::setup(
+            schemas(),
+            FunctionType::Noop,
+            &mut SRS::CircuitSpecific(&mut thread_rng()),
+        )
+        .unwrap();
+        assert_eq!(&schemas()[..], payload_function.schemas());
+
+        // Retrieve the function from its program, as the virtual machine does.
+        let payload_function = Arc::new(payload_function);
+        let program = Program::<N>::new(vec![payload_function.clone()]).unwrap();
+        let function = program.to_function(&payload_function.function_id()).unwrap();
+
+        let public = public_variables();
+        let private = PayloadPrivateVariables::<N>::new(payloads());
+        let proof = function.execute(public, &private).unwrap();
+        assert!(function.verify(&public, &proof));
+
+        // Ensure the proof does not verify for a different transition ID, or with the noop function.
+        assert!(!function.verify(&public_variables(), &proof));
+        assert!(!N::noop_program()
+            .to_function(N::noop_function_id())
+            .unwrap()
+            .verify(&public, &proof));
+
+        // Ensure the number of payloads is checked.
+        let missing = payloads()[..1].to_vec();
+        assert!(function
+            .execute(public, &PayloadPrivateVariables::<N>::new(missing))
+            .is_err());
+        assert!(function
+            .execute(public, &crate::NoopPrivateVariables::<N>::new_blank().unwrap())
+            .is_err());
+
+        // Ensure a payload that is malformed under its schema is rejected.
+        let mut malformed = payloads();
+        malformed[0] = Payload::from(&[0, 0, 0, 0, 0, 0, 0, 0, 2]).unwrap();
+        assert!(function
+            .execute(public, &PayloadPrivateVariables::<N>::new(malformed))
+            .is_err());
+    }
+
+    #[test]
+    fn test_synthesize() {
+        let function = PayloadFunction::<N>::setup(
+            schemas(),
+            FunctionType::Noop,
+            &mut SRS::CircuitSpecific(&mut thread_rng()),
+        )
+        .unwrap();
+
+        let mut cs = TestConstraintSystem::new();
+        function.synthesize(&mut cs, &public_variables()).unwrap();
+        assert!(cs.is_satisfied());
+
+        // The public inputs are the constant one, followed by the program public variables.
+        let num_public_variables = ProgramPublicVariables::<N>::blank().to_field_elements().unwrap().len();
+        assert_eq!(1 + num_public_variables, cs.num_public_variables());
+    }
+
+    #[test]
+    fn test_circuit_enforces_payload_schemas() {
+        let schemas = schemas();
+        let public = public_variables();
+
+        let is_satisfied = |payloads: &[Payload<N>]| {
+            let mut cs = TestConstraintSystem::new();
+            PayloadCircuit::new(&schemas, &public, payloads)
+                .generate_constraints(&mut cs)
+                .unwrap();
+            cs.is_satisfied()
+        };
+        assert!(is_satisfied(&payloads()));
+
+        // Ensure a boolean byte that is not zero or one is not satisfied.
+        let mut malformed = payloads();
+        malformed[0] = Payload::from(&[0, 0, 0, 0, 0, 0, 0, 0, 2]).unwrap();
+        assert!(!is_satisfied(&malformed));
+
+        // Ensure non-zero padding is not satisfied.
+        let mut malformed = payloads();
+        let mut bytes = vec![0u8; 64];
+        bytes.push(1);
+        malformed[1] = Payload::from(&bytes).unwrap();
+        assert!(!is_satisfied(&malformed));
+
+        // Ensure a mismatched number of payloads is rejected.
+        let mut cs = TestConstraintSystem::new();
+        assert!(PayloadCircuit::new(&schemas, &public, &payloads()[..1])
+            .generate_constraints(&mut cs)
+            .is_err());
+    }
+
+    #[test]
+    fn test_private_variables_bytes() {
+        let private = PayloadPrivateVariables::<N>::new(payloads());
+        let expected = private.to_bytes_le().unwrap();
+        let candidate = PayloadPrivateVariables::<N>::read_le(&expected[..]).unwrap();
+        assert_eq!(private.payloads(), candidate.payloads());
+        assert_eq!(expected, candidate.to_bytes_le().unwrap());
+    }
+}
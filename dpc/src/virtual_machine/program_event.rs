@@ -0,0 +1,244 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Network;
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::io::{Read, Result as IoResult, Write};
+
+/// A 32-byte value used to index a program event.
+pub type EventTopic = [u8; 32];
+
+/// A publicly-visible event emitted by a program, indexed by its program ID and topics.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct ProgramEvent<N: Network> {
+    /// The program ID of the program emitting the event.
+    program_id: N::ProgramID,
+    /// The indexed topics of the event.
+    topics: Vec<EventTopic>,
+    /// The unindexed data of the event.
+    data: Vec<u8>,
+}
+
+impl<N: Network> ProgramEvent<N> {
+    /// The maximum number of topics in a program event.
+    pub const MAX_TOPICS: usize = 4;
+
+    /// Returns a new instance of a program event.
+    pub fn new(program_id: N::ProgramID, topics: Vec<EventTopic>, data: Vec<u8>) -> Result<Self> {
+        if topics.len() > Self::MAX_TOPICS {
+            return Err(anyhow!(
+                "Program event contains {} topics, exceeding the maximum of {}",
+                topics.len(),
+                Self::MAX_TOPICS
+            ));
+        }
+        if data.len() > u16::MAX as usize {
+            return Err(anyhow!("Program event data exceeds {} bytes", u16::MAX));
+        }
+
+        Ok(Self {
+            program_id,
+            topics,
+            data,
+        })
+    }
+
+    /// Returns the program ID of the program emitting the event.
+    pub fn program_id(&self) -> N::ProgramID {
+        self.program_id
+    }
+
+    /// Returns a reference to the topics.
+    pub fn topics(&self) -> &Vec<EventTopic> {
+        &self.topics
+    }
+
+    /// Returns a reference to the data.
+    pub fn data(&self) -> &Vec<u8> {
+        &self.data
+    }
+}
+
+impl<N: Network> FromBytes for ProgramEvent<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let program_id = FromBytes::read_le(&mut reader)?;
+
+        let num_topics: u8 = FromBytes::read_le(&mut reader)?;
+        if num_topics as usize > Self::MAX_TOPICS {
+            return Err(error("Invalid number of program event topics"));
+        }
+        let mut topics = Vec::with_capacity(num_topics as usize);
+        for _ in 0..num_topics {
+            topics.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        let num_bytes: u16 = FromBytes::read_le(&mut reader)?;
+        let mut data = vec![0u8; num_bytes as usize];
+        reader.read_exact(&mut data)?;
+
+        Ok(Self {
+            program_id,
+            topics,
+            data,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for ProgramEvent<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.program_id.write_le(&mut writer)?;
+        (self.topics.len() as u8).write_le(&mut writer)?;
+        for topic in &self.topics {
+            topic.write_le(&mut writer)?;
+        }
+        (self.data.len() as u16).write_le(&mut writer)?;
+        self.data.write_le(&mut writer)
+    }
+}
+
+/// A filter over program events, matching on the program ID and on topics by position.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    Default(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct EventFilter<N: Network> {
+    /// The program ID to match, if any.
+    program_id: Option<N::ProgramID>,
+    /// The topic to match at each position, if any.
+    topics: Vec<Option<EventTopic>>,
+}
+
+impl<N: Network> EventFilter<N> {
+    /// Returns a new filter that matches every program event.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the filter, restricted to events from the given program ID.
+    pub fn program_id(mut self, program_id: N::ProgramID) -> Self {
+        self.program_id = Some(program_id);
+        self
+    }
+
+    /// Returns the filter, restricted to events with the given topic at the given position.
+    pub fn topic(mut self, index: usize, topic: EventTopic) -> Self {
+        if self.topics.len() <= index {
+            self.topics.resize(index + 1, None);
+        }
+        self.topics[index] = Some(topic);
+        self
+    }
+
+    /// Returns the program ID to match, if any.
+    pub fn to_program_id(&self) -> Option<N::ProgramID> {
+        self.program_id
+    }
+
+    /// Returns `true` if the given program event satisfies the filter.
+    pub fn matches(&self, event: &ProgramEvent<N>) -> bool {
+        if let Some(program_id) = self.program_id {
+            if program_id != event.program_id() {
+                return false;
+            }
+        }
+
+        self.topics.iter().enumerate().all(|(index, topic)| match topic {
+            Some(topic) => event.topics().get(index) == Some(topic),
+            None => true,
+        })
+    }
+}
+
+/// A program event, along with its location in the ledger.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct IndexedEvent<N: Network> {
+    /// The height of the block containing the event.
+    pub block_height: u32,
+    /// The ID of the transition emitting the event.
+    pub transition_id: N::TransitionID,
+    /// The program event.
+    pub event: ProgramEvent<N>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_program_event_bytes() {
+        let rng = &mut thread_rng();
+
+        let expected_event =
+            ProgramEvent::<Testnet2>::new(UniformRand::rand(rng), vec![[1u8; 32], [2u8; 32]], vec![3u8; 10]).unwrap();
+
+        let expected_bytes = expected_event.to_bytes_le().unwrap();
+        assert_eq!(expected_event, ProgramEvent::read_le(&expected_bytes[..]).unwrap());
+
+        // Ensure truncated bytes fail to deserialize.
+        assert!(ProgramEvent::<Testnet2>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_program_event_too_many_topics() {
+        let rng = &mut thread_rng();
+        let topics = vec![[0u8; 32]; ProgramEvent::<Testnet2>::MAX_TOPICS + 1];
+        assert!(ProgramEvent::<Testnet2>::new(UniformRand::rand(rng), topics, vec![]).is_err());
+    }
+
+    #[test]
+    fn test_event_filter() {
+        let rng = &mut thread_rng();
+
+        let program_id = UniformRand::rand(rng);
+        let event = ProgramEvent::<Testnet2>::new(program_id, vec![[1u8; 32], [2u8; 32]], vec![]).unwrap();
+
+        assert!(EventFilter::new().matches(&event));
+        assert!(EventFilter::new().program_id(program_id).matches(&event));
+        assert!(EventFilter::new().topic(1, [2u8; 32]).matches(&event));
+        assert!(EventFilter::new()
+            .program_id(program_id)
+            .topic(0, [1u8; 32])
+            .matches(&event));
+
+        assert!(!EventFilter::new().program_id(UniformRand::rand(rng)).matches(&event));
+        assert!(!EventFilter::new().topic(0, [2u8; 32]).matches(&event));
+        assert!(!EventFilter::new().topic(2, [1u8; 32]).matches(&event));
+    }
+}
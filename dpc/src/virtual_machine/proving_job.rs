@@ -0,0 +1,329 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt,
+    io::{Read, Result as IoResult, Write},
+    str::FromStr,
+};
+
+/// A self-contained job for proving a signed request on a separate prover.
+///
+/// The caller signs the request locally with their private key. The prover only receives the
+/// signed request, its ledger proofs, and the ledger root, and recovers the compute key of the
+/// caller from the request signature.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct ProvingJob<N: Network> {
+    /// The root of the ledger tree used to prove inclusion of ledger-consumed records.
+    ledger_root: N::LedgerRoot,
+    /// The signed request, including the ledger proofs of its records.
+    request: Request<N>,
+}
+
+impl<N: Network> ProvingJob<N> {
+    /// Returns a new proving job for the given signed request.
+    pub fn new(ledger_root: N::LedgerRoot, request: Request<N>) -> Result<Self> {
+        let job = Self { ledger_root, request };
+
+        match job.is_valid() {
+            true => Ok(job),
+            false => Err(anyhow!("Proving job contains an invalid request")),
+        }
+    }
+
+    /// Returns `true` if the request in the proving job is well-formed.
+    pub fn is_valid(&self) -> bool {
+        self.request.is_valid()
+    }
+
+    /// Returns the ledger root.
+    pub fn ledger_root(&self) -> N::LedgerRoot {
+        self.ledger_root
+    }
+
+    /// Returns a reference to the request.
+    pub fn request(&self) -> &Request<N> {
+        &self.request
+    }
+
+    /// Returns the compute key of the caller, recovered from the request signature.
+    pub fn to_compute_key(&self) -> Result<ComputeKey<N>> {
+        Ok(ComputeKey::from_signature(self.request.signature())?)
+    }
+
+    /// Proves the request, and returns the resulting transaction.
+    pub fn prove<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Transaction<N>> {
+        // Ensure the request is valid, as the proving job may originate from an untrusted caller.
+        if !self.is_valid() {
+            return Err(anyhow!("Proving job contains an invalid request"));
+        }
+
+        let (vm, _) = VirtualMachine::<N>::new(self.ledger_root)?.execute(&self.request, rng)?;
+        vm.finalize()
+    }
+}
+
+impl<N: Network> FromBytes for ProvingJob<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let network_id: u16 = FromBytes::read_le(&mut reader)?;
+        if network_id != N::NETWORK_ID {
+            return Err(error("Proving job is for a different network"));
+        }
+
+        let ledger_root = FromBytes::read_le(&mut reader)?;
+        let request = FromBytes::read_le(&mut reader)?;
+
+        Self::new(ledger_root, request).map_err(|_| error("Failed to deserialize a proving job"))
+    }
+}
+
+impl<N: Network> ToBytes for ProvingJob<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        N::NETWORK_ID.write_le(&mut writer)?;
+        self.ledger_root.write_le(&mut writer)?;
+        self.request.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromStr for ProvingJob<N> {
+    type Err = anyhow::Error;
+
+    fn from_str(job: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(job)?)
+    }
+}
+
+impl<N: Network> fmt::Display for ProvingJob<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err::<fmt::Error, _>(serde::ser::Error::custom)?
+        )
+    }
+}
+
+impl<N: Network> Serialize for ProvingJob<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let request = self.request.to_bytes_le().map_err(serde::ser::Error::custom)?;
+
+                let mut job = serializer.serialize_struct("ProvingJob", 3)?;
+                job.serialize_field("network_id", &N::NETWORK_ID)?;
+                job.serialize_field("ledger_root", &self.ledger_root)?;
+                job.serialize_field("request", &hex::encode(request))?;
+                job.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProvingJob<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let job = serde_json::Value::deserialize(deserializer)?;
+
+                // Ensure the proving job is for this network.
+                let network_id: u16 = serde_json::from_value(job["network_id"].clone()).map_err(de::Error::custom)?;
+                if network_id != N::NETWORK_ID {
+                    return Err(de::Error::custom("Proving job is for a different network"));
+                }
+
+                // Recover the request.
+                let request: String = serde_json::from_value(job["request"].clone()).map_err(de::Error::custom)?;
+                let request = hex::decode(request).map_err(de::Error::custom)?;
+
+                Self::new(
+                    serde_json::from_value(job["ledger_root"].clone()).map_err(de::Error::custom)?,
+                    FromBytes::read_le(&request[..]).map_err(de::Error::custom)?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proving job"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    /// Returns a ledger with a mined block, and a proving job transferring the coinbase record of the block.
+    fn transfer_job(
+        account: &Account<Testnet2>,
+        recipient: Address<Testnet2>,
+    ) -> (Ledger<Testnet2>, ProvingJob<Testnet2>) {
+        let rng = &mut thread_rng();
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let record = ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng, -1)
+            .unwrap();
+        let ledger_proof = ledger.to_ledger_proof(record.commitment()).unwrap();
+
+        let amount = AleoAmount::from_gates(1);
+        let request = Request::new_transfer(
+            account.private_key(),
+            vec![record],
+            vec![ledger_proof, LedgerProof::default()],
+            recipient,
+            amount,
+            AleoAmount::ZERO,
+            false,
+            rng,
+        )
+        .unwrap();
+
+        let job = ProvingJob::new(ledger.latest_ledger_root(), request).unwrap();
+        (ledger, job)
+    }
+
+    #[test]
+    fn test_delegated_proving() {
+        let rng = &mut thread_rng();
+
+        // On the client, sign a request for a new account.
+        let account = Account::<Testnet2>::new(rng);
+        let request = Request::new_coinbase(account.address(), AleoAmount::from_gates(1), true, rng).unwrap();
+        let ledger_root = LedgerTree::<Testnet2>::new().unwrap().root();
+        let job = ProvingJob::new(ledger_root, request.clone()).unwrap();
+
+        // Send the proving job to the prover, which never sees the private key.
+        let job_json = job.to_string();
+        let job_bytes = job.to_bytes_le().unwrap();
+        let prover_job = ProvingJob::<Testnet2>::from_str(&job_json).unwrap();
+        assert_eq!(job_bytes, prover_job.to_bytes_le().unwrap());
+        assert_eq!(
+            job_bytes,
+            ProvingJob::<Testnet2>::read_le(&job_bytes[..])
+                .unwrap()
+                .to_bytes_le()
+                .unwrap()
+        );
+        assert_eq!(
+            job_bytes,
+            bincode::deserialize::<ProvingJob<Testnet2>>(&bincode::serialize(&job).unwrap())
+                .unwrap()
+                .to_bytes_le()
+                .unwrap()
+        );
+
+        // On the prover, recover the compute key and prove the request.
+        let compute_key = prover_job.to_compute_key().unwrap();
+        assert_eq!(request.caller().unwrap(), Address::from_compute_key(&compute_key));
+
+        let transaction = prover_job.prove(rng).unwrap();
+        assert!(transaction.is_valid());
+        assert_eq!(ledger_root, transaction.ledger_root());
+
+        // On the client, ensure the transaction contains the record for the account.
+        let records: Vec<_> = transaction.to_decrypted_records(&account.view_key().into()).collect();
+        assert_eq!(1, records.len());
+        assert_eq!(account.address(), records[0].owner());
+    }
+
+    #[test]
+    fn test_proving_job_for_different_network() {
+        let rng = &mut thread_rng();
+
+        let account = Account::<Testnet2>::new(rng);
+        let request = Request::new_coinbase(account.address(), AleoAmount::from_gates(1), true, rng).unwrap();
+        let job = ProvingJob::new(LedgerTree::<Testnet2>::new().unwrap().root(), request).unwrap();
+
+        let mut job_bytes = job.to_bytes_le().unwrap();
+        job_bytes[0] = job_bytes[0].wrapping_add(1);
+        assert!(ProvingJob::<Testnet2>::read_le(&job_bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_delegated_proving_of_ledger_records() {
+        let rng = &mut thread_rng();
+
+        let account = Account::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng);
+        let (mut ledger, job) = transfer_job(&account, recipient.address());
+
+        // Send the proving job to the prover, which proves the transfer of the ledger record.
+        let prover_job = ProvingJob::<Testnet2>::read_le(&job.to_bytes_le().unwrap()[..]).unwrap();
+        let transaction = prover_job.prove(rng).unwrap();
+        assert!(transaction.is_valid());
+        assert_eq!(ledger.latest_ledger_root(), transaction.ledger_root());
+
+        // Ensure the transaction spends the ledger record, and is accepted by the ledger.
+        let spent = job.request().records()[0]
+            .to_serial_number(&account.private_key().to_compute_key())
+            .unwrap();
+        assert!(transaction
+            .serial_numbers()
+            .any(|serial_number| *serial_number == spent));
+        ledger.add_unconfirmed_transaction(&transaction).unwrap();
+        ledger
+            .mine_next_block(account.address(), true, &AtomicBool::new(false), rng, -1)
+            .unwrap();
+        assert!(ledger.contains_transaction(&transaction));
+
+        // Ensure the ledger record may not be spent twice.
+        assert!(ledger.add_unconfirmed_transaction(&transaction).is_err());
+
+        // On the client, ensure the transaction contains the record for the recipient.
+        let records: Vec<_> = transaction.to_decrypted_records(&recipient.view_key().into()).collect();
+        assert_eq!(1, records.len());
+        assert_eq!(AleoAmount::from_gates(1), records[0].value());
+    }
+
+    #[test]
+    fn test_proving_job_with_corrupted_ledger_proof() {
+        let rng = &mut thread_rng();
+
+        let account = Account::<Testnet2>::new(rng);
+        let (_, job) = transfer_job(&account, Account::<Testnet2>::new(rng).address());
+
+        // Locate the ledger proof of the ledger record in the proving job.
+        let ledger_proof = job.request().ledger_proofs()[0].to_bytes_le().unwrap();
+        let job_bytes = job.to_bytes_le().unwrap();
+        let offset = job_bytes
+            .windows(ledger_proof.len())
+            .position(|window| window == &ledger_proof[..])
+            .unwrap();
+
+        // Ensure a ledger proof whose commitment is not included in the local proof is rejected.
+        // The commitment is the last field of the ledger proof, and flipping its lowest bit keeps it canonical.
+        let mut corrupted = job_bytes.clone();
+        corrupted[offset + ledger_proof.len() - 32] ^= 1;
+        assert!(ProvingJob::<Testnet2>::from_bytes_le(&corrupted).is_err());
+
+        // Ensure a ledger proof with a corrupted ledger root is rejected.
+        let mut corrupted = job_bytes;
+        corrupted[offset] ^= 1;
+        assert!(ProvingJob::<Testnet2>::from_bytes_le(&corrupted).is_err());
+    }
+}
@@ -59,6 +59,14 @@ impl<N: Network> VirtualMachine<N> {
             return Err(anyhow!("Virtual machine received an invalid request"));
         }
 
+        // Ensure the inner circuit parameters of the network were set up for the number of input records.
+        if request.records().len() != N::NUM_INPUT_RECORDS {
+            return Err(anyhow!(
+                "Inner circuit parameters for {} input records are not available",
+                request.records().len()
+            ));
+        }
+
         // Compute the operation.
         let operation = request.operation().clone();
         let response = match operation {
@@ -168,13 +176,18 @@ impl<N: Network> VirtualMachine<N> {
         }
 
         // Compute the starting balance of the caller.
-        let starting_balance = request.to_balance().sub(request.fee());
+        let starting_balance = request
+            .to_balance()?
+            .checked_sub(request.fee())
+            .map_err(|_| VMError::BalanceOverflow)?;
         if starting_balance.is_negative() {
             return Err(VMError::BalanceInsufficient.into());
         }
 
         // Compute the final balance of the caller.
-        let caller_balance = starting_balance.sub(amount);
+        let caller_balance = starting_balance
+            .checked_sub(amount)
+            .map_err(|_| VMError::BalanceOverflow)?;
         if caller_balance.is_negative() {
             return Err(VMError::BalanceInsufficient.into());
         }
@@ -212,13 +225,18 @@ impl<N: Network> VirtualMachine<N> {
         }
 
         // Compute the starting balance of the caller.
-        let starting_balance = request.to_balance().sub(request.fee());
+        let starting_balance = request
+            .to_balance()?
+            .checked_sub(request.fee())
+            .map_err(|_| VMError::BalanceOverflow)?;
         if starting_balance.is_negative() {
             return Err(VMError::BalanceInsufficient.into());
         }
 
         // Compute the final balance of the caller.
-        let caller_balance = starting_balance.sub(function_inputs.amount);
+        let caller_balance = starting_balance
+            .checked_sub(function_inputs.amount)
+            .map_err(|_| VMError::BalanceOverflow)?;
         if caller_balance.is_negative() {
             return Err(VMError::BalanceInsufficient.into());
         }
@@ -273,6 +291,14 @@ impl<N: Network> VirtualMachine<N> {
             return Err(anyhow!("Virtual machine received an invalid request"));
         }
 
+        // Ensure the inner circuit parameters of the network were set up for the number of input records.
+        if request.records().len() != N::NUM_INPUT_RECORDS {
+            return Err(anyhow!(
+                "Inner circuit parameters for {} input records are not available",
+                request.records().len()
+            ));
+        }
+
         // Compute the operation.
         let operation = request.operation().clone();
         let response = match operation {
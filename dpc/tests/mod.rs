@@ -49,7 +49,7 @@ fn test_posw_terminate() {
         std::thread::sleep(Duration::from_secs(1));
         thread_terminator.store(true, Ordering::SeqCst);
     });
-    let result = Testnet2::posw().mine(&block_template, &AtomicBool::new(true), &mut thread_rng());
+    let result = Testnet2::posw().mine(&block_template, &AtomicBool::new(true), &mut thread_rng(), -1);
 
     assert!(matches!(result, Err(PoSWError::SNARKError(SNARKError::Terminated))));
 }
@@ -92,9 +92,10 @@ fn test_posw_setup_vs_load_weak_sanity_check() {
         let max_degree = snarkvm_marlin::AHPForR1CS::<Fr, MarlinTestnet1Mode>::max_degree(40000, 40000, 60000).unwrap();
         let universal_srs = <Testnet2 as Network>::PoSWSNARK::universal_setup(&max_degree, rng).unwrap();
         // Run the circuit setup.
-        <<Testnet2 as Network>::PoSW as PoSWScheme<Testnet2>>::setup::<ThreadRng>(&mut SRS::<ThreadRng, _>::Universal(
-            &universal_srs,
-        ))
+        <<Testnet2 as Network>::PoSW as PoSWScheme<Testnet2>>::setup::<ThreadRng>(
+            &mut SRS::<ThreadRng, _>::Universal(&universal_srs),
+            -1,
+        )
         .unwrap()
     };
     let loaded_posw = Testnet2::posw().clone();
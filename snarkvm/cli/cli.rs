@@ -43,4 +43,22 @@ pub enum Command {
         #[structopt(short = "q", long)]
         quiet: bool,
     },
+    /// Search for an account whose address matches a prefix or suffix
+    Vanity {
+        /// The characters at the start of the address, after `aleo1`
+        #[structopt(short = "p", long, default_value = "")]
+        prefix: String,
+
+        /// The characters at the end of the address
+        #[structopt(short = "s", long, default_value = "")]
+        suffix: String,
+
+        /// The number of threads to search with, defaults to all cores
+        #[structopt(short = "t", long)]
+        threads: Option<usize>,
+
+        /// The network to generate the account for
+        #[structopt(short = "n", long, default_value = "testnet2", possible_values = &["testnet1", "testnet2"])]
+        network: String,
+    },
 }
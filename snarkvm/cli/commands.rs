@@ -14,7 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::cli::{Command, Updater};
+use crate::{
+    cli::{Command, Updater, Vanity},
+    dpc::{testnet1::Testnet1, testnet2::Testnet2},
+};
+
+use anyhow::anyhow;
 
 pub fn parse(command: Command) -> anyhow::Result<String> {
     match command {
@@ -42,6 +47,17 @@ pub fn parse(command: Command) -> anyhow::Result<String> {
                     Ok("".to_string())
                 }
             }
-        }, // _ => Err(anyhow!("\nUnknown command\n")),
+        },
+        Command::Vanity {
+            prefix,
+            suffix,
+            threads,
+            network,
+        } => match network.as_str() {
+            "testnet1" => Vanity::search::<Testnet1>(&prefix, &suffix, threads),
+            "testnet2" => Vanity::search::<Testnet2>(&prefix, &suffix, threads),
+            _ => Err(anyhow!("\nUnknown network {}\n", network)),
+        },
+        // _ => Err(anyhow!("\nUnknown command\n")),
     }
 }
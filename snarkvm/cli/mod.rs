@@ -25,3 +25,6 @@ pub use errors::*;
 
 pub mod updater;
 pub use updater::*;
+
+pub mod vanity;
+pub use vanity::*;
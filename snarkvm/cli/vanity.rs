@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dpc::{Account, Network, VanityPattern};
+
+use colored::Colorize;
+use std::{
+    io::{self, Write},
+    sync::atomic::AtomicBool,
+    time::Instant,
+};
+
+pub struct Vanity;
+
+impl Vanity {
+    /// Search for an account whose address matches the given prefix and suffix.
+    pub fn search<N: Network>(prefix: &str, suffix: &str, threads: Option<usize>) -> anyhow::Result<String>
+    where
+        N::AccountSeed: Send,
+    {
+        let pattern = VanityPattern::<N>::new(prefix, suffix)?;
+
+        let mut thread_pool = rayon::ThreadPoolBuilder::new();
+        if let Some(threads) = threads {
+            thread_pool = thread_pool.num_threads(threads);
+        }
+        let thread_pool = thread_pool.build()?;
+
+        eprintln!(
+            "Searching for an address matching aleo1{}...{} on {} threads, expecting {:.0} attempts",
+            pattern.prefix(),
+            pattern.suffix(),
+            thread_pool.current_num_threads(),
+            pattern.difficulty()
+        );
+
+        let start = Instant::now();
+        let private_key = thread_pool
+            .install(|| {
+                pattern.search(&AtomicBool::new(false), |attempts| {
+                    let elapsed = start.elapsed().as_secs_f64();
+                    eprint!(
+                        "\r{} attempts in {:.0}s ({:.0} per second), {:.2}% probability of a match so far",
+                        attempts,
+                        elapsed,
+                        attempts as f64 / elapsed,
+                        100.0 * pattern.probability(attempts)
+                    );
+                    let _ = io::stderr().flush();
+                })
+            })
+            .ok_or_else(|| anyhow::anyhow!("The vanity search was stopped"))?;
+        eprintln!();
+
+        let account = Account::from(private_key);
+        let mut output = format!("\n{:>12}  {}\n", "Private Key".cyan().bold(), account.private_key());
+        output += &format!("{:>12}  {}\n", "View Key".cyan().bold(), account.view_key());
+        output += &format!("{:>12}  {}\n", "Address".cyan().bold(), account.address());
+        Ok(output)
+    }
+}